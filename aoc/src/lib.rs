@@ -0,0 +1,62 @@
+use anyhow::{Result, anyhow};
+
+use cli::Solver;
+
+/// The registry of every [Solver], of every year. A calendar is added by depending on the crates
+/// of its days and listing their solvers here.
+pub const SOLVERS: &[&Solver] = &[
+    &day_1::SOLVER,
+    &day_2::SOLVER,
+    &day_3::SOLVER,
+    &day_4::SOLVER,
+    &day_5::SOLVER,
+    &day_6::SOLVER,
+    &day_7::SOLVER,
+    &day_8::SOLVER,
+    &day_9::SOLVER,
+    &day_10::SOLVER,
+    &day_11::SOLVER,
+    &day_12::SOLVER,
+    &day_13::SOLVER,
+    &day_14::SOLVER,
+    &day_15::SOLVER,
+    &day_16::SOLVER,
+    &day_17::SOLVER,
+    &day_18::SOLVER,
+    &day_19::SOLVER,
+    &day_20::SOLVER,
+    &day_21::SOLVER,
+    &day_22::SOLVER,
+    &day_23::SOLVER,
+    &day_24::SOLVER,
+    &day_25::SOLVER,
+];
+
+/// Gets the [Solver] for the given day of the given year.
+pub fn get_solver(year: u16, day: u8) -> Result<&'static Solver> {
+    SOLVERS
+        .iter()
+        .copied()
+        .find(|solver| solver.year == year && solver.day == day)
+        .ok_or(anyhow!("No solver for day {} of {}", day, year))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn versions() {
+        let versions = SOLVERS
+            .iter()
+            .map(|solver| solver.version)
+            .collect::<HashSet<_>>();
+
+        assert_eq!(versions.len(), SOLVERS.len());
+        assert!(versions.iter().all(|version| {
+            version.len() == 16 && version.bytes().all(|byte| byte.is_ascii_hexdigit())
+        }));
+    }
+}
@@ -0,0 +1,680 @@
+use std::{env, fs, path::PathBuf, time::Duration};
+
+use anyhow::{Result, anyhow};
+use clap::{Parser, Subcommand};
+
+use cli::{
+    animate, answer, cache,
+    certify::Certificate,
+    compare::{self, External},
+    config::{Config, Format, find_config},
+    examples, input, parallel,
+    perf::{self, hash_input, hash_reader},
+    progress, read_input, trim_newlines_in_place,
+};
+
+use aoc::{SOLVERS, get_solver};
+
+mod serve;
+
+#[derive(Parser)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves one part of a day's puzzle.
+    Run {
+        year: u16,
+        day: u8,
+        part: u8,
+
+        #[arg(short = 'i', long)]
+        input: Option<String>,
+
+        /// Also draws the solution to an image, as .ppm, .png or .svg. Only grid days can be drawn.
+        #[arg(long)]
+        render: Option<String>,
+
+        /// Plays back the simulation in the terminal before giving the answer, or writes out every
+        /// frame when stdout is not a terminal.
+        #[arg(long)]
+        animate: bool,
+
+        /// Prints the parsed model of the input as JSON instead of solving.
+        #[arg(long)]
+        dump_parsed: bool,
+
+        /// Solves without reading the input into memory: a line at a time where the day allows,
+        /// or else from the input mapped into memory.
+        #[arg(long, conflicts_with_all = ["render", "animate", "dump_parsed"])]
+        stream: bool,
+
+        /// Prints the answer along with a witness to it as JSON, once the witness has passed the
+        /// day's checker. Only days 17, 22, 23, 24 and 25 give witnesses.
+        #[arg(long, conflicts_with_all = ["render", "animate", "dump_parsed", "stream"])]
+        certify: bool,
+
+        /// Prints how each line of the input is solved instead of solving, such as the digits
+        /// picked on day 1. Only day 1 reports.
+        #[arg(long, conflicts_with_all = ["render", "animate", "dump_parsed", "stream", "certify"])]
+        report: bool,
+
+        /// Delay between frames when animating, in milliseconds.
+        #[arg(long, default_value_t = 100)]
+        delay: u64,
+
+        /// Overrides the format in aoc.toml.
+        #[arg(long)]
+        format: Option<Format>,
+
+        /// Overrides the timeout in aoc.toml, in seconds.
+        #[arg(long)]
+        timeout: Option<u64>,
+
+        /// How many threads the parallel parts of a solver use, where 0 is a thread per CPU.
+        #[arg(long, default_value_t = 0)]
+        threads: usize,
+
+        /// Solves even if the answer is in the answer cache, and leaves the cache untouched.
+        #[arg(long)]
+        no_cache: bool,
+
+        /// Accumulates answers in 128 bits, overriding aoc.toml.
+        #[arg(long)]
+        wide: bool,
+    },
+
+    /// Solves both parts of every day that has a puzzle input, reusing cached answers.
+    RunAll {
+        /// Only solves the days of this year.
+        #[arg(long)]
+        year: Option<u16>,
+
+        /// Overrides the format in aoc.toml.
+        #[arg(long)]
+        format: Option<Format>,
+
+        /// Overrides the timeout in aoc.toml, in seconds.
+        #[arg(long)]
+        timeout: Option<u64>,
+
+        /// How many threads the parallel parts of a solver use, where 0 is a thread per CPU.
+        #[arg(long, default_value_t = 0)]
+        threads: usize,
+
+        /// Solves even if the answer is in the answer cache, and leaves the cache untouched.
+        #[arg(long)]
+        no_cache: bool,
+
+        /// Accumulates answers in 128 bits, overriding aoc.toml.
+        #[arg(long)]
+        wide: bool,
+    },
+
+    /// Checks a certificate printed by `aoc run --certify` against the input, without solving.
+    Check {
+        year: u16,
+        day: u8,
+        part: u8,
+        certificate: String,
+
+        #[arg(short = 'i', long)]
+        input: Option<String>,
+    },
+
+    /// Compares the answers to a day with those of another solver, run as a shell command, on the
+    /// puzzle input, the example fixtures, or generated inputs.
+    ///
+    /// In the command, {part} is replaced by the part number, and {input} by the path of a file
+    /// holding the input, which is otherwise piped to stdin. The answer is the last non-empty line
+    /// of stdout. Reports the first input the solvers disagree on, shrunk if it was generated.
+    Compare {
+        year: u16,
+        day: u8,
+
+        #[arg(long)]
+        against: String,
+
+        /// Only compares this part.
+        #[arg(long)]
+        part: Option<u8>,
+
+        #[arg(short = 'i', long)]
+        input: Option<String>,
+
+        /// Compares on the example fixtures instead of the puzzle input.
+        #[arg(long, conflicts_with_all = ["input", "generated"])]
+        examples: bool,
+
+        /// Compares on inputs generated from this many seeds, from 0, instead of the puzzle input.
+        #[arg(long, conflicts_with = "input")]
+        generated: Option<u64>,
+
+        /// The size of the generated inputs.
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+
+        /// Overrides the timeout in aoc.toml, in seconds, after which the command is killed.
+        #[arg(long)]
+        timeout: Option<u64>,
+    },
+
+    /// Generates a random puzzle input for a day.
+    ///
+    /// The input is written to stdout, or to the output file along with the expected answers in
+    /// <OUTPUT>.answers, where they are known.
+    Gen {
+        year: u16,
+        day: u8,
+
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Roughly the num of lines, or the side of the grid, depending on the day.
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+
+        #[arg(short = 'o', long)]
+        output: Option<String>,
+    },
+
+    /// Serves every day as a JSON API on localhost: `POST /solve/{year}/{day}/{part}` with the
+    /// input as the body gives the answer, or the error, and the time taken.
+    Serve {
+        #[arg(long, default_value_t = 8023)]
+        port: u16,
+
+        /// Overrides the timeout in aoc.toml, in seconds. A request may override it in turn with
+        /// ?timeout=<SECONDS>.
+        #[arg(long)]
+        timeout: Option<u64>,
+
+        /// How many threads the parallel parts of a solver use, where 0 is a thread per CPU.
+        #[arg(long, default_value_t = 0)]
+        threads: usize,
+
+        /// How many puzzles are solved at once, where 0 is one per CPU. Solves that time out
+        /// still count until they finish, and requests beyond them are answered with 503.
+        #[arg(long, default_value_t = 0)]
+        solves: usize,
+    },
+
+    /// Manages the example fixtures that the tests of each day check.
+    Examples {
+        #[command(subcommand)]
+        command: ExamplesCommand,
+    },
+
+    /// Shows the configuration in effect, from the aoc.toml found in the current directory or its
+    /// nearest ancestor.
+    Config,
+
+    /// Inspects the timings recorded by every run.
+    Perf {
+        #[command(subcommand)]
+        command: PerfCommand,
+    },
+
+    /// Inspects or purges the cached answers.
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum PerfCommand {
+    /// Shows the trend of each day and part by commit, flagging regressions.
+    Report {
+        /// Only reports this year.
+        #[arg(long)]
+        year: Option<u16>,
+
+        /// Only reports this day.
+        #[arg(long)]
+        day: Option<u8>,
+
+        /// How many earlier commits the latest one is compared against.
+        #[arg(long, default_value_t = 5)]
+        window: usize,
+
+        /// Overrides the regression threshold in aoc.toml.
+        #[arg(long)]
+        threshold: Option<f64>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ExamplesCommand {
+    /// Pulls the examples and their expected answers out of a puzzle description saved from
+    /// adventofcode.com, writing them as fixtures in the fixtures directory of the day's crate.
+    ///
+    /// Save the page once part 2 is unlocked to also get the answers to part 2. Check the
+    /// fixtures written, as which answer goes with which example is a guess.
+    Import { year: u16, day: u8, page: String },
+}
+
+#[derive(Subcommand)]
+enum CacheCommand {
+    /// Lists the cached answers, marking those left behind by an edit to the day.
+    List {
+        /// Only lists this year.
+        #[arg(long)]
+        year: Option<u16>,
+
+        /// Only lists this day.
+        #[arg(long)]
+        day: Option<u8>,
+    },
+
+    /// Removes cached answers.
+    Purge {
+        /// Only removes the answers to this year.
+        #[arg(long)]
+        year: Option<u16>,
+
+        /// Only removes the answers to this day.
+        #[arg(long)]
+        day: Option<u8>,
+    },
+}
+
+fn run(command: Command) -> Result<()> {
+    match command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            render,
+            animate,
+            dump_parsed,
+            stream,
+            certify,
+            report,
+            delay,
+            format,
+            timeout,
+            threads,
+            no_cache,
+            wide,
+        } => {
+            parallel::set_threads(threads)?;
+            let config = Config::discover()?;
+            let solver = get_solver(year, day)?;
+            let path = input.map_or(config.input_path(year, day), PathBuf::from);
+            let format = format.unwrap_or(config.format);
+            let mut settings = config.settings(year, day);
+            if wide {
+                settings.set(answer::WIDE, true);
+            }
+            let timeout = timeout.or(config.timeout);
+
+            if stream {
+                let input_hash = hash_reader(&mut input::open(&path)?)?;
+                let (answer, _) = progress::show_while(progress::is_shown(format), || {
+                    solver.solve_cached(part, input_hash, &settings, &config, !no_cache, || {
+                        solver.solve_path(part, path, settings.clone(), timeout)
+                    })
+                })?;
+                println!("{}", format.answer(year, day, part, &answer));
+                return Ok(());
+            }
+
+            let mut input = read_input(&path)?;
+            trim_newlines_in_place(&mut input);
+
+            if dump_parsed {
+                println!("{}", solver.dump(&input)?);
+                return Ok(());
+            }
+            if report {
+                println!("{}", solver.report(part, &input, &settings)?);
+                return Ok(());
+            }
+            if certify {
+                let certificate = solver.certify(part, &input)?;
+                solver.check(part, &input, &certificate)?;
+                println!("{}", serde_json::to_string_pretty(&certificate)?);
+                return Ok(());
+            }
+            if animate {
+                let frames = solver.animate(part, &input)?;
+                animate::play(frames, Duration::from_millis(delay))?;
+            }
+            // Only rendering needs the input once it has been solved.
+            let kept_input = render.is_some().then(|| input.clone());
+            let (answer, _) = progress::show_while(progress::is_shown(format), || {
+                solver.solve_cached(
+                    part,
+                    hash_input(&input),
+                    &settings,
+                    &config,
+                    !no_cache,
+                    || solver.solve_with_timeout(part, input, settings.clone(), timeout),
+                )
+            })?;
+            println!("{}", format.answer(year, day, part, &answer));
+            if let (Some(render), Some(input)) = (render, kept_input) {
+                solver.render(part, &input)?.save(&render)?;
+            }
+        }
+        Command::RunAll {
+            year,
+            format,
+            timeout,
+            threads,
+            no_cache,
+            wide,
+        } => {
+            parallel::set_threads(threads)?;
+            let config = Config::discover()?;
+            let format = format.unwrap_or(config.format);
+            let timeout = timeout.or(config.timeout);
+
+            for solver in SOLVERS
+                .iter()
+                .filter(|solver| year.is_none_or(|year| solver.year == year))
+            {
+                let path = config.input_path(solver.year, solver.day);
+                if !path.exists() {
+                    continue;
+                }
+
+                let mut input = read_input(&path)?;
+                trim_newlines_in_place(&mut input);
+                let input_hash = hash_input(&input);
+                let num_parts = if solver.part_2.is_some() { 2 } else { 1 };
+                for part in 1..=num_parts {
+                    let input = input.clone();
+                    let mut settings = config.settings(solver.year, solver.day);
+                    if wide {
+                        settings.set(answer::WIDE, true);
+                    }
+                    let solved = progress::show_while(progress::is_shown(format), || {
+                        solver.solve_cached(
+                            part,
+                            input_hash.clone(),
+                            &settings,
+                            &config,
+                            !no_cache,
+                            || solver.solve_with_timeout(part, input, settings.clone(), timeout),
+                        )
+                    });
+
+                    match (format, solved) {
+                        (Format::Text, Ok((answer, cached))) => println!(
+                            "{} day {} part {}: {}{}",
+                            solver.year,
+                            solver.day,
+                            part,
+                            answer,
+                            if cached { " (cached)" } else { "" }
+                        ),
+                        (Format::Json, Ok((answer, _))) => {
+                            println!("{}", format.answer(solver.year, solver.day, part, &answer))
+                        }
+                        (_, Err(error)) => println!(
+                            "{} day {} part {}: {}",
+                            solver.year, solver.day, part, error
+                        ),
+                    }
+                }
+            }
+        }
+        Command::Check {
+            year,
+            day,
+            part,
+            certificate,
+            input,
+        } => {
+            let config = Config::discover()?;
+            let path = input.map_or(config.input_path(year, day), PathBuf::from);
+            let mut input = read_input(&path)?;
+            trim_newlines_in_place(&mut input);
+            let certificate: Certificate = serde_json::from_str(&fs::read_to_string(&certificate)?)
+                .map_err(|error| anyhow!("Cannot parse {}: {}", certificate, error))?;
+
+            get_solver(year, day)?.check(part, &input, &certificate)?;
+            println!("Checked: {}", certificate.answer);
+        }
+        Command::Compare {
+            year,
+            day,
+            against,
+            part,
+            input,
+            examples,
+            generated,
+            size,
+            timeout,
+        } => {
+            let config = Config::discover()?;
+            let solver = get_solver(year, day)?;
+            let external = External {
+                command: against,
+                timeout: timeout.or(config.timeout),
+            };
+            let num_parts = if solver.part_2.is_some() { 2 } else { 1 };
+            let parts = part.map_or((1..=num_parts).collect(), |part| vec![part]);
+
+            // (label, input, seed of a generated input)
+            let inputs = if examples {
+                examples::load_fixtures(&examples::fixture_dir(year, day))?
+                    .into_iter()
+                    .map(|(path, example)| (path.display().to_string(), example.input, None))
+                    .collect::<Vec<_>>()
+            } else if let Some(seeds) = generated {
+                (0..seeds)
+                    .map(|seed| {
+                        let input = solver.generate(seed, size).input;
+                        (format!("seed {seed} size {size}"), input, Some(seed))
+                    })
+                    .collect()
+            } else {
+                let path = input.map_or(config.input_path(year, day), PathBuf::from);
+                let mut input = read_input(&path)?;
+                trim_newlines_in_place(&mut input);
+                vec![(path.display().to_string(), input, None)]
+            };
+
+            for (label, input, seed) in &inputs {
+                for &part in &parts {
+                    let Some(disagreement) = compare::compare(solver, &external, part, input)
+                        .map_err(|error| anyhow!("{} on part {} of {}", error, part, label))?
+                    else {
+                        continue;
+                    };
+
+                    println!(
+                        "Disagree on part {} of {}: {} != {}",
+                        part, label, disagreement.ours, disagreement.theirs
+                    );
+                    if let Some(seed) = seed {
+                        let shrunk = compare::shrink(solver, &external, *seed, size, disagreement);
+                        println!(
+                            "Shrunk to {} lines: {} != {}\n{}",
+                            shrunk.input.lines().count(),
+                            shrunk.ours,
+                            shrunk.theirs,
+                            shrunk.input
+                        );
+                    }
+                    return Ok(());
+                }
+            }
+            println!("Agree on {} inputs", inputs.len());
+        }
+        Command::Gen {
+            year,
+            day,
+            seed,
+            size,
+            output,
+        } => {
+            let generated = get_solver(year, day)?.generate(seed, size);
+
+            match output {
+                Some(output) => {
+                    let answers = generated
+                        .answers
+                        .iter()
+                        .map(|answer| answer.as_deref().unwrap_or("N.A."))
+                        .collect::<Vec<_>>();
+
+                    fs::write(&output, generated.input + "\n")?;
+                    fs::write(format!("{output}.answers"), answers.join("\n") + "\n")?;
+                }
+                None => println!("{}", generated.input),
+            }
+        }
+        Command::Cache {
+            command: CacheCommand::List { year, day },
+        } => {
+            let config = Config::discover()?;
+            let path = config.answer_cache_path();
+            let entries = cache::load(&path)?
+                .into_iter()
+                .filter(|entry| entry.key.is_of(year, day))
+                .collect::<Vec<_>>();
+            if entries.is_empty() {
+                println!("No answers cached in {}", path.display());
+            }
+            for entry in entries {
+                let key = &entry.key;
+                let stale = !get_solver(key.year, key.day)
+                    .is_ok_and(|solver| solver.version == key.version);
+                println!(
+                    "{} day {} part {}: {} for input {}, version {}{}",
+                    key.year,
+                    key.day,
+                    key.part,
+                    entry.answer,
+                    key.input_hash,
+                    key.version,
+                    if stale { " (stale)" } else { "" }
+                );
+            }
+        }
+        Command::Cache {
+            command: CacheCommand::Purge { year, day },
+        } => {
+            let config = Config::discover()?;
+            let purged = cache::purge(&config.answer_cache_path(), year, day)?;
+            println!("Purged {purged} cached answers");
+        }
+        Command::Serve {
+            port,
+            timeout,
+            threads,
+            solves,
+        } => {
+            parallel::set_threads(threads)?;
+            let config = Config::discover()?;
+            let timeout = timeout.or(config.timeout);
+
+            serve::serve(port, config, timeout, solves)?;
+        }
+        Command::Examples {
+            command: ExamplesCommand::Import { year, day, page },
+        } => {
+            get_solver(year, day)?;
+            let html = read_input(&PathBuf::from(page))?;
+            let examples = examples::parse_page(&html);
+            if examples.is_empty() {
+                return Err(anyhow!("No examples with answers found"));
+            }
+
+            let paths = examples::write_fixtures(&examples::fixture_dir(year, day), &examples)?;
+            for (path, example) in paths.iter().zip(&examples) {
+                let answers = example
+                    .answers
+                    .iter()
+                    .map(|answer| answer.as_deref().unwrap_or("N.A."))
+                    .collect::<Vec<_>>();
+                println!("{}: {}", path.display(), answers.join(", "));
+            }
+        }
+        Command::Config => {
+            match find_config(&env::current_dir()?) {
+                Some(path) => println!("Config: {}", path.display()),
+                None => println!("Config: none, using defaults"),
+            }
+
+            let config = Config::discover()?;
+            println!(
+                "Inputs: {}",
+                config
+                    .root
+                    .join(&config.input_dir)
+                    .join(&config.input_file)
+                    .display()
+            );
+            println!("Format: {:?}", config.format);
+            match config.timeout {
+                Some(timeout) => println!("Timeout: {timeout}s"),
+                None => println!("Timeout: none"),
+            }
+            match (&config.session, config.session_token()) {
+                (None, _) => println!("Session token: not configured"),
+                (Some(session), Ok(_)) => println!("Session token: {session:?}, found"),
+                (Some(session), Err(_)) => println!("Session token: {session:?}, missing"),
+            }
+            println!("Perf history: {}", config.perf_history_path().display());
+            println!("Regression threshold: {}x", config.regression_threshold);
+            println!("Answer cache: {}", config.answer_cache_path().display());
+            let mut years = config.days.keys().collect::<Vec<_>>();
+            years.sort();
+            for year in years {
+                let mut days = config.days[year].keys().collect::<Vec<_>>();
+                days.sort_by_key(|day| day.parse::<u8>().unwrap_or_default());
+                for day in days {
+                    println!("{year} day {day} settings: {:?}", config.days[year][day]);
+                }
+            }
+        }
+        Command::Perf {
+            command:
+                PerfCommand::Report {
+                    year,
+                    day,
+                    window,
+                    threshold,
+                },
+        } => {
+            let config = Config::discover()?;
+            let path = config.perf_history_path();
+            let records = perf::load(&path)?
+                .into_iter()
+                .filter(|record| {
+                    year.is_none_or(|year| record.year == year)
+                        && day.is_none_or(|day| record.day == day)
+                })
+                .collect::<Vec<_>>();
+            if records.is_empty() {
+                println!("No runs recorded in {}", path.display());
+            } else {
+                print!(
+                    "{}",
+                    perf::report(
+                        &records,
+                        window,
+                        threshold.unwrap_or(config.regression_threshold)
+                    )
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(error) = run(Args::parse().command) {
+        println!("{error:?}");
+    }
+}
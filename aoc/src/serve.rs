@@ -0,0 +1,428 @@
+use std::{
+    io::Read,
+    num::NonZero,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+    time::Instant,
+};
+
+use anyhow::{Result, anyhow};
+use serde_json::{Value, json};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use cli::{ErrorKind, FIRST_YEAR, Unsound, config::Config, trim_newlines_in_place, with_timeout};
+
+use aoc::get_solver;
+
+/// The largest puzzle input that is solved, in bytes. Real inputs are tens of kilobytes.
+const MAX_INPUT_LEN: usize = 1 << 20;
+
+/// Answers `POST /solve/{year}/{day}/{part}` on localhost at port until the process is killed,
+/// solving the body of each request as the puzzle input. `POST /solve/{day}/{part}` is a day of
+/// the [FIRST_YEAR].
+///
+/// A request may override timeout, in seconds, with `?timeout=<SECONDS>`.
+///
+/// At most solves puzzles are solved at once, where 0 is one per CPU. A solve that times out
+/// cannot be stopped, so it still counts until it finishes, and requests are answered with 503
+/// while timed out solves take up every place.
+pub fn serve(port: u16, config: Config, timeout: Option<u64>, solves: usize) -> Result<()> {
+    let server = Server::http(("127.0.0.1", port))
+        .map_err(|error| anyhow!("Cannot listen on port {}: {}", port, error))?;
+    eprintln!("Listening on http://{}", server.server_addr());
+
+    let solves = match solves {
+        0 => thread::available_parallelism().map_or(1, NonZero::get),
+        solves => solves,
+    };
+    listen(server, Arc::new(config), Permits::new(solves), timeout);
+
+    Ok(())
+}
+
+/// Answers the requests to server on a thread per permit, so that a slow solve does not hold up
+/// the others.
+fn listen(server: Server, config: Arc<Config>, permits: Permits, timeout: Option<u64>) {
+    let server = Arc::new(server);
+    let workers = (0..permits.count)
+        .map(|_| {
+            let server = Arc::clone(&server);
+            let config = Arc::clone(&config);
+            let permits = permits.clone();
+            thread::spawn(move || {
+                while let Ok(request) = server.recv() {
+                    if let Err(error) = answer(request, &config, &permits, timeout) {
+                        eprintln!("Cannot respond: {error}");
+                    }
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for worker in workers {
+        let _ = worker.join();
+    }
+}
+
+/// The places of the solves that may run at once, shared by the workers and the solves they start.
+#[derive(Clone)]
+struct Permits {
+    /// The places that are free.
+    free: Arc<AtomicUsize>,
+
+    count: usize,
+}
+
+/// A place taken by a solve, given back when it is dropped.
+struct Permit(Arc<AtomicUsize>);
+
+impl Permits {
+    fn new(count: usize) -> Self {
+        Self {
+            free: Arc::new(AtomicUsize::new(count)),
+            count,
+        }
+    }
+
+    /// Takes a place, if any is free.
+    fn try_acquire(&self) -> Option<Permit> {
+        self.free
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |count| {
+                count.checked_sub(1)
+            })
+            .ok()
+            .map(|_| Permit(Arc::clone(&self.free)))
+    }
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::AcqRel);
+    }
+}
+
+fn answer(
+    mut request: Request,
+    config: &Config,
+    permits: &Permits,
+    timeout: Option<u64>,
+) -> Result<()> {
+    let (status, body) = respond(&mut request, config, permits, timeout);
+    let content_type = Header::from_bytes("Content-Type", "application/json")
+        .map_err(|_| anyhow!("Invalid header"))?;
+
+    request.respond(
+        Response::from_string(body.to_string())
+            .with_status_code(status)
+            .with_header(content_type),
+    )?;
+
+    Ok(())
+}
+
+/// Gets the status code and the JSON body of the response to request.
+fn respond(
+    request: &mut Request,
+    config: &Config,
+    permits: &Permits,
+    timeout: Option<u64>,
+) -> (u16, Value) {
+    let url = request.url().to_string();
+    let (path, query) = url.split_once('?').unwrap_or((&url, ""));
+    let segments = path.split('/').collect::<Vec<_>>();
+    let (year, day, part) = match segments.as_slice() {
+        ["", "solve", year, day, part] => (year.parse::<u16>().ok(), *day, *part),
+        // The route from before years were added, kept for the tools that use it.
+        ["", "solve", day, part] => (Some(FIRST_YEAR), *day, *part),
+        _ => return (404, json!({ "error": format!("Not found: {path}") })),
+    };
+    if *request.method() != Method::Post {
+        return (405, json!({ "error": "Expected POST" }));
+    }
+
+    let (Some(year), Ok(day), Ok(part)) = (year, day.parse::<u8>(), part.parse::<u8>()) else {
+        return (
+            400,
+            json!({ "error": format!("Invalid year, day or part: {path}") }),
+        );
+    };
+    if !(1..=2).contains(&part) {
+        return (
+            404,
+            json!({ "error": format!("Invalid part number: {part}") }),
+        );
+    }
+    let solver = match get_solver(year, day) {
+        Ok(solver) => solver,
+        Err(error) => return (404, json!({ "error": error.to_string() })),
+    };
+    let timeout = match parse_timeout(query) {
+        Ok(Some(timeout)) => Some(timeout),
+        Ok(None) => timeout,
+        Err(error) => return (400, json!({ "error": error.to_string() })),
+    };
+
+    let mut input = match read_input(request) {
+        Ok(input) => input,
+        Err(response) => return response,
+    };
+    trim_newlines_in_place(&mut input);
+
+    let Some(permit) = permits.try_acquire() else {
+        return (
+            503,
+            json!({ "error": "Too many solves running, including timed out ones" }),
+        );
+    };
+    let settings = config.settings(year, day);
+
+    let start = Instant::now();
+    let answer = with_timeout(timeout, move || {
+        let _permit = permit;
+
+        solver.solve_with(part, &input, &settings)
+    });
+    let nanos = u64::try_from(start.elapsed().as_nanos()).unwrap_or(u64::MAX);
+
+    match answer {
+        Ok(answer) => (
+            200,
+            json!({ "year": year, "day": day, "part": part, "answer": answer, "nanos": nanos }),
+        ),
+        Err(error) => {
+            let kind = (solver.classify)(&error);
+            let status = match (error.downcast_ref::<Unsound>(), kind) {
+                (Some(Unsound::TimedOut(_)), _) => 504,
+                (Some(Unsound::Panicked), _) => 500,
+                (None, Some(ErrorKind::Parse)) => 400,
+                (None, Some(ErrorKind::Unsolvable)) => 409,
+                (None, _) => 422,
+            };
+
+            (
+                status,
+                json!({ "year": year, "day": day, "part": part, "error": error.to_string(), "kind": kind, "nanos": nanos }),
+            )
+        }
+    }
+}
+
+/// Reads the body of request as the puzzle input, or gets the response if it is too long or is not
+/// text.
+fn read_input(request: &mut Request) -> Result<String, (u16, Value)> {
+    let too_long = || {
+        (
+            413,
+            json!({ "error": format!("Input is longer than {MAX_INPUT_LEN} bytes") }),
+        )
+    };
+    if request.body_length().is_some_and(|len| len > MAX_INPUT_LEN) {
+        return Err(too_long());
+    }
+
+    let mut input = Vec::new();
+    request
+        .as_reader()
+        .take(MAX_INPUT_LEN as u64 + 1)
+        .read_to_end(&mut input)
+        .map_err(|error| {
+            (
+                400,
+                json!({ "error": format!("Cannot read input: {error}") }),
+            )
+        })?;
+    if input.len() > MAX_INPUT_LEN {
+        return Err(too_long());
+    }
+
+    String::from_utf8(input).map_err(|error| {
+        (
+            400,
+            json!({ "error": format!("Cannot read input: {error}") }),
+        )
+    })
+}
+
+/// Gets the timeout in the query string of a request, if any.
+fn parse_timeout(query: &str) -> Result<Option<u64>> {
+    query
+        .split('&')
+        .find_map(|param| param.strip_prefix("timeout="))
+        .map(|timeout| {
+            timeout
+                .parse()
+                .map_err(|_| anyhow!("Invalid timeout: {}", timeout))
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::Write,
+        net::{SocketAddr, TcpStream},
+    };
+
+    use super::*;
+
+    /// Starts a server on a free port of localhost, answering in the background.
+    fn start() -> Result<SocketAddr> {
+        start_with(Permits::new(4))
+    }
+
+    /// Starts a server that runs as many solves at once as there are permits.
+    fn start_with(permits: Permits) -> Result<SocketAddr> {
+        let server = Server::http("127.0.0.1:0").map_err(|error| anyhow!("{}", error))?;
+        let addr = server
+            .server_addr()
+            .to_ip()
+            .ok_or_else(|| anyhow!("Not listening on IP"))?;
+        thread::spawn(move || listen(server, Arc::new(Config::default()), permits, None));
+
+        Ok(addr)
+    }
+
+    /// Sends a request, getting the status code and the JSON body of the response.
+    fn send(addr: SocketAddr, method: &str, path: &str, body: &str) -> Result<(u16, Value)> {
+        let mut stream = TcpStream::connect(addr)?;
+        write!(
+            stream,
+            "{method} {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        )?;
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+        let (head, body) = response
+            .split_once("\r\n\r\n")
+            .ok_or_else(|| anyhow!("Invalid response: {}", response))?;
+        let status = head
+            .split_whitespace()
+            .nth(1)
+            .ok_or_else(|| anyhow!("Invalid status line: {}", head))?
+            .parse()?;
+
+        Ok((status, serde_json::from_str(body)?))
+    }
+
+    #[test]
+    fn solve() -> Result<()> {
+        let addr = start()?;
+        let (status, body) = send(
+            addr,
+            "POST",
+            "/solve/2023/1/1",
+            "\n1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n",
+        )?;
+
+        assert_eq!(status, 200);
+        assert_eq!(body["answer"], "142");
+        assert_eq!(
+            (
+                body["year"].as_u64(),
+                body["day"].as_u64(),
+                body["part"].as_u64()
+            ),
+            (Some(2023), Some(1), Some(1))
+        );
+        assert!(body["nanos"].is_u64());
+
+        Ok(())
+    }
+
+    #[test]
+    fn solve_without_year() -> Result<()> {
+        let addr = start()?;
+        let (status, body) = send(addr, "POST", "/solve/1/1", "1abc2\npqr3stu8vwx")?;
+
+        assert_eq!(status, 200);
+        assert_eq!(body["answer"], "50");
+        assert_eq!(body["year"].as_u64(), Some(2023));
+        assert_eq!(send(addr, "POST", "/solve/one/1", "")?.0, 400);
+
+        Ok(())
+    }
+
+    #[test]
+    fn errors() -> Result<()> {
+        let addr = start()?;
+
+        let (status, body) = send(addr, "POST", "/solve/2023/1/1", "abc")?;
+        assert_eq!(status, 422);
+        assert_eq!(body["error"], "Cannot find first digit in \"abc\"");
+        assert_eq!(body["kind"], "validation");
+
+        let (status, body) = send(addr, "POST", "/solve/2023/2/1", "abc")?;
+        assert_eq!(status, 400);
+        assert_eq!(body["error"], "Cannot split input into id and draws: abc");
+        assert_eq!(body["kind"], "parse");
+
+        let (status, body) = send(addr, "POST", "/solve/2023/13/1", "#.\n.#")?;
+        assert_eq!(status, 409);
+        assert_eq!(body["kind"], "unsolvable");
+
+        assert_eq!(send(addr, "POST", "/solve/2023/26/1", "")?.0, 404);
+        assert_eq!(send(addr, "POST", "/solve/2015/1/1", "")?.0, 404);
+        assert_eq!(send(addr, "POST", "/solve/2023/1/3", "")?.0, 404);
+        assert_eq!(send(addr, "POST", "/solve/2023/one/1", "")?.0, 400);
+        assert_eq!(send(addr, "POST", "/solve/1", "")?.0, 404);
+        assert_eq!(
+            send(addr, "POST", "/solve/2023/1/1?timeout=soon", "1")?.0,
+            400
+        );
+        assert_eq!(send(addr, "GET", "/solve/2023/1/1", "")?.0, 405);
+        assert_eq!(send(addr, "POST", "/answers", "")?.0, 404);
+
+        Ok(())
+    }
+
+    #[test]
+    fn timeout() -> Result<()> {
+        let addr = start()?;
+        // The smallest lattice of trails: a zero timeout gives up before the solve can reply, and
+        // the solve left running in the background finishes almost at once.
+        let input = day_23::SOLVER.generate(0, 2).input;
+        let (status, body) = send(addr, "POST", "/solve/2023/23/2?timeout=0", &input)?;
+
+        assert_eq!(status, 504);
+        assert_eq!(body["error"], "Timed out after 0s");
+
+        Ok(())
+    }
+
+    #[test]
+    fn too_long() -> Result<()> {
+        let addr = start()?;
+        let input = "1".repeat(MAX_INPUT_LEN + 1);
+        let (status, body) = send(addr, "POST", "/solve/2023/1/1", &input)?;
+
+        assert_eq!(status, 413);
+        assert_eq!(body["error"], "Input is longer than 1048576 bytes");
+
+        Ok(())
+    }
+
+    #[test]
+    fn busy() -> Result<()> {
+        let permits = Permits::new(1);
+        let addr = start_with(permits.clone())?;
+        // As a solve that timed out but is still running would.
+        let permit = permits.try_acquire();
+        let (status, body) = send(addr, "POST", "/solve/2023/1/1", "1")?;
+
+        assert_eq!(status, 503);
+        assert_eq!(
+            body["error"],
+            "Too many solves running, including timed out ones"
+        );
+
+        drop(permit);
+
+        assert_eq!(send(addr, "POST", "/solve/2023/1/1", "1")?.0, 200);
+
+        Ok(())
+    }
+}
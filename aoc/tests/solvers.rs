@@ -0,0 +1,128 @@
+//! Checks every [Solver] in the registry against its examples and against generated inputs.
+
+use anyhow::{Result, anyhow};
+
+use aoc::SOLVERS;
+use cli::{Solver, examples};
+
+/// The size of the generated inputs of a day, small enough to be solved quickly, and the num of
+/// seeds to generate answers from, and to run every other check on.
+struct Sizes {
+    size: usize,
+    answer_seeds: u64,
+    seeds: u64,
+}
+
+/// The [Sizes] of the given day.
+fn sizes(solver: &Solver) -> Result<Sizes> {
+    let (size, answer_seeds, seeds) = match (solver.year, solver.day) {
+        (2023, 1 | 2 | 12 | 19 | 24) => (50, 20, 5),
+        (2023, 3) => (30, 20, 5),
+        (2023, 4 | 7) => (100, 20, 5),
+        (2023, 5 | 14) => (10, 20, 5),
+        (2023, 6) => (3, 20, 5),
+        (2023, 8) => (6, 20, 5),
+        (2023, 9 | 15) => (200, 20, 5),
+        (2023, 10 | 11 | 13 | 16 | 17 | 18 | 25) => (20, 20, 5),
+        (2023, 20) => (4, 5, 2),
+        (2023, 21) => (41, 20, 5),
+        (2023, 22) => (40, 20, 5),
+        (2023, 23) => (5, 10, 5),
+        (year, day) => return Err(anyhow!("No sizes for day {} of {}", day, year)),
+    };
+
+    Ok(Sizes {
+        size,
+        answer_seeds,
+        seeds,
+    })
+}
+
+/// Runs check on every solver with its [Sizes], naming the day of the first that fails.
+fn check_every_solver(check: impl Fn(&Solver, Sizes) -> Result<()>) -> Result<()> {
+    for solver in SOLVERS {
+        check(solver, sizes(solver)?)
+            .map_err(|error| anyhow!("Day {} of {}: {}", solver.day, solver.year, error))?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn examples() -> Result<()> {
+    check_every_solver(|solver, _| {
+        let checked = solver.verify_examples(&examples::fixture_dir(solver.year, solver.day))?;
+
+        // Day 21 of 2023 only has an example for 6 steps, where part 1 takes 64.
+        if checked == 0 && (solver.year, solver.day) != (2023, 21) {
+            return Err(anyhow!("No examples checked"));
+        }
+
+        Ok(())
+    })
+}
+
+#[test]
+fn generated() -> Result<()> {
+    check_every_solver(|solver, sizes| {
+        for seed in 0..sizes.answer_seeds {
+            solver.verify_generated(seed, sizes.size)?;
+        }
+
+        Ok(())
+    })
+}
+
+#[test]
+fn malformed() -> Result<()> {
+    check_every_solver(|solver, sizes| {
+        for seed in 0..sizes.seeds {
+            solver.verify_malformed(seed, sizes.size, 10)?;
+        }
+
+        Ok(())
+    })
+}
+
+#[test]
+fn stream() -> Result<()> {
+    check_every_solver(|solver, sizes| {
+        if solver.stream.is_none() {
+            return Ok(());
+        }
+
+        for seed in 0..sizes.seeds {
+            solver.verify_stream(seed, sizes.size)?;
+        }
+
+        Ok(())
+    })
+}
+
+#[test]
+fn certified() -> Result<()> {
+    check_every_solver(|solver, sizes| {
+        let Some(certifier) = solver.certify else {
+            return Ok(());
+        };
+
+        for seed in 0..sizes.seeds {
+            for &part in certifier.parts {
+                solver.verify_certified(part, seed, sizes.size)?;
+            }
+        }
+
+        Ok(())
+    })
+}
+
+#[test]
+fn threads() -> Result<()> {
+    check_every_solver(|solver, sizes| {
+        for seed in 0..sizes.seeds {
+            solver.verify_threads(seed, sizes.size)?;
+        }
+
+        Ok(())
+    })
+}
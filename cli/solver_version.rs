@@ -0,0 +1,124 @@
+//! The build script of every day, shared through `build` in their Cargo.toml.
+//!
+//! Sets `SOLVER_VERSION` to a hash of the sources of the day's crate and of every crate of the
+//! workspace it depends on, directly or not, so that editing any of them gives the day a new
+//! version and its cached answers are not reused.
+
+use std::{
+    collections::BTreeSet,
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use toml::{Table, Value};
+
+/// As in cli::perf, which a build script cannot depend on.
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+
+fn fnv_1a(hash: u64, byte: u8) -> u64 {
+    (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+}
+
+fn main() -> io::Result<()> {
+    let dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").map_err(io::Error::other)?);
+    let workspace = dir
+        .parent()
+        .ok_or_else(|| io::Error::other("Day crate is not in a workspace"))?
+        .canonicalize()?;
+    let workspace = workspace.as_path();
+    let workspace_dependencies = read_manifest(&workspace.join("Cargo.toml"))?
+        .get("workspace")
+        .and_then(|workspace| workspace.get("dependencies"))
+        .and_then(Value::as_table)
+        .cloned()
+        .unwrap_or_default();
+
+    let mut crates = BTreeSet::new();
+    collect_crates(&dir, workspace, &workspace_dependencies, &mut crates)?;
+
+    let mut hash = FNV_OFFSET_BASIS;
+    for dir in crates {
+        println!(
+            "cargo::rerun-if-changed={}",
+            dir.join("Cargo.toml").display()
+        );
+        println!("cargo::rerun-if-changed={}", dir.join("src").display());
+
+        let mut paths = vec![dir.join("Cargo.toml")];
+        collect_files(&dir.join("src"), &mut paths)?;
+        paths.sort();
+
+        for path in paths {
+            let relative = path.strip_prefix(workspace).map_err(io::Error::other)?;
+            let contents = fs::read(&path)?;
+            hash = relative
+                .to_string_lossy()
+                .bytes()
+                .chain([0])
+                .fold(hash, fnv_1a);
+            hash = contents.into_iter().chain([0]).fold(hash, fnv_1a);
+        }
+    }
+
+    println!("cargo::rustc-env=SOLVER_VERSION={hash:016x}");
+
+    Ok(())
+}
+
+fn read_manifest(path: &Path) -> io::Result<Table> {
+    fs::read_to_string(path)?.parse().map_err(io::Error::other)
+}
+
+/// Collects the dir of the crate in dir and of every crate it depends on by path into crates,
+/// whether the path is given by the crate or by the workspace.
+fn collect_crates(
+    dir: &Path,
+    workspace: &Path,
+    workspace_dependencies: &Table,
+    crates: &mut BTreeSet<PathBuf>,
+) -> io::Result<()> {
+    let dir = dir.canonicalize()?;
+    if !crates.insert(dir.clone()) {
+        return Ok(());
+    }
+
+    let manifest = read_manifest(&dir.join("Cargo.toml"))?;
+    let dependencies = manifest
+        .get("dependencies")
+        .and_then(Value::as_table)
+        .cloned()
+        .unwrap_or_default();
+    for (name, dependency) in dependencies {
+        let path = if dependency.get("workspace").and_then(Value::as_bool) == Some(true) {
+            workspace_dependencies
+                .get(&name)
+                .and_then(|dependency| dependency.get("path"))
+                .and_then(Value::as_str)
+                .map(|path| workspace.join(path))
+        } else {
+            dependency
+                .get("path")
+                .and_then(Value::as_str)
+                .map(|path| dir.join(path))
+        };
+        if let Some(path) = path {
+            collect_crates(&path, workspace, workspace_dependencies, crates)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Collects the path of every file under dir into paths.
+fn collect_files(dir: &Path, paths: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(&path, paths)?;
+        } else {
+            paths.push(path);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,186 @@
+use std::{
+    io::{self, IsTerminal, Write},
+    time::Duration,
+};
+
+use anyhow::Result;
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::Print,
+    terminal,
+};
+
+/// One step of a simulation, drawn as text.
+pub struct Frame {
+    pub caption: String,
+    pub text: String,
+}
+
+impl Frame {
+    pub fn new(caption: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            caption: caption.into(),
+            text: text.into(),
+        }
+    }
+}
+
+/// The frames of a simulation, produced as they are played.
+pub type Frames<'a> = Box<dyn Iterator<Item = Frame> + 'a>;
+
+const CONTROLS: &str = "[space] pause  [n/→] step  [p/←] back  [+/-] speed  [q] quit";
+
+/// Plays the frames in the terminal, waiting delay between frames. When stdout is not a terminal,
+/// every frame is written out in turn instead.
+pub fn play(frames: Frames, delay: Duration) -> Result<()> {
+    let mut stdout = io::stdout();
+    if stdout.is_terminal() {
+        play_in_terminal(frames, delay, &mut stdout)
+    } else {
+        dump(frames, &mut stdout.lock())
+    }
+}
+
+/// Writes every frame as plain text, each under a header line.
+pub fn dump(frames: Frames, writer: &mut impl Write) -> Result<()> {
+    for (index, frame) in frames.enumerate() {
+        writeln!(writer, "--- Frame {}: {}", index + 1, frame.caption)?;
+        writeln!(writer, "{}", frame.text)?;
+    }
+
+    Ok(())
+}
+
+/// Switches the terminal to raw mode on an alternate screen, and back again when dropped.
+struct RawTerminal;
+
+impl RawTerminal {
+    fn enter(stdout: &mut impl Write) -> Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+        Ok(Self)
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn play_in_terminal(
+    mut frames: Frames,
+    mut delay: Duration,
+    stdout: &mut impl Write,
+) -> Result<()> {
+    let _terminal = RawTerminal::enter(stdout)?;
+
+    // Played frames are kept so that playback can step back.
+    let mut history = Vec::new();
+    let mut index = 0;
+    let mut paused = false;
+    loop {
+        while history.len() <= index {
+            let Some(frame) = frames.next() else {
+                break;
+            };
+            history.push(frame);
+        }
+        if history.is_empty() {
+            return Ok(());
+        }
+        if index >= history.len() {
+            // Hold the last frame until the user quits.
+            index = history.len() - 1;
+            paused = true;
+        }
+
+        draw(&history[index], index, paused, delay, stdout)?;
+
+        let event = if paused || event::poll(delay)? {
+            Some(event::read()?)
+        } else {
+            index += 1;
+            None
+        };
+        let Some(Event::Key(KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+            ..
+        })) = event
+        else {
+            continue;
+        };
+
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => return Ok(()),
+            KeyCode::Char(' ') => paused = !paused,
+            KeyCode::Char('n') | KeyCode::Right => {
+                paused = true;
+                index += 1;
+            }
+            KeyCode::Char('p') | KeyCode::Left => {
+                paused = true;
+                index = index.saturating_sub(1);
+            }
+            KeyCode::Char('+') => delay = (delay / 2).max(Duration::from_millis(1)),
+            KeyCode::Char('-') => delay = (delay * 2).min(Duration::from_secs(10)),
+            _ => (),
+        }
+    }
+}
+
+fn draw(
+    frame: &Frame,
+    index: usize,
+    paused: bool,
+    delay: Duration,
+    stdout: &mut impl Write,
+) -> Result<()> {
+    let status = if paused { "paused" } else { "playing" };
+    queue!(
+        stdout,
+        cursor::MoveTo(0, 0),
+        terminal::Clear(terminal::ClearType::All),
+        Print(format!(
+            "Frame {}: {}\r\n{} every {}ms  {}\r\n\r\n",
+            index + 1,
+            frame.caption,
+            status,
+            delay.as_millis(),
+            CONTROLS
+        )),
+    )?;
+    // Raw mode does not return the cursor to the start of the line.
+    for line in frame.text.lines() {
+        queue!(stdout, Print(line), Print("\r\n"))?;
+    }
+    stdout.flush()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dump_frames() -> Result<()> {
+        let frames = vec![Frame::new("start", "#.\n.."), Frame::new("end", "..\n.#")];
+        let mut output = Vec::new();
+        dump(Box::new(frames.into_iter()), &mut output)?;
+
+        assert_eq!(
+            String::from_utf8(output)?,
+            "--- Frame 1: start\n#.\n..\n--- Frame 2: end\n..\n.#\n"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,153 @@
+use std::{
+    any::type_name,
+    fmt::Display,
+    ops::{Div, Rem},
+};
+
+use anyhow::Result;
+use thiserror::Error;
+
+use crate::config::Settings;
+
+/// The setting that has answers accumulated in u128 (or i128) instead of the day's usual type.
+pub const WIDE: &str = "wide";
+
+/// An answer that does not fit in the integer type it is accumulated in.
+#[derive(Clone, Debug, Error, PartialEq)]
+#[error("Answer overflows {0}, set {WIDE} to accumulate in 128 bits")]
+pub struct Overflow(pub &'static str);
+
+/// An integer type that answers are accumulated in, with arithmetic that reports [Overflow]
+/// rather than wrapping.
+pub trait Answer:
+    Copy + Default + Display + Ord + From<u8> + Send + Div<Output = Self> + Rem<Output = Self>
+{
+    fn try_add(self, other: Self) -> Result<Self, Overflow>;
+    fn try_sub(self, other: Self) -> Result<Self, Overflow>;
+    fn try_mul(self, other: Self) -> Result<Self, Overflow>;
+    fn try_pow(self, exp: u32) -> Result<Self, Overflow>;
+}
+
+macro_rules! impl_answer {
+    ($($t:ty),*) => {
+        $(
+            impl Answer for $t {
+                fn try_add(self, other: Self) -> Result<Self, Overflow> {
+                    self.checked_add(other).ok_or(Overflow(stringify!($t)))
+                }
+
+                fn try_sub(self, other: Self) -> Result<Self, Overflow> {
+                    self.checked_sub(other).ok_or(Overflow(stringify!($t)))
+                }
+
+                fn try_mul(self, other: Self) -> Result<Self, Overflow> {
+                    self.checked_mul(other).ok_or(Overflow(stringify!($t)))
+                }
+
+                fn try_pow(self, exp: u32) -> Result<Self, Overflow> {
+                    self.checked_pow(exp).ok_or(Overflow(stringify!($t)))
+                }
+            }
+        )*
+    };
+}
+
+impl_answer!(u32, u64, u128, usize, i64, i128, isize);
+
+/// Converts a value to the [Answer] type, which may be narrower.
+pub fn widen<A, T>(value: T) -> Result<A, Overflow>
+where
+    A: Answer + TryFrom<T>,
+{
+    A::try_from(value).map_err(|_| Overflow(type_name::<A>()))
+}
+
+/// Sums the items, failing on [Overflow].
+pub fn try_sum<A: Answer>(items: impl IntoIterator<Item = A>) -> Result<A, Overflow> {
+    items
+        .into_iter()
+        .try_fold(A::default(), |sum, item| sum.try_add(item))
+}
+
+/// Multiplies the items, failing on [Overflow].
+pub fn try_product<A: Answer>(items: impl IntoIterator<Item = A>) -> Result<A, Overflow> {
+    items
+        .into_iter()
+        .try_fold(A::from(1), |product, item| product.try_mul(item))
+}
+
+/// Finds the greatest common divisor of a and b.
+pub fn gcd<A: Answer>(mut a: A, mut b: A) -> A {
+    while b != A::default() {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Finds the least common multiple of a and b, failing on [Overflow].
+pub fn try_lcm<A: Answer>(a: A, b: A) -> Result<A, Overflow> {
+    if a == A::default() || b == A::default() {
+        return Ok(A::default());
+    }
+
+    (a / gcd(a, b)).try_mul(b)
+}
+
+/// Whether answers are accumulated in 128 bits, as set by [WIDE] in the [Settings].
+pub fn is_wide(settings: &Settings) -> Result<bool> {
+    Ok(settings.get(WIDE)?.unwrap_or(false))
+}
+
+/// Gets the answer from narrow, or from wide if [is_wide].
+pub fn with_width<N, W, E>(
+    settings: &Settings,
+    narrow: impl FnOnce() -> Result<N, E>,
+    wide: impl FnOnce() -> Result<W, E>,
+) -> Result<String>
+where
+    N: Display,
+    W: Display,
+    anyhow::Error: From<E>,
+{
+    if is_wide(settings)? {
+        Ok(wide()?.to_string())
+    } else {
+        Ok(narrow()?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked() {
+        assert_eq!(try_sum([u32::MAX - 1, 1]), Ok(u32::MAX));
+        assert_eq!(try_sum([u32::MAX, 1]), Err(Overflow("u32")));
+        assert_eq!(try_product([1u64 << 32, 1 << 31]), Ok(1 << 63));
+        assert_eq!(try_product([1u64 << 32, 1 << 32]), Err(Overflow("u64")));
+        assert_eq!(try_sum::<i64>([]), Ok(0));
+        assert_eq!(try_product::<u128>([]), Ok(1));
+        assert_eq!(2u32.try_pow(32), Err(Overflow("u32")));
+        assert_eq!(0u64.try_sub(1), Err(Overflow("u64")));
+        assert_eq!(widen::<u32, _>(1u64 << 32), Err(Overflow("u32")));
+        assert_eq!(widen::<u128, _>(u64::MAX), Ok(u128::from(u64::MAX)));
+        assert_eq!(gcd(12u64, 18), 6);
+        assert_eq!(try_lcm(12u64, 18), Ok(36));
+        assert_eq!(try_lcm(0u64, 18), Ok(0));
+        assert_eq!(try_lcm(u64::MAX, u64::MAX - 1), Err(Overflow("u64")));
+    }
+
+    #[test]
+    fn width() -> Result<()> {
+        let narrow = || Ok::<_, Overflow>(1u32);
+        let wide = || Ok::<_, Overflow>(2u128);
+
+        assert_eq!(with_width(&Settings::default(), narrow, wide)?, "1");
+        assert_eq!(with_width(&"wide = true".parse()?, narrow, wide)?, "2");
+        assert!(with_width(&"wide = 1".parse()?, narrow, wide).is_err());
+
+        Ok(())
+    }
+}
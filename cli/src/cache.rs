@@ -0,0 +1,219 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+use crate::{FIRST_YEAR, config::Settings, perf::hash_input};
+
+/// What an answer depends on. An answer is only reused for the same [Key].
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Key {
+    #[serde(default = "first_year")]
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+
+    /// The [hash_input] of the puzzle input.
+    pub input_hash: String,
+
+    /// The [crate::Solver::version] of the day.
+    pub version: String,
+
+    /// The [hash_input] of the day's [Settings], as they may change the answer.
+    pub settings_hash: String,
+}
+
+impl Key {
+    /// Creates the [Key] of the given part of a day at the given version on the input with the
+    /// given [hash_input].
+    pub fn new(
+        year: u16,
+        day: u8,
+        part: u8,
+        input_hash: String,
+        version: &str,
+        settings: &Settings,
+    ) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            input_hash,
+            version: version.to_string(),
+            settings_hash: hash_input(&settings.to_string()),
+        }
+    }
+
+    /// Whether the [Key] is of the given year and day, where None is any.
+    pub fn is_of(&self, year: Option<u16>, day: Option<u8>) -> bool {
+        year.is_none_or(|year| self.year == year) && day.is_none_or(|day| self.day == day)
+    }
+}
+
+/// Answers cached before years were recorded are of the [FIRST_YEAR].
+fn first_year() -> u16 {
+    FIRST_YEAR
+}
+
+/// One answer, as kept in the answer cache.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Entry {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+
+    #[serde(flatten)]
+    pub key: Key,
+
+    pub answer: String,
+}
+
+impl Entry {
+    /// Creates the [Entry] of the answer for the given [Key], timestamped now.
+    pub fn new(key: Key, answer: String) -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |since| since.as_secs()),
+            key,
+            answer,
+        }
+    }
+}
+
+/// Gets the answer for the [Key] from the cache file at path, if it is there.
+pub fn lookup(path: &Path, key: &Key) -> Result<Option<String>> {
+    Ok(load(path)?
+        .into_iter()
+        .rev()
+        .find(|entry| entry.key == *key)
+        .map(|entry| entry.answer))
+}
+
+/// Appends the [Entry] to the cache file at path, one JSON object per line.
+pub fn store(path: &Path, entry: &Entry) -> Result<()> {
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|error| anyhow!("Cannot open {}: {}", path.display(), error))?;
+    writeln!(file, "{}", serde_json::to_string(entry)?)?;
+
+    Ok(())
+}
+
+/// Loads every [Entry] in the cache file at path, oldest first. A missing file is an empty cache.
+pub fn load(path: &Path) -> Result<Vec<Entry>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    fs::read_to_string(path)?
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line).map_err(|error| {
+                anyhow!(
+                    "Cannot parse {} line {}: {}",
+                    path.display(),
+                    index + 1,
+                    error
+                )
+            })
+        })
+        .collect()
+}
+
+/// Removes the entries of the given year and day, or of every year or day where None, from the
+/// cache file at path.
+///
+/// Returns the num of entries removed.
+pub fn purge(path: &Path, year: Option<u16>, day: Option<u8>) -> Result<usize> {
+    let entries = load(path)?;
+    let (purged, kept): (Vec<_>, Vec<_>) = entries
+        .into_iter()
+        .partition(|entry| entry.key.is_of(year, day));
+
+    if kept.is_empty() {
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+    } else {
+        let lines = kept
+            .iter()
+            .map(serde_json::to_string)
+            .collect::<Result<Vec<_>, _>>()?;
+        fs::write(path, lines.join("\n") + "\n")?;
+    }
+
+    Ok(purged.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    fn key(day: u8, part: u8, input_hash: &str) -> Key {
+        Key {
+            year: FIRST_YEAR,
+            day,
+            part,
+            input_hash: input_hash.to_string(),
+            version: String::from("version"),
+            settings_hash: hash_input(""),
+        }
+    }
+
+    #[test]
+    fn store_lookup_and_purge() -> Result<()> {
+        let path = env::temp_dir()
+            .join(format!("aoc-cache-{}", std::process::id()))
+            .join("cache.jsonl");
+        store(&path, &Entry::new(key(1, 1, "input"), String::from("1")))?;
+        store(&path, &Entry::new(key(1, 2, "input"), String::from("2")))?;
+        store(&path, &Entry::new(key(2, 1, "input"), String::from("3")))?;
+
+        let found = lookup(&path, &key(1, 2, "input"))?;
+        let missing = lookup(&path, &key(1, 2, "other"))?;
+        let purged_other_year = purge(&path, Some(2015), None)?;
+        let purged = purge(&path, Some(FIRST_YEAR), Some(1))?;
+        let kept = load(&path)?;
+        let purged_all = purge(&path, None, None)?;
+        let exists = path.exists();
+        fs::remove_dir_all(path.parent().unwrap())?;
+
+        assert_eq!(found.as_deref(), Some("2"));
+        assert_eq!(missing, None);
+        assert_eq!(purged_other_year, 0);
+        assert_eq!(purged, 2);
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].key, key(2, 1, "input"));
+        assert_eq!(purged_all, 1);
+        assert!(!exists);
+
+        Ok(())
+    }
+
+    #[test]
+    fn without_year() -> Result<()> {
+        let line = r#"{"timestamp":0,"day":1,"part":1,"input_hash":"input","version":"version","settings_hash":"cbf29ce484222325","answer":"1"}"#;
+        let entry: Entry = serde_json::from_str(line)?;
+
+        assert_eq!(entry.key, key(1, 1, "input"));
+
+        Ok(())
+    }
+}
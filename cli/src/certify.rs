@@ -0,0 +1,65 @@
+use std::fmt::Display;
+
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+/// An answer along with a witness to it, such as the path taken for a shortest path, which a
+/// checker can verify against the input without trusting the solver.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Certificate {
+    pub answer: String,
+    pub witness: Value,
+}
+
+impl Certificate {
+    pub fn new<T>(answer: impl Display, witness: &T) -> Result<Self>
+    where
+        T: Serialize,
+    {
+        Ok(Self {
+            answer: answer.to_string(),
+            witness: serde_json::to_value(witness)?,
+        })
+    }
+
+    /// Gets the witness as the day's witness type.
+    pub fn witness<T>(&self) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        T::deserialize(&self.witness).map_err(|error| anyhow!("Invalid witness: {}", error))
+    }
+
+    /// Checks that the answer is the one the checker arrived at from the witness.
+    pub fn expect_answer(&self, answer: impl Display) -> Result<()> {
+        let answer = answer.to_string();
+        if self.answer != answer {
+            return Err(anyhow!(
+                "Answer {} does not match the witness, which gives {}",
+                self.answer,
+                answer
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Solves the given part, along with a witness to the answer.
+pub type Witness = fn(&str, u8) -> Result<Certificate>;
+
+/// Checks a [Certificate] of the given part against the input.
+pub type Check = fn(&str, u8, &Certificate) -> Result<()>;
+
+/// The witnesses and the checker of a day's answers.
+#[derive(Clone, Copy)]
+pub struct Certifier {
+    /// The parts that have witnesses.
+    pub parts: &'static [u8],
+
+    pub witness: Witness,
+
+    /// Kept apart from the solver, with simple code that is obviously correct rather than fast.
+    pub check: Check,
+}
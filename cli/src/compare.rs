@@ -0,0 +1,294 @@
+use std::{
+    env, fs,
+    io::{Read, Write},
+    os::unix::process::CommandExt,
+    process::{Child, Command, Stdio},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use anyhow::{Result, anyhow};
+
+use crate::{Solver, Unsound, with_timeout};
+
+/// A solver in another repository, run as a shell command.
+///
+/// In the command, `{part}` is replaced by the part number, and `{input}` by the path of a file
+/// holding the puzzle input. Without `{input}`, the input is piped to stdin instead. The answer is
+/// the last non-empty line of stdout.
+pub struct External {
+    pub command: String,
+
+    /// In seconds, after which the command is killed, if any.
+    pub timeout: Option<u64>,
+}
+
+impl External {
+    /// Runs the command for the answer to the given part of the input.
+    pub fn solve(&self, part: u8, input: &str) -> Result<String> {
+        let mut command = self.command.replace("{part}", &part.to_string());
+        let input_path = command.contains("{input}").then(|| {
+            let run = RUNS.fetch_add(1, Ordering::Relaxed);
+            env::temp_dir().join(format!("aoc-compare-{}-{}.txt", std::process::id(), run))
+        });
+        if let Some(input_path) = &input_path {
+            fs::write(input_path, input)?;
+            command = command.replace("{input}", &input_path.to_string_lossy());
+        }
+
+        let output = Command::new("sh")
+            .args(["-c", &command])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            // A group of its own, so that whatever the command starts can be killed with it.
+            .process_group(0)
+            .spawn()
+            .map_err(|error| anyhow!("Cannot run {}: {}", command, error))
+            .and_then(|child| {
+                let piped = input_path.is_none().then(|| input.to_string());
+                self.wait(&command, child, piped)
+            });
+        if let Some(input_path) = &input_path {
+            let _ = fs::remove_file(input_path);
+        }
+
+        String::from_utf8_lossy(&output?)
+            .lines()
+            .map(str::trim)
+            .rfind(|line| !line.is_empty())
+            .map(str::to_string)
+            .ok_or_else(|| anyhow!("{} gave no answer", command))
+    }
+
+    /// Pipes input, if any, to the child running command and gets its stdout, killing it if it
+    /// runs out of time. Fails if it does not exit successfully.
+    fn wait(&self, command: &str, mut child: Child, input: Option<String>) -> Result<Vec<u8>> {
+        let stdin = child.stdin.take();
+        let stdout = child.stdout.take();
+        let stderr = child.stderr.take();
+        let output = with_timeout(self.timeout, move || {
+            // Written and read on threads of their own, so that a full pipe cannot block the
+            // other.
+            let writer = thread::spawn(move || {
+                if let (Some(mut stdin), Some(input)) = (stdin, input) {
+                    // A command that exits without reading its input is not an error.
+                    let _ = stdin.write_all(input.as_bytes());
+                }
+            });
+            let errors = thread::spawn(move || read_all(stderr));
+            let output = read_all(stdout)?;
+            let _ = writer.join();
+            let errors = errors.join().map_err(|_| anyhow!("Cannot read stderr"))??;
+
+            Ok((output, errors))
+        });
+
+        let (stdout, stderr) = match output {
+            Ok(output) => output,
+            Err(error) => {
+                if matches!(error.downcast_ref(), Some(Unsound::TimedOut(_))) {
+                    kill(&mut child);
+                }
+                return Err(anyhow!("Cannot run {}: {}", command, error));
+            }
+        };
+        let status = child
+            .wait()
+            .map_err(|error| anyhow!("Cannot run {}: {}", command, error))?;
+        if !status.success() {
+            return Err(anyhow!(
+                "{} failed with {}: {}",
+                command,
+                status,
+                String::from_utf8_lossy(&stderr).trim()
+            ));
+        }
+
+        Ok(stdout)
+    }
+}
+
+/// Reads a pipe of a child to the end, if it was piped.
+fn read_all(pipe: Option<impl Read>) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    if let Some(mut pipe) = pipe {
+        pipe.read_to_end(&mut bytes)?;
+    }
+
+    Ok(bytes)
+}
+
+/// Kills child and every process in its group, then reaps it.
+fn kill(child: &mut Child) {
+    let _ = Command::new("kill")
+        .args(["-KILL", "--", &format!("-{}", child.id())])
+        .stderr(Stdio::null())
+        .status();
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+/// Numbers the input files of concurrent runs apart.
+static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+/// Answers that differ between the [Solver] and an [External] solver.
+#[derive(Debug, PartialEq)]
+pub struct Disagreement {
+    pub part: u8,
+    pub input: String,
+    pub ours: String,
+    pub theirs: String,
+}
+
+/// Compares the answers to the given part of the input.
+///
+/// Returns the [Disagreement], if any. Fails if either solver fails, as then there is nothing to
+/// compare.
+pub fn compare(
+    solver: &Solver,
+    external: &External,
+    part: u8,
+    input: &str,
+) -> Result<Option<Disagreement>> {
+    let ours = solver.solve(part, input)?;
+    let theirs = external.solve(part, input)?;
+
+    Ok((ours != theirs).then(|| Disagreement {
+        part,
+        input: input.to_string(),
+        ours,
+        theirs,
+    }))
+}
+
+/// Shrinks a [Disagreement] on the input generated from seed at the given size: first to the
+/// smallest size that the solvers disagree on, then by removing lines for as long as they still
+/// disagree. Inputs that either solver fails on are skipped, as they are malformed.
+pub fn shrink(
+    solver: &Solver,
+    external: &External,
+    seed: u64,
+    size: usize,
+    disagreement: Disagreement,
+) -> Disagreement {
+    let part = disagreement.part;
+    let disagrees = |input: &str| compare(solver, external, part, input).ok().flatten();
+
+    let mut smallest = (1..size)
+        .find_map(|size| disagrees(&solver.generate(seed, size).input))
+        .unwrap_or(disagreement);
+
+    let mut index = 0;
+    loop {
+        let lines = smallest.input.lines().collect::<Vec<_>>();
+        if index >= lines.len() || lines.len() <= 1 {
+            break;
+        }
+
+        let mut removed = lines.clone();
+        removed.remove(index);
+        match disagrees(&removed.join("\n")) {
+            Some(disagreement) => smallest = disagreement,
+            None => index += 1,
+        }
+    }
+
+    smallest
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+
+    /// Answers part 1 with the num of lines of the input, and part 2 with the num of lines that
+    /// contain an a.
+    const SOLVER: Solver = Solver {
+        year: 0,
+        day: 0,
+        version: "0",
+        part_1: |input, _| Ok(input.lines().count().to_string()),
+        part_2: Some(|input, _| {
+            Ok(input
+                .lines()
+                .filter(|line| line.contains('a'))
+                .count()
+                .to_string())
+        }),
+        generator: |_, size| crate::Generated {
+            input: (0..size)
+                .map(|index| if index % 3 == 0 { "a" } else { "b" })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            answers: [None, None],
+        },
+        render: None,
+        animate: None,
+        dump: None,
+        stream: None,
+        certify: None,
+        report: None,
+        classify: |_| None,
+    };
+
+    #[test]
+    fn agree() -> Result<()> {
+        let stdin = External {
+            command: String::from("wc -l | tr -d ' ' && echo"),
+            timeout: None,
+        };
+        let file = External {
+            command: String::from("echo part {part}; grep -c a {input}"),
+            timeout: Some(10),
+        };
+
+        assert_eq!(compare(&SOLVER, &stdin, 1, "a\nb\n")?, None);
+        assert_eq!(compare(&SOLVER, &file, 2, "a\nb\na")?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn failing_command() {
+        let external = External {
+            command: String::from("exit 3"),
+            timeout: None,
+        };
+
+        assert!(external.solve(1, "a").is_err());
+    }
+
+    #[test]
+    fn timeout() {
+        let external = External {
+            command: String::from("sleep 5 | cat; echo 1"),
+            timeout: Some(0),
+        };
+        let start = Instant::now();
+        let answer = external.solve(1, "a");
+
+        assert!(answer.is_err_and(|error| error.to_string().ends_with("Timed out after 0s")));
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn shrink_generated() -> Result<()> {
+        // Counts the lines with a b instead, so disagrees on part 2 on every input.
+        let external = External {
+            command: String::from("grep -c b {input} || true"),
+            timeout: None,
+        };
+        let input = SOLVER.generate(0, 20).input;
+        let Some(disagreement) = compare(&SOLVER, &external, 2, &input)? else {
+            return Err(anyhow!("No disagreement"));
+        };
+        let shrunk = shrink(&SOLVER, &external, 0, 20, disagreement);
+
+        assert_eq!(shrunk.input, "a");
+        assert_eq!((shrunk.ours.as_str(), shrunk.theirs.as_str()), ("1", "0"));
+
+        Ok(())
+    }
+}
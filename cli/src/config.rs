@@ -0,0 +1,406 @@
+use std::{
+    collections::HashMap,
+    env,
+    fmt::{self, Display},
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{Result, anyhow};
+use clap::ValueEnum;
+use serde::{Deserialize, de::DeserializeOwned};
+
+use crate::FIRST_YEAR;
+
+/// The name of the configuration file, looked for in the current directory and its ancestors.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// How answers are written to stdout.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl Format {
+    /// Formats the answer to the given part of the given day of the given year.
+    pub fn answer(&self, year: u16, day: u8, part: u8, answer: &str) -> String {
+        match self {
+            Format::Text => answer.to_string(),
+            Format::Json => serde_json::json!({
+                "year": year,
+                "day": day,
+                "part": part,
+                "answer": answer,
+            })
+            .to_string(),
+        }
+    }
+}
+
+/// Where the session token for adventofcode.com is read from.
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase", deny_unknown_fields)]
+pub enum Session {
+    /// The name of an environment variable.
+    Env(String),
+
+    /// A file holding the token, relative to the directory of aoc.toml.
+    File(PathBuf),
+}
+
+/// Settings that only apply to one day, such as day 24's test area.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct Settings(toml::Table);
+
+impl Display for Settings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Settings {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self(s.parse()?))
+    }
+}
+
+impl Settings {
+    /// Gets the setting with the given key, or None if it is not set.
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+        self.0
+            .get(key)
+            .map(|value| {
+                value
+                    .clone()
+                    .try_into()
+                    .map_err(|error| anyhow!("Invalid setting {}: {}", key, error))
+            })
+            .transpose()
+    }
+
+    /// Replaces every setting written as `{ file = "<PATH>" }` by the contents of the file,
+    /// relative to root, so that solvers are given text rather than paths, and editing the file
+    /// changes the settings.
+    pub fn read_files(&mut self, root: &Path) -> Result<()> {
+        for (_, value) in self.0.iter_mut() {
+            if let toml::Value::Table(table) = value
+                && table.len() == 1
+                && let Some(toml::Value::String(path)) = table.get("file")
+            {
+                let path = root.join(path);
+                let contents = fs::read_to_string(&path)
+                    .map_err(|error| anyhow!("Cannot read {}: {}", path.display(), error))?;
+                *value = toml::Value::String(contents);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sets the setting with the given key, replacing any value it has.
+    pub fn set(&mut self, key: &str, value: impl Into<toml::Value>) {
+        self.0.insert(key.to_string(), value.into());
+    }
+}
+
+/// The contents of aoc.toml. Every field is optional, and defaults to the behaviour without a
+/// configuration file.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// The directory of the puzzle inputs, relative to the directory of aoc.toml.
+    pub input_dir: PathBuf,
+
+    /// The file name of each puzzle input, where `{year}` is replaced by the year and `{day}` by
+    /// the day number. It may name a subdirectory, such as one per year.
+    pub input_file: String,
+
+    pub format: Format,
+
+    /// In seconds. None to never time out.
+    pub timeout: Option<u64>,
+
+    pub session: Option<Session>,
+
+    /// year => day number => [Settings]
+    pub days: HashMap<String, HashMap<String, Settings>>,
+
+    /// The file every run's timing is appended to, relative to the directory of aoc.toml.
+    pub perf_history: PathBuf,
+
+    /// How many times slower than the best recent commit a run may be before `aoc perf report`
+    /// flags it.
+    pub regression_threshold: f64,
+
+    /// The file answers are cached in, relative to the directory of aoc.toml.
+    pub answer_cache: PathBuf,
+
+    /// The directory of aoc.toml, or empty if there is none, so that paths are relative to the
+    /// current directory.
+    #[serde(skip)]
+    pub root: PathBuf,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            input_dir: PathBuf::from("input"),
+            input_file: String::from("{year}/day-{day}.txt"),
+            format: Default::default(),
+            timeout: None,
+            session: None,
+            days: HashMap::new(),
+            perf_history: PathBuf::from("perf-history.jsonl"),
+            regression_threshold: 1.5,
+            answer_cache: PathBuf::from("answer-cache.jsonl"),
+            root: PathBuf::new(),
+        }
+    }
+}
+
+impl Config {
+    /// Loads aoc.toml from the current directory or its nearest ancestor that has one, or the
+    /// default [Config] if there is none.
+    pub fn discover() -> Result<Self> {
+        match find_config(&env::current_dir()?) {
+            Some(path) => Self::load(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Loads the [Config] at path.
+    pub fn load(path: &Path) -> Result<Self> {
+        let mut config: Config = toml::from_str(&fs::read_to_string(path)?)
+            .map_err(|error| anyhow!("Cannot parse {}: {}", path.display(), error))?;
+        config.root = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        for settings in config.days.values_mut().flat_map(HashMap::values_mut) {
+            settings.read_files(&config.root)?;
+        }
+
+        Ok(config)
+    }
+
+    /// Gets the path of the puzzle input for the given day of the given year.
+    ///
+    /// Inputs of the [FIRST_YEAR] were saved before years were part of the file name, so if there
+    /// is no such input, one at the file name without its `{year}/` directory is taken instead.
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        let path = self.input_path_from(&self.input_file, year, day);
+        let legacy_file = self.input_file.replace("{year}/", "");
+        if year == FIRST_YEAR && legacy_file != self.input_file && !path.exists() {
+            let legacy_path = self.input_path_from(&legacy_file, year, day);
+            if legacy_path.exists() {
+                return legacy_path;
+            }
+        }
+
+        path
+    }
+
+    /// Gets the path of the puzzle input for the given day of the given year, from the given
+    /// file name template.
+    fn input_path_from(&self, input_file: &str, year: u16, day: u8) -> PathBuf {
+        self.root.join(&self.input_dir).join(
+            input_file
+                .replace("{year}", &year.to_string())
+                .replace("{day}", &day.to_string()),
+        )
+    }
+
+    /// Gets the [Settings] for the given day of the given year, which are empty if there are none.
+    pub fn settings(&self, year: u16, day: u8) -> Settings {
+        self.days
+            .get(&year.to_string())
+            .and_then(|days| days.get(&day.to_string()))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Gets the path of the performance history.
+    pub fn perf_history_path(&self) -> PathBuf {
+        self.root.join(&self.perf_history)
+    }
+
+    /// Gets the path of the answer cache.
+    pub fn answer_cache_path(&self) -> PathBuf {
+        self.root.join(&self.answer_cache)
+    }
+
+    /// Reads the session token from its configured source.
+    pub fn session_token(&self) -> Result<String> {
+        let token = match &self.session {
+            Some(Session::Env(name)) => {
+                env::var(name).map_err(|_| anyhow!("Cannot read session token from ${}", name))?
+            }
+            Some(Session::File(path)) => fs::read_to_string(self.root.join(path))
+                .map_err(|_| anyhow!("Cannot read session token from {}", path.display()))?,
+            None => return Err(anyhow!("No session token source in {}", CONFIG_FILE)),
+        };
+
+        Ok(token.trim().to_string())
+    }
+}
+
+/// Finds aoc.toml in start or its nearest ancestor that has one.
+pub fn find_config(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join(CONFIG_FILE))
+        .find(|path| path.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"
+input_dir = "../puzzles"
+input_file = "{year}-{day}.in"
+format = "json"
+timeout = 30
+session = { env = "AOC_SESSION" }
+perf_history = ".aoc/perf.jsonl"
+regression_threshold = 2.0
+answer_cache = ".aoc/answers.jsonl"
+
+[days.2023.24]
+test_area = [7, 27]
+"#;
+
+    #[test]
+    fn parse() -> Result<()> {
+        let mut config: Config = toml::from_str(EXAMPLE)?;
+        config.root = PathBuf::from("/aoc");
+
+        assert_eq!(
+            config.input_path(2023, 3),
+            PathBuf::from("/aoc/../puzzles/2023-3.in")
+        );
+        assert_eq!(config.format, Format::Json);
+        assert_eq!(config.timeout, Some(30));
+        assert_eq!(config.session, Some(Session::Env("AOC_SESSION".into())));
+        assert_eq!(
+            config.settings(2023, 24).get("test_area")?,
+            Some([7.0, 27.0])
+        );
+        assert_eq!(config.settings(2023, 24).get::<u64>("other")?, None);
+        assert_eq!(config.settings(2023, 1), Settings::default());
+        assert_eq!(config.settings(2022, 24), Settings::default());
+        assert_eq!(
+            config.perf_history_path(),
+            PathBuf::from("/aoc/.aoc/perf.jsonl")
+        );
+        assert_eq!(config.regression_threshold, 2.0);
+        assert_eq!(
+            config.answer_cache_path(),
+            PathBuf::from("/aoc/.aoc/answers.jsonl")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn defaults() -> Result<()> {
+        let config: Config = toml::from_str("")?;
+
+        assert_eq!(config, Config::default());
+        assert_eq!(
+            config.input_path(2023, 19),
+            PathBuf::from("input/2023/day-19.txt")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_fields() {
+        assert!(toml::from_str::<Config>("input_directory = \"input\"").is_err());
+    }
+
+    #[test]
+    fn days_without_year() {
+        assert!(toml::from_str::<Config>("[days.24]\ntest_area = [7, 27]").is_err());
+    }
+
+    #[test]
+    fn discover_from_subdirectory() -> Result<()> {
+        let root = env::temp_dir().join(format!("aoc-config-{}", std::process::id()));
+        let subdirectory = root.join("day-1").join("src");
+        fs::create_dir_all(&subdirectory)?;
+        fs::write(root.join(CONFIG_FILE), "input_dir = \"inputs\"")?;
+
+        let path = find_config(&subdirectory);
+        let config = Config::load(&root.join(CONFIG_FILE))?;
+        fs::remove_dir_all(&root)?;
+
+        assert_eq!(path, Some(root.join(CONFIG_FILE)));
+        assert_eq!(
+            config.input_path(2023, 1),
+            root.join("inputs").join("2023").join("day-1.txt")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn settings_from_files() -> Result<()> {
+        let root = env::temp_dir().join(format!("aoc-config-files-{}", std::process::id()));
+        fs::create_dir_all(&root)?;
+        fs::write(
+            root.join(CONFIG_FILE),
+            "[days.2023.1]\nvocabulary = { file = \"words.txt\" }\nwide = true",
+        )?;
+        fs::write(root.join("words.txt"), "eins 1\n")?;
+        let before = Config::load(&root.join(CONFIG_FILE))?.settings(2023, 1);
+        fs::write(root.join("words.txt"), "eins 1\nzwei 2\n")?;
+        let after = Config::load(&root.join(CONFIG_FILE))?.settings(2023, 1);
+        fs::remove_file(root.join("words.txt"))?;
+        let missing = Config::load(&root.join(CONFIG_FILE));
+        fs::remove_dir_all(&root)?;
+
+        assert_eq!(
+            before.get::<String>("vocabulary")?.as_deref(),
+            Some("eins 1\n")
+        );
+        assert_eq!(before.get("wide")?, Some(true));
+        assert_ne!(before.to_string(), after.to_string());
+        assert!(missing.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn input_without_year() -> Result<()> {
+        let root = env::temp_dir().join(format!("aoc-config-legacy-{}", std::process::id()));
+        fs::create_dir_all(root.join("input").join("2023"))?;
+        fs::write(root.join("input").join("day-1.txt"), "1")?;
+        fs::write(root.join("input").join("day-2.txt"), "2")?;
+        fs::write(root.join("input").join("2023").join("day-2.txt"), "2")?;
+        let config = Config {
+            root: root.clone(),
+            ..Config::default()
+        };
+
+        let legacy = config.input_path(2023, 1);
+        let current = config.input_path(2023, 2);
+        let missing = config.input_path(2023, 3);
+        let other_year = config.input_path(2024, 1);
+        fs::remove_dir_all(&root)?;
+
+        assert_eq!(legacy, root.join("input").join("day-1.txt"));
+        assert_eq!(current, root.join("input").join("2023").join("day-2.txt"));
+        assert_eq!(missing, root.join("input").join("2023").join("day-3.txt"));
+        assert_eq!(
+            other_year,
+            root.join("input").join("2024").join("day-1.txt")
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,300 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{Result, anyhow};
+
+use crate::config::Settings;
+
+/// An example from a puzzle description, along with the expected answer to each part, where the
+/// description gives one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Example {
+    pub input: String,
+    pub answers: [Option<String>; 2],
+
+    /// Settings the example is solved with, such as day 24's test area.
+    pub settings: Settings,
+}
+
+/// Gets the directory of the example fixtures of the given day, within the day's crate.
+pub fn fixture_dir(year: u16, day: u8) -> PathBuf {
+    crate::day_dir(year, day).join("fixtures")
+}
+
+/// Pulls the examples out of a puzzle description saved from adventofcode.com.
+///
+/// Every `<pre><code>` block is a candidate example, and every emphasised `<code><em>` is a
+/// candidate answer. The description of each part is an `<article>`. An answer goes to the first
+/// block after the previous answer of the part, as the blocks that follow an example usually
+/// illustrate its solution. An answer in part 2 before any block of its own goes to the example
+/// last answered in part 1, as part 2 usually reuses it. Only blocks that are given an answer are
+/// examples.
+pub fn parse_page(html: &str) -> Vec<Example> {
+    let articles = html
+        .split("<article")
+        .skip(1)
+        .map(|article| article.split("</article>").next().unwrap_or(article))
+        .collect::<Vec<_>>();
+    let articles = if articles.is_empty() {
+        vec![html]
+    } else {
+        articles
+    };
+
+    let mut blocks: Vec<(String, [Option<String>; 2])> = Vec::new();
+    let mut last_answered = None;
+    for (part, article) in articles.into_iter().take(2).enumerate() {
+        let mut unanswered = None;
+        let mut answered = None;
+        let mut rest = article;
+        while let Some((index, token)) = next_token(rest) {
+            rest = &rest[index..];
+            let Some(end) = rest.find(token.end()) else {
+                break;
+            };
+            let text = decode(&rest[token.start().len()..end]);
+            rest = &rest[end + token.end().len()..];
+
+            match token {
+                Token::Block => {
+                    blocks.push((text, [None, None]));
+                    if unanswered.is_none() {
+                        unanswered = Some(blocks.len() - 1);
+                    }
+                }
+                Token::Answer => {
+                    let Some(index) = unanswered.take().or(answered).or(last_answered) else {
+                        continue;
+                    };
+                    blocks[index].1[part] = Some(text);
+                    answered = Some(index);
+                }
+            }
+        }
+        last_answered = answered.or(last_answered);
+    }
+
+    blocks
+        .into_iter()
+        .filter(|(_, answers)| answers.iter().any(Option::is_some))
+        .map(|(input, answers)| Example {
+            input,
+            answers,
+            settings: Settings::default(),
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy)]
+enum Token {
+    Block,
+    Answer,
+}
+
+impl Token {
+    fn start(&self) -> &'static str {
+        match self {
+            Token::Block => "<pre><code>",
+            Token::Answer => "<code><em>",
+        }
+    }
+
+    fn end(&self) -> &'static str {
+        match self {
+            Token::Block => "</code></pre>",
+            Token::Answer => "</em></code>",
+        }
+    }
+}
+
+/// Finds the next [Token] in html, and where it starts.
+fn next_token(html: &str) -> Option<(usize, Token)> {
+    [Token::Block, Token::Answer]
+        .into_iter()
+        .filter_map(|token| Some((html.find(token.start())?, token)))
+        .min_by_key(|(index, _)| *index)
+}
+
+/// Strips the tags from html, such as the emphasis within an example, and decodes the entities.
+fn decode(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+        .trim_matches('\n')
+        .to_string()
+}
+
+/// Writes the examples to dir as fixtures, numbered from the one after the highest already there:
+/// the input in `<N>.txt` and the expected answers in `<N>.txt.answers`, one part per line, or
+/// `N.A.` where they are not known.
+///
+/// Returns the paths of the inputs written.
+pub fn write_fixtures(dir: &Path, examples: &[Example]) -> Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+    let first = load_fixtures(dir)?
+        .iter()
+        .filter_map(|(path, _)| path.file_stem()?.to_str()?.parse::<usize>().ok())
+        .max()
+        .unwrap_or_default()
+        + 1;
+
+    let mut paths = Vec::new();
+    for (number, example) in (first..).zip(examples) {
+        let path = dir.join(format!("{number}.txt"));
+        let answers = example
+            .answers
+            .iter()
+            .map(|answer| answer.as_deref().unwrap_or("N.A."))
+            .collect::<Vec<_>>();
+
+        fs::write(&path, example.input.clone() + "\n")?;
+        fs::write(answers_path(&path), answers.join("\n") + "\n")?;
+        paths.push(path);
+    }
+
+    Ok(paths)
+}
+
+/// Loads every fixture in dir, ordered by path. A missing dir has no fixtures.
+///
+/// The settings of a fixture, if it needs any, are in `<N>.toml`.
+pub fn load_fixtures(dir: &Path) -> Result<Vec<(PathBuf, Example)>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut paths = fs::read_dir(dir)?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<Result<Vec<_>>>()?;
+    paths.retain(|path| path.extension().is_some_and(|extension| extension == "txt"));
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let mut input = fs::read_to_string(&path)?;
+            crate::trim_newlines_in_place(&mut input);
+
+            let answers = fs::read_to_string(answers_path(&path))
+                .map_err(|error| anyhow!("Cannot read answers to {}: {}", path.display(), error))?;
+            let mut answers = answers
+                .lines()
+                .map(|answer| (answer != "N.A.").then(|| answer.to_string()));
+            let answers = [answers.next().flatten(), answers.next().flatten()];
+
+            let settings_path = path.with_extension("toml");
+            let settings = if settings_path.exists() {
+                Settings::from_str(&fs::read_to_string(&settings_path)?)?
+            } else {
+                Settings::default()
+            };
+
+            Ok((
+                path,
+                Example {
+                    input,
+                    answers,
+                    settings,
+                },
+            ))
+        })
+        .collect()
+}
+
+fn answers_path(path: &Path) -> PathBuf {
+    let mut answers_path = path.as_os_str().to_owned();
+    answers_path.push(".answers");
+
+    PathBuf::from(answers_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    const PAGE: &str = r#"
+<main>
+<article class="day-desc"><h2>--- Day 0: Example ---</h2>
+<p>For example:</p>
+<pre><code>1 &lt; 2
+<em>3</em> &amp; 4
+</code></pre>
+<p>Which looks like this when solved:</p>
+<pre><code>solved
+</code></pre>
+<p>So the answer is <code>1</code> + <code>2</code> = <code><em>3</em></code>.</p>
+<p>Another:</p>
+<pre><code>5
+</code></pre>
+<p>Gives <code><em>5</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>123</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>With the same example, the answer is <code><em>7</em></code>.</p>
+</article>
+</main>
+"#;
+
+    #[test]
+    fn parse() {
+        let examples = parse_page(PAGE);
+
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].input, "1 < 2\n3 & 4");
+        assert_eq!(examples[0].answers, [Some(String::from("3")), None]);
+        assert_eq!(examples[1].input, "5");
+        assert_eq!(
+            examples[1].answers,
+            [Some(String::from("5")), Some(String::from("7"))]
+        );
+    }
+
+    #[test]
+    fn part_2_examples() {
+        let page = r#"
+<article><pre><code>a</code></pre><code><em>1</em></code></article>
+<article><pre><code>b</code></pre><code><em>2</em></code></article>
+"#;
+        let examples = parse_page(page);
+
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].answers, [Some(String::from("1")), None]);
+        assert_eq!(examples[1].answers, [None, Some(String::from("2"))]);
+    }
+
+    #[test]
+    fn write_and_load() -> Result<()> {
+        let dir = env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        let examples = parse_page(PAGE);
+        let written = write_fixtures(&dir, &examples)?;
+        let written_again = write_fixtures(&dir, &examples[..1])?;
+        let loaded = load_fixtures(&dir)?;
+        fs::remove_dir_all(&dir)?;
+
+        assert_eq!(written, [dir.join("1.txt"), dir.join("2.txt")]);
+        assert_eq!(written_again, [dir.join("3.txt")]);
+        assert_eq!(loaded.len(), 3);
+        assert_eq!(loaded[0].1, examples[0]);
+        assert_eq!(loaded[1].1, examples[1]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+    str,
+};
+
+use anyhow::{Result, anyhow};
+use memmap2::Mmap;
+
+use crate::trim_newlines;
+
+/// Opens the puzzle input at path to be read a line at a time with [for_each_line].
+pub fn open(path: &Path) -> Result<BufReader<File>> {
+    File::open(path)
+        .map(BufReader::new)
+        .map_err(|error| anyhow!("Cannot read {}: {}", path.display(), error))
+}
+
+/// A puzzle input mapped into memory, so that unlike [crate::read_input] it is never copied.
+pub struct Mapped {
+    mmap: Mmap,
+}
+
+impl Mapped {
+    pub fn open(path: &Path) -> Result<Self> {
+        let file = File::open(path)
+            .map_err(|error| anyhow!("Cannot read {}: {}", path.display(), error))?;
+        // SAFETY: Puzzle inputs are not expected to change while they are being solved.
+        let mmap = unsafe { Mmap::map(&file) }
+            .map_err(|error| anyhow!("Cannot map {}: {}", path.display(), error))?;
+
+        Ok(Self { mmap })
+    }
+
+    /// Gets the input with the newlines at the start and the end trimmed, as by [trim_newlines].
+    pub fn as_str(&self) -> Result<&str> {
+        let input =
+            str::from_utf8(&self.mmap).map_err(|error| anyhow!("Invalid input: {}", error))?;
+
+        Ok(trim_newlines(input))
+    }
+}
+
+/// Calls f with every line read from reader, without its line ending. Blank lines at the start and
+/// the end are skipped, as by [trim_newlines].
+///
+/// Each line is read into the same buffer, so memory stays flat however long the input is.
+pub fn for_each_line(
+    reader: &mut dyn BufRead,
+    mut f: impl FnMut(&str) -> Result<()>,
+) -> Result<()> {
+    let mut buffer = Vec::new();
+    let mut started = false;
+    let mut blank_lines = 0;
+    while read_record(reader, b'\n', &mut buffer)? {
+        let line = as_record(&buffer, b'\n')?;
+        if line.is_empty() {
+            blank_lines += usize::from(started);
+            continue;
+        }
+
+        // Blank lines are only passed on once a line follows them.
+        for _ in 0..blank_lines {
+            f("")?;
+        }
+        blank_lines = 0;
+        started = true;
+        f(line)?;
+    }
+
+    Ok(())
+}
+
+/// Calls f with every record read from reader, as separated by delimiter, like
+/// [str::split_terminator] on the input with its newlines trimmed.
+pub fn for_each_record(
+    reader: &mut dyn BufRead,
+    delimiter: u8,
+    mut f: impl FnMut(&str) -> Result<()>,
+) -> Result<()> {
+    let mut buffer = Vec::new();
+    while read_record(reader, delimiter, &mut buffer)? {
+        let record = as_record(&buffer, delimiter)?;
+        if record.is_empty() && buffer.last() != Some(&delimiter) {
+            // The newlines after the last delimiter.
+            break;
+        }
+
+        f(record)?;
+    }
+
+    Ok(())
+}
+
+/// Reads the next record into buffer, returning false at the end of the input.
+fn read_record(reader: &mut dyn BufRead, delimiter: u8, buffer: &mut Vec<u8>) -> Result<bool> {
+    buffer.clear();
+
+    Ok(reader.read_until(delimiter, buffer)? > 0)
+}
+
+/// Gets the record in buffer without its delimiter, carriage return or surrounding newlines.
+fn as_record(buffer: &[u8], delimiter: u8) -> Result<&str> {
+    let record = buffer.strip_suffix(&[delimiter]).unwrap_or(buffer);
+    let record = str::from_utf8(record).map_err(|error| anyhow!("Invalid input: {}", error))?;
+
+    Ok(trim_newlines(record.strip_suffix('\r').unwrap_or(record)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    fn lines(input: &str) -> Result<Vec<String>> {
+        let mut lines = Vec::new();
+        for_each_line(&mut Cursor::new(input), |line| {
+            lines.push(line.to_string());
+
+            Ok(())
+        })?;
+
+        Ok(lines)
+    }
+
+    #[test]
+    fn line_at_a_time() -> Result<()> {
+        assert_eq!(lines("\n\na\r\nb\n\n\nc\n\n")?, ["a", "b", "", "", "c"]);
+        assert_eq!(lines("a")?, ["a"]);
+        assert_eq!(lines("")?, Vec::<String>::new());
+
+        Ok(())
+    }
+
+    #[test]
+    fn record_at_a_time() -> Result<()> {
+        let mut records = Vec::new();
+        for_each_record(&mut Cursor::new("\nrn=1,,cm-,\n"), b',', |record| {
+            records.push(record.to_string());
+
+            Ok(())
+        })?;
+
+        assert_eq!(records, ["rn=1", "", "cm-"]);
+
+        Ok(())
+    }
+}
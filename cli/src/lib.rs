@@ -1,7 +1,43 @@
-use std::fs;
+use std::{
+    fmt::{Debug, Display},
+    fs,
+    io::BufRead,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
 
 use anyhow::{Result, anyhow};
 use clap::Parser;
+use rand::{SeedableRng, rngs::StdRng};
+use serde::{Serialize, Serializer};
+use thiserror::Error;
+
+use animate::Frames;
+use answer::Overflow;
+use cache::{Entry, Key};
+use certify::{Certificate, Certifier};
+use config::{Config, Settings};
+use input::Mapped;
+use perf::{Record, hash_input};
+use render::Image;
+
+pub mod animate;
+pub mod answer;
+pub mod cache;
+pub mod certify;
+pub mod compare;
+pub mod config;
+pub mod examples;
+pub mod input;
+pub mod parallel;
+pub mod perf;
+pub mod progress;
+pub mod render;
+#[cfg(feature = "test-support")]
+pub mod verify;
 
 #[derive(Parser)]
 struct Args {
@@ -9,6 +45,18 @@ struct Args {
 
     #[arg(short = 'i', long)]
     input: Option<String>,
+
+    /// How many threads the parallel parts of a solver use, where 0 is a thread per CPU.
+    #[arg(long, default_value_t = 0)]
+    threads: usize,
+
+    /// Solves even if the answer is in the answer cache, and leaves the cache untouched.
+    #[arg(long)]
+    no_cache: bool,
+
+    /// Accumulates the answer in 128 bits, overriding aoc.toml.
+    #[arg(long)]
+    wide: bool,
 }
 
 pub enum Part {
@@ -17,11 +65,19 @@ pub enum Part {
 }
 
 /// Gets the [Part] to execute.
-pub fn get_part(default_input: &str) -> Result<Part> {
-    let args = Args::parse();
+pub fn get_part(default_input: &Path) -> Result<Part> {
+    read_part(Args::parse(), default_input)
+}
+
+/// Gets the [Part] to execute as given by args.
+fn read_part(args: Args, default_input: &Path) -> Result<Part> {
+    parallel::set_threads(args.threads)?;
 
-    let path = args.input.unwrap_or(String::from(default_input));
-    let input = String::from(trim_newlines(&fs::read_to_string(path)?));
+    let path = args
+        .input
+        .map_or(default_input.to_path_buf(), PathBuf::from);
+    let mut input = read_input(&path)?;
+    trim_newlines_in_place(&mut input);
 
     match args.part {
         1 => Ok(Part::Part1(input)),
@@ -30,7 +86,450 @@ pub fn get_part(default_input: &str) -> Result<Part> {
     }
 }
 
+/// Reads the puzzle input at path.
+pub fn read_input(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|error| anyhow!("Cannot read {}: {}", path.display(), error))
+}
+
 /// Trims newlines from the start and the end of the input string.
 pub fn trim_newlines(input: &str) -> &str {
     input.trim_start_matches("\n").trim_end_matches("\n")
 }
+
+/// Trims newlines from the start and the end of the input string, as by [trim_newlines], without
+/// copying it.
+pub fn trim_newlines_in_place(input: &mut String) {
+    input.truncate(input.trim_end_matches("\n").len());
+    input.drain(..input.len() - input.trim_start_matches("\n").len());
+}
+
+/// A number in the puzzle input that cannot be parsed, shared by the error types of every day.
+#[derive(Clone, Debug, Error, PartialEq)]
+#[error("Invalid number {text:?}: {reason}")]
+pub struct NumberError {
+    pub text: String,
+    pub reason: String,
+}
+
+/// Parses a number from the puzzle input, keeping the offending text on failure.
+pub fn parse_number<T>(text: &str) -> Result<T, NumberError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.parse().map_err(|error: T::Err| NumberError {
+        text: text.to_string(),
+        reason: error.to_string(),
+    })
+}
+
+/// Why a puzzle input is rejected: for its format, for breaking the rules of the puzzle, or for
+/// having no answer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ErrorKind {
+    /// The input is not in the format of the puzzle.
+    Parse,
+
+    /// The input is well formed, but breaks a rule of the puzzle, such as a name that is used but
+    /// never defined.
+    Validation,
+
+    /// The input is a valid puzzle, but has no answer, or none that the solver can find.
+    Unsolvable,
+}
+
+/// An error that knows its [ErrorKind], implemented by the error type of every day.
+pub trait Kind {
+    fn kind(&self) -> ErrorKind;
+}
+
+impl Kind for NumberError {
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Parse
+    }
+}
+
+impl Kind for Overflow {
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Unsolvable
+    }
+}
+
+/// Gets the [ErrorKind] of an error from a solver whose error type is E, for [Solver::classify].
+/// None if the error did not come from the puzzle input, such as an invalid part number.
+pub fn classify<E>(error: &anyhow::Error) -> Option<ErrorKind>
+where
+    E: Kind + Display + Debug + Send + Sync + 'static,
+{
+    if let Some(error) = error.downcast_ref::<E>() {
+        Some(error.kind())
+    } else if let Some(error) = error.downcast_ref::<NumberError>() {
+        Some(error.kind())
+    } else {
+        error.downcast_ref::<Overflow>().map(Overflow::kind)
+    }
+}
+
+/// Converts a parsed model to pretty-printed JSON for [Solver::dump]. Keys of maps are sorted, so
+/// that the models of different inputs can be diffed.
+pub fn to_json<T>(model: &T) -> Result<String>
+where
+    T: Serialize,
+{
+    Ok(serde_json::to_string_pretty(&serde_json::to_value(model)?)?)
+}
+
+/// Parses every line of the input with parse and converts the models to JSON with [to_json].
+pub fn dump_lines<'a, T, E>(
+    input: &'a str,
+    parse: impl Fn(&'a str) -> Result<T, E>,
+) -> Result<String>
+where
+    T: Serialize,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    to_json(&input.lines().map(parse).collect::<Result<Vec<_>, _>>()?)
+}
+
+/// Serializes the rows of a grid as strings rather than arrays of bytes, for use with
+/// `#[serde(serialize_with = "cli::serialize_rows")]`.
+pub fn serialize_rows<S>(rows: &[Vec<u8>], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(rows.iter().map(|row| String::from_utf8_lossy(row)))
+}
+
+/// Serializes a set in order rather than in the order of its hashes, for use with
+/// `#[serde(serialize_with = "cli::serialize_sorted")]`.
+pub fn serialize_sorted<'a, I, T, S>(items: &'a I, serializer: S) -> Result<S::Ok, S::Error>
+where
+    &'a I: IntoIterator<Item = &'a T>,
+    T: Ord + Serialize + 'a,
+    S: Serializer,
+{
+    let mut items = items.into_iter().collect::<Vec<_>>();
+    items.sort();
+
+    serializer.collect_seq(items)
+}
+
+/// The year of the calendar the workspace started with. Records written before years were
+/// recorded are of this year.
+pub const FIRST_YEAR: u16 = 2023;
+
+/// Gets the directory of the crate of the given day: `day-<DAY>` in the workspace for the
+/// [FIRST_YEAR], or `<YEAR>/day-<DAY>` for the years added since.
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap_or(Path::new("."));
+    let dir = format!("day-{day}");
+
+    if year == FIRST_YEAR {
+        workspace.join(dir)
+    } else {
+        workspace.join(year.to_string()).join(dir)
+    }
+}
+
+/// Solves one part of a puzzle with the day's [Settings], giving the answer as text.
+pub type SolvePart = fn(&str, &Settings) -> Result<String>;
+
+/// A randomly generated puzzle input.
+pub struct Generated {
+    pub input: String,
+
+    /// The answers to part 1 and part 2, where they are known by construction or brute force.
+    pub answers: [Option<String>; 2],
+}
+
+/// Generates a puzzle input from the given RNG, scaled by size.
+pub type Generate = fn(&mut StdRng, usize) -> Generated;
+
+/// Draws the state of a grid puzzle after solving the given part, with the solution overlaid.
+pub type Render = fn(&str, u8) -> Result<Image>;
+
+/// Steps through the simulation of the given part, a [animate::Frame] at a time.
+pub type Animate = fn(&str, u8) -> Result<Frames<'_>>;
+
+/// Parses the puzzle input into the day's model, as JSON.
+pub type Dump = fn(&str) -> Result<String>;
+
+/// Solves the given part from a reader of the puzzle input, a line at a time.
+pub type Stream = fn(&mut dyn BufRead, u8, &Settings) -> Result<String>;
+
+/// Reports how the given part is solved, a line of the puzzle input at a time, with the day's
+/// [Settings].
+pub type Report = fn(&str, u8, &Settings) -> Result<String>;
+
+/// The solutions to a day's puzzle.
+///
+/// Answers are converted to text so that every day can be driven by the same runner.
+#[derive(Clone, Copy)]
+pub struct Solver {
+    pub year: u16,
+    pub day: u8,
+
+    /// A hash of the sources of the day's crate and of the crates of the workspace it depends on,
+    /// as they were built: `env!("SOLVER_VERSION")`, set by the build script of every day.
+    pub version: &'static str,
+
+    pub part_1: SolvePart,
+
+    /// None if the day has no part 2.
+    pub part_2: Option<SolvePart>,
+
+    pub generator: Generate,
+
+    /// None if the day is not a grid puzzle that can be drawn.
+    pub render: Option<Render>,
+
+    /// None if the day is not a simulation that can be played back.
+    pub animate: Option<Animate>,
+
+    /// None if the day has no model beyond the lines of the input.
+    pub dump: Option<Dump>,
+
+    /// None if the day cannot be solved a line at a time.
+    pub stream: Option<Stream>,
+
+    /// None if the day gives no witnesses to its answers.
+    pub certify: Option<Certifier>,
+
+    /// None if the day does not report how each line is solved.
+    pub report: Option<Report>,
+
+    /// Tells errors in the input's format from invalid puzzles and puzzles without an answer,
+    /// usually [classify] of the day's error type.
+    pub classify: fn(&anyhow::Error) -> Option<ErrorKind>,
+}
+
+impl Solver {
+    /// Solves the given part with the default [Settings].
+    pub fn solve(&self, part: u8, input: &str) -> Result<String> {
+        self.solve_with(part, input, &Settings::default())
+    }
+
+    /// Solves the given part.
+    pub fn solve_with(&self, part: u8, input: &str, settings: &Settings) -> Result<String> {
+        match (part, self.part_2) {
+            (1, _) => (self.part_1)(input, settings),
+            (2, Some(part_2)) => part_2(input, settings),
+            (2, None) => Err(anyhow!("No part 2")),
+            _ => Err(anyhow!("Invalid part number: {}", part)),
+        }
+    }
+
+    /// Solves the given part on another thread, giving up after timeout seconds, if any.
+    pub fn solve_with_timeout(
+        &self,
+        part: u8,
+        input: String,
+        settings: Settings,
+        timeout: Option<u64>,
+    ) -> Result<String> {
+        let solver = *self;
+
+        with_timeout(timeout, move || solver.solve_with(part, &input, &settings))
+    }
+
+    /// Runs solve for the answer to the given part of the input with the given [hash_input] and
+    /// [Settings], recording the timing in the performance history, unless the answer is in the
+    /// answer cache. The cache is neither read nor written without use_cache.
+    ///
+    /// Returns the answer, and whether it came from the cache.
+    pub fn solve_cached(
+        &self,
+        part: u8,
+        input_hash: String,
+        settings: &Settings,
+        config: &Config,
+        use_cache: bool,
+        solve: impl FnOnce() -> Result<String>,
+    ) -> Result<(String, bool)> {
+        let cache_path = config.answer_cache_path();
+        let key = use_cache.then(|| {
+            Key::new(
+                self.year,
+                self.day,
+                part,
+                input_hash.clone(),
+                self.version,
+                settings,
+            )
+        });
+        if let Some(key) = &key
+            && let Some(answer) = cache::lookup(&cache_path, key)?
+        {
+            return Ok((answer, true));
+        }
+
+        let start = Instant::now();
+        let answer = solve()?;
+        let record = Record::new(
+            self.year,
+            self.day,
+            part,
+            input_hash,
+            start.elapsed(),
+            &config.root,
+        );
+        perf::append(&config.perf_history_path(), &record)?;
+        if let Some(key) = key {
+            cache::store(&cache_path, &Entry::new(key, answer.clone()))?;
+        }
+
+        Ok((answer, false))
+    }
+
+    /// Solves the given part a line at a time from reader.
+    pub fn solve_stream(
+        &self,
+        part: u8,
+        reader: &mut dyn BufRead,
+        settings: &Settings,
+    ) -> Result<String> {
+        match self.stream {
+            Some(stream) => stream(reader, part, settings),
+            None => Err(anyhow!("Cannot stream day {}", self.day)),
+        }
+    }
+
+    /// Solves the given part from the puzzle input at path without reading it into memory: a line
+    /// at a time if the day can be streamed, or else from the input mapped into memory.
+    pub fn solve_path(
+        &self,
+        part: u8,
+        path: PathBuf,
+        settings: Settings,
+        timeout: Option<u64>,
+    ) -> Result<String> {
+        let solver = *self;
+
+        with_timeout(timeout, move || match solver.stream {
+            Some(stream) => stream(&mut input::open(&path)?, part, &settings),
+            None => solver.solve_with(part, Mapped::open(&path)?.as_str()?, &settings),
+        })
+    }
+
+    /// Draws the given part as an [Image].
+    pub fn render(&self, part: u8, input: &str) -> Result<Image> {
+        match self.render {
+            Some(render) => render(input, part),
+            None => Err(anyhow!("Cannot render day {}", self.day)),
+        }
+    }
+
+    /// Plays back the simulation of the given part as [Frames].
+    pub fn animate<'a>(&self, part: u8, input: &'a str) -> Result<Frames<'a>> {
+        match self.animate {
+            Some(animate) => animate(input, part),
+            None => Err(anyhow!("Cannot animate day {}", self.day)),
+        }
+    }
+
+    /// Reports how the given part is solved, a line of the input at a time.
+    pub fn report(&self, part: u8, input: &str, settings: &Settings) -> Result<String> {
+        match self.report {
+            Some(report) => report(input, part, settings),
+            None => Err(anyhow!("Cannot report day {}", self.day)),
+        }
+    }
+
+    /// Dumps the parsed model of the input as JSON.
+    pub fn dump(&self, input: &str) -> Result<String> {
+        match self.dump {
+            Some(dump) => dump(input),
+            None => Err(anyhow!("Cannot dump day {}", self.day)),
+        }
+    }
+
+    /// Solves the given part, along with a witness to the answer.
+    pub fn certify(&self, part: u8, input: &str) -> Result<Certificate> {
+        match self.certify {
+            Some(certifier) => (certifier.witness)(input, part),
+            None => Err(anyhow!("Cannot certify day {}", self.day)),
+        }
+    }
+
+    /// Checks a [Certificate] of the given part against the input.
+    pub fn check(&self, part: u8, input: &str, certificate: &Certificate) -> Result<()> {
+        match self.certify {
+            Some(certifier) => (certifier.check)(input, part, certificate),
+            None => Err(anyhow!("Cannot check day {}", self.day)),
+        }
+    }
+
+    /// Generates a puzzle input, reproducible from seed.
+    pub fn generate(&self, seed: u64, size: usize) -> Generated {
+        (self.generator)(&mut StdRng::seed_from_u64(seed), size)
+    }
+}
+
+/// Runs solve on another thread, giving up after timeout seconds, if any. A solve that times out
+/// cannot be stopped, so its thread runs on until it finishes.
+pub fn with_timeout<T, F>(timeout: Option<u64>, solve: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T> + Send + 'static,
+{
+    let Some(timeout) = timeout else {
+        return solve();
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let progress = progress::Reporter::current();
+    thread::spawn(move || sender.send(progress.within(solve)));
+
+    match receiver.recv_timeout(Duration::from_secs(timeout)) {
+        Ok(answer) => answer,
+        Err(RecvTimeoutError::Timeout) => Err(Unsound::TimedOut(timeout).into()),
+        Err(RecvTimeoutError::Disconnected) => Err(Unsound::Panicked.into()),
+    }
+}
+
+/// How a solver failed to either answer or reject its input.
+#[derive(Debug, Error)]
+pub enum Unsound {
+    #[error("Timed out after {0}s")]
+    TimedOut(u64),
+
+    #[error("Solver panicked")]
+    Panicked,
+}
+
+/// Runs the given [Solver] against the part and input given on the command line, as configured
+/// by aoc.toml, and records the timing in the performance history, unless the answer is cached.
+pub fn run(solver: &Solver) {
+    let answer = Config::discover().and_then(|config| {
+        let args = Args::parse();
+        let use_cache = !args.no_cache;
+        let mut settings = config.settings(solver.year, solver.day);
+        if args.wide {
+            settings.set(answer::WIDE, true);
+        }
+        let (part, input) = match read_part(args, &config.input_path(solver.year, solver.day))? {
+            Part::Part1(input) => (1, input),
+            Part::Part2(input) => (2, input),
+        };
+        let (answer, _) = progress::show_while(progress::is_shown(config.format), || {
+            solver.solve_cached(
+                part,
+                hash_input(&input),
+                &settings,
+                &config,
+                use_cache,
+                || solver.solve_with_timeout(part, input, settings.clone(), config.timeout),
+            )
+        })?;
+
+        Ok(config.format.answer(solver.year, solver.day, part, &answer))
+    });
+
+    match answer {
+        Ok(answer) => println!("{answer}"),
+        Err(error) => println!("{error:?}"),
+    }
+}
@@ -0,0 +1,56 @@
+use anyhow::{Result, anyhow};
+use rayon::{ThreadPoolBuilder, prelude::*};
+
+/// Sets how many threads [map] spreads work over for the rest of the process, where 0 is a thread
+/// per CPU, as when it is never set.
+pub fn set_threads(threads: usize) -> Result<()> {
+    ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build_global()
+        .map_err(|error| anyhow!("Cannot set threads: {}", error))
+}
+
+/// Runs f with [map] spreading work over the given num of threads, whatever [set_threads] is set to.
+pub fn with_threads<R>(threads: usize, f: impl FnOnce() -> R + Send) -> Result<R>
+where
+    R: Send,
+{
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .map_err(|error| anyhow!("Cannot start {} threads: {}", threads, error))?;
+
+    Ok(pool.install(f))
+}
+
+/// Applies f to every item, spread over the threads. The results are in the order of the items
+/// however the work is spread, so that answers do not depend on the num of threads.
+pub fn map<T, R>(items: &[T], f: impl Fn(&T) -> R + Send + Sync) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+{
+    items.par_iter().map(f).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn in_order() -> Result<()> {
+        let items = (0..1000).collect::<Vec<u64>>();
+        let squares = items.iter().map(|item| item * item).collect::<Vec<_>>();
+
+        assert_eq!(
+            with_threads(4, || map(&items, |item| item * item))?,
+            squares
+        );
+        assert_eq!(
+            with_threads(1, || map(&items, |item| item * item))?,
+            squares
+        );
+
+        Ok(())
+    }
+}
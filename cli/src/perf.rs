@@ -0,0 +1,399 @@
+use std::{
+    fmt::Write as _,
+    fs::{self, OpenOptions},
+    io::{BufRead, Write},
+    path::Path,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+use crate::FIRST_YEAR;
+
+/// One timed run of a solver, as kept in the performance history.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Record {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+
+    /// As given by git describe, ending in -dirty when there are uncommitted changes.
+    pub commit: String,
+
+    /// The [hash_input] of the puzzle input, as timings are only comparable on the same input.
+    pub input_hash: String,
+
+    #[serde(default = "first_year")]
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub nanos: u64,
+}
+
+impl Record {
+    /// Creates the [Record] of a run of the solver in dir, timestamped now, on the input with the
+    /// given [hash_input].
+    pub fn new(
+        year: u16,
+        day: u8,
+        part: u8,
+        input_hash: String,
+        elapsed: Duration,
+        dir: &Path,
+    ) -> Self {
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |since| since.as_secs()),
+            commit: current_commit(dir),
+            input_hash,
+            year,
+            day,
+            part,
+            nanos: elapsed.as_nanos().try_into().unwrap_or(u64::MAX),
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.nanos)
+    }
+}
+
+/// Runs recorded before years were recorded are of the [FIRST_YEAR].
+fn first_year() -> u16 {
+    FIRST_YEAR
+}
+
+/// Hashes the puzzle input with 64-bit FNV-1a, which unlike the standard library's hasher is
+/// stable across builds.
+pub fn hash_input(input: &str) -> String {
+    let hash = input.bytes().fold(FNV_OFFSET_BASIS, fnv_1a);
+
+    format!("{hash:016x}")
+}
+
+/// Hashes the puzzle input read from reader the same as [hash_input] once the newlines at the start
+/// and the end are trimmed, without reading it into memory.
+pub fn hash_reader(reader: &mut dyn BufRead) -> Result<String> {
+    let mut hash = FNV_OFFSET_BASIS;
+    let mut started = false;
+    let mut newlines = 0;
+    loop {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            break;
+        }
+
+        for &byte in buffer {
+            if byte == b'\n' {
+                newlines += usize::from(started);
+                continue;
+            }
+
+            // Newlines are only hashed once a byte follows them.
+            for _ in 0..newlines {
+                hash = fnv_1a(hash, b'\n');
+            }
+            newlines = 0;
+            started = true;
+            hash = fnv_1a(hash, byte);
+        }
+        let len = buffer.len();
+        reader.consume(len);
+    }
+
+    Ok(format!("{hash:016x}"))
+}
+
+pub(crate) const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+
+pub(crate) fn fnv_1a(hash: u64, byte: u8) -> u64 {
+    (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+}
+
+/// Describes the git commit checked out in dir, or "unknown" outside of a repository.
+pub fn current_commit(dir: &Path) -> String {
+    let dir = if dir.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        dir
+    };
+
+    Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .current_dir(dir)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|commit| commit.trim().to_string())
+        .unwrap_or_else(|| String::from("unknown"))
+}
+
+/// Appends the [Record] to the history file at path, one JSON object per line.
+pub fn append(path: &Path, record: &Record) -> Result<()> {
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|error| anyhow!("Cannot open {}: {}", path.display(), error))?;
+    writeln!(file, "{}", serde_json::to_string(record)?)?;
+
+    Ok(())
+}
+
+/// Loads every [Record] in the history file at path, oldest first. A missing file is an empty
+/// history.
+pub fn load(path: &Path) -> Result<Vec<Record>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    fs::read_to_string(path)?
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line).map_err(|error| {
+                anyhow!(
+                    "Cannot parse {} line {}: {}",
+                    path.display(),
+                    index + 1,
+                    error
+                )
+            })
+        })
+        .collect()
+}
+
+/// The timings of one day and part on the input of its latest run.
+#[derive(Debug, PartialEq)]
+pub struct Trend {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+
+    /// The best timing of each commit, ordered by the latest run of the commit.
+    pub commits: Vec<(String, Duration)>,
+}
+
+impl Trend {
+    /// Gets the best timing of the latest commit.
+    pub fn latest(&self) -> &(String, Duration) {
+        &self.commits[self.commits.len() - 1]
+    }
+
+    /// Gets the best timing among the window commits before the latest one, if there are any.
+    pub fn best_recent(&self, window: usize) -> Option<&(String, Duration)> {
+        let previous = &self.commits[..self.commits.len() - 1];
+        previous[previous.len().saturating_sub(window)..]
+            .iter()
+            .min_by_key(|(_, elapsed)| *elapsed)
+    }
+
+    /// Gets how many times slower the latest commit is than the best recent one.
+    pub fn slowdown(&self, window: usize) -> Option<f64> {
+        self.best_recent(window)
+            .map(|(_, best)| self.latest().1.as_secs_f64() / best.as_secs_f64().max(f64::EPSILON))
+    }
+}
+
+/// Groups the records into a [Trend] per day and part, ordered by year, day and part.
+pub fn trends(records: &[Record]) -> Vec<Trend> {
+    let mut keys = records
+        .iter()
+        .map(|record| (record.year, record.day, record.part))
+        .collect::<Vec<_>>();
+    keys.sort();
+    keys.dedup();
+
+    keys.into_iter()
+        .filter_map(|(year, day, part)| {
+            let runs = records
+                .iter()
+                .filter(|record| record.year == year && record.day == day && record.part == part)
+                .collect::<Vec<_>>();
+            let input_hash = runs.last()?.input_hash.clone();
+
+            let mut commits: Vec<(String, Duration)> = Vec::new();
+            for run in runs.iter().filter(|run| run.input_hash == input_hash) {
+                let mut best = run.elapsed();
+                if let Some(index) = commits.iter().position(|(commit, _)| *commit == run.commit) {
+                    best = best.min(commits.remove(index).1);
+                }
+                commits.push((run.commit.clone(), best));
+            }
+
+            Some(Trend {
+                year,
+                day,
+                part,
+                input_hash,
+                commits,
+            })
+        })
+        .collect()
+}
+
+/// Reports the trend of every day and part over the last window commits, flagging those where
+/// the latest commit is more than threshold times slower than the best recent one.
+pub fn report(records: &[Record], window: usize, threshold: f64) -> String {
+    let mut report = String::new();
+    for trend in trends(records) {
+        let (commit, latest) = trend.latest();
+        let _ = write!(
+            report,
+            "{} day {} part {}: {:.2?} at {}",
+            trend.year, trend.day, trend.part, latest, commit
+        );
+        match (trend.best_recent(window), trend.slowdown(window)) {
+            (Some((best_commit, best)), Some(slowdown)) => {
+                let _ = write!(
+                    report,
+                    ", best recent {:.2?} at {} ({:.2}x)",
+                    best, best_commit, slowdown
+                );
+                if slowdown > threshold {
+                    report.push_str(" REGRESSION");
+                }
+            }
+            _ => report.push_str(", no earlier commits"),
+        }
+        report.push('\n');
+
+        let recent = &trend.commits[trend.commits.len().saturating_sub(window + 1)..];
+        for (commit, elapsed) in recent {
+            let _ = writeln!(report, "  {commit:<20} {elapsed:.2?}");
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    fn record(commit: &str, input_hash: &str, day: u8, part: u8, millis: u64) -> Record {
+        Record {
+            timestamp: 0,
+            commit: commit.to_string(),
+            input_hash: input_hash.to_string(),
+            year: FIRST_YEAR,
+            day,
+            part,
+            nanos: millis * 1_000_000,
+        }
+    }
+
+    #[test]
+    fn input_hash() {
+        assert_eq!(hash_input(""), "cbf29ce484222325");
+        assert_eq!(hash_input("a"), "af63dc4c8601ec8c");
+        assert_ne!(hash_input("#.\n.."), hash_input("..\n.#"));
+        assert_eq!(
+            hash_reader(&mut "\n\n#.\n\n..\n\n".as_bytes()).ok(),
+            Some(hash_input("#.\n\n.."))
+        );
+    }
+
+    #[test]
+    fn trend_per_commit() {
+        let records = [
+            record("a", "old", 21, 2, 5),
+            record("a", "input", 21, 2, 100),
+            record("a", "input", 21, 2, 90),
+            record("b", "input", 21, 2, 120),
+            record("a", "input", 21, 2, 95),
+            record("a", "input", 21, 1, 10),
+            Record {
+                year: 2015,
+                ..record("a", "input", 25, 1, 1)
+            },
+        ];
+        let trends = trends(&records);
+
+        assert_eq!(trends.len(), 3);
+        assert_eq!((trends[0].year, trends[0].day), (2015, 25));
+        assert_eq!((trends[1].day, trends[1].part), (21, 1));
+        assert_eq!(
+            trends[2],
+            Trend {
+                year: 2023,
+                day: 21,
+                part: 2,
+                input_hash: String::from("input"),
+                commits: vec![
+                    (String::from("b"), Duration::from_millis(120)),
+                    (String::from("a"), Duration::from_millis(90)),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn regression() {
+        let records = [
+            record("a", "input", 21, 2, 100),
+            record("b", "input", 21, 2, 300),
+            record("c", "input", 21, 2, 150),
+            record("d", "input", 21, 2, 250),
+        ];
+        let trend = &trends(&records)[0];
+
+        assert_eq!(
+            trend.best_recent(3).map(|(commit, _)| commit.as_str()),
+            Some("a")
+        );
+        assert_eq!(
+            trend.best_recent(2).map(|(commit, _)| commit.as_str()),
+            Some("c")
+        );
+        assert_eq!(trend.slowdown(3), Some(2.5));
+
+        let report = report(&records, 3, 2.0);
+        assert!(report.starts_with(
+            "2023 day 21 part 2: 250.00ms at d, best recent 100.00ms at a (2.50x) REGRESSION\n"
+        ));
+        assert_eq!(report.lines().count(), 5);
+        assert!(!super::report(&records, 2, 2.0).contains("REGRESSION"));
+    }
+
+    #[test]
+    fn single_commit() {
+        let report = report(&[record("a", "input", 1, 1, 1)], 5, 1.5);
+
+        assert_eq!(
+            report,
+            "2023 day 1 part 1: 1.00ms at a, no earlier commits\n  a                    1.00ms\n"
+        );
+    }
+
+    #[test]
+    fn append_and_load() -> Result<()> {
+        let path = env::temp_dir()
+            .join(format!("aoc-perf-{}", std::process::id()))
+            .join("history.jsonl");
+        let records = [record("a", "input", 1, 1, 1), record("b", "input", 1, 2, 2)];
+        for record in &records {
+            append(&path, record)?;
+        }
+        let loaded = load(&path)?;
+        fs::remove_dir_all(path.parent().unwrap())?;
+
+        assert_eq!(loaded, records);
+        assert_eq!(load(&path)?, []);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,292 @@
+use std::{
+    cell::RefCell,
+    fmt::{self, Display},
+    io::{IsTerminal, stderr},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        mpsc::{self, RecvTimeoutError},
+    },
+    thread,
+    time::Duration,
+};
+
+use crate::config::Format;
+
+/// How far a solve has got, as reported by the solver.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Progress {
+    pub done: u64,
+
+    /// How many items there are to do, if known up front.
+    pub total: Option<u64>,
+
+    /// The best answer found so far, if the solver reports one.
+    pub best: Option<u64>,
+}
+
+impl Display for Progress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.total {
+            Some(total) => write!(
+                f,
+                "{}/{} ({}%)",
+                self.done,
+                total,
+                self.done * 100 / total.max(1)
+            )?,
+            None => write!(f, "{} done", self.done)?,
+        }
+        if let Some(best) = self.best {
+            write!(f, ", best {best}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Which of the answers a solver reports is the best so far, for [Reporter::best].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Goal {
+    Max,
+    Min,
+}
+
+/// The progress of one solve. Solvers report from any thread, so it is kept in atomics. The total
+/// and best fields hold 1 more than their values, with 0 for none.
+#[derive(Default)]
+struct Tracker {
+    started: AtomicBool,
+    done: AtomicU64,
+    total: AtomicU64,
+    best: AtomicU64,
+}
+
+impl Tracker {
+    fn current(&self) -> Option<Progress> {
+        self.started.load(Ordering::Relaxed).then(|| Progress {
+            done: self.done.load(Ordering::Relaxed),
+            total: self.total.load(Ordering::Relaxed).checked_sub(1),
+            best: self.best.load(Ordering::Relaxed).checked_sub(1),
+        })
+    }
+}
+
+thread_local! {
+    /// The [Tracker] of the solve running on this thread, if its progress is tracked.
+    static TRACKER: RefCell<Option<Arc<Tracker>>> = const { RefCell::new(None) };
+}
+
+/// Where a solve reports its [Progress]. It may be cloned into the threads the solve spreads its
+/// work over, and reports nothing where progress is not tracked, as when solves run at once.
+#[derive(Clone, Default)]
+pub struct Reporter(Option<Arc<Tracker>>);
+
+impl Reporter {
+    /// Gets the [Reporter] of the solve running on this thread.
+    pub fn current() -> Self {
+        Self(TRACKER.with_borrow(Clone::clone))
+    }
+
+    /// Runs f with this [Reporter] as the one of the solve running on this thread, as when the
+    /// solve moves to another thread.
+    pub fn within<R>(&self, f: impl FnOnce() -> R) -> R {
+        /// Puts the outer [Tracker] back when dropped, so that it is restored even if f panics.
+        struct Restore(Option<Arc<Tracker>>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                TRACKER.set(self.0.take());
+            }
+        }
+
+        let _restore = Restore(TRACKER.replace(self.0.clone()));
+
+        f()
+    }
+
+    /// Reports another item done.
+    pub fn advance(&self) {
+        if let Some(tracker) = &self.0 {
+            tracker.done.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Reports an answer found, of which the best is the highest or the lowest, by goal.
+    pub fn best(&self, value: usize, goal: Goal) {
+        let Some(tracker) = &self.0 else {
+            return;
+        };
+
+        let value = (value as u64).saturating_add(1);
+        let _ = tracker
+            .best
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |best| {
+                let better = match goal {
+                    Goal::Max => value > best,
+                    Goal::Min => best == 0 || value < best,
+                };
+
+                better.then_some(value)
+            });
+    }
+}
+
+/// Starts reporting the progress of the solve running on this thread over total items, or an
+/// unknown num of items.
+pub fn start(total: Option<usize>) -> Reporter {
+    let reporter = Reporter::current();
+    if let Some(tracker) = &reporter.0 {
+        tracker.done.store(0, Ordering::Relaxed);
+        tracker.total.store(
+            total.map_or(0, |total| (total as u64).saturating_add(1)),
+            Ordering::Relaxed,
+        );
+        tracker.best.store(0, Ordering::Relaxed);
+        tracker.started.store(true, Ordering::Relaxed);
+    }
+
+    reporter
+}
+
+/// Runs solve with its progress tracked, getting the last [Progress] it reported, if any.
+pub fn track<R>(solve: impl FnOnce() -> R) -> (R, Option<Progress>) {
+    let tracker = Arc::new(Tracker::default());
+    let result = Reporter(Some(Arc::clone(&tracker))).within(solve);
+
+    (result, tracker.current())
+}
+
+/// Whether progress is shown for answers in the given [Format]: only as text, and only to a
+/// terminal, so that it never ends up in a file or a pipe.
+pub fn is_shown(format: Format) -> bool {
+    format == Format::Text && stderr().is_terminal()
+}
+
+/// Runs solve, redrawing the [Progress] it reports as a line on stderr until it returns, if shown.
+pub fn show_while<R>(shown: bool, solve: impl FnOnce() -> R) -> R {
+    if !shown {
+        return solve();
+    }
+
+    let tracker = Arc::new(Tracker::default());
+    let (sender, receiver) = mpsc::channel::<()>();
+    let drawer = thread::spawn({
+        let tracker = Arc::clone(&tracker);
+        move || {
+            let mut drawn = false;
+            while let Err(RecvTimeoutError::Timeout) = receiver.recv_timeout(REDRAW_INTERVAL) {
+                if let Some(progress) = tracker.current() {
+                    eprint!("\r{progress}\x1b[K");
+                    drawn = true;
+                }
+            }
+            if drawn {
+                eprint!("\r\x1b[K");
+            }
+        }
+    });
+
+    let result = Reporter(Some(tracker)).within(solve);
+    drop(sender);
+    let _ = drawer.join();
+
+    result
+}
+
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let progress = Progress {
+            done: 3,
+            total: Some(12),
+            best: None,
+        };
+        let unknown = Progress {
+            done: 3,
+            total: None,
+            best: Some(0),
+        };
+
+        assert_eq!(progress.to_string(), "3/12 (25%)");
+        assert_eq!(unknown.to_string(), "3 done, best 0");
+    }
+
+    #[test]
+    fn report() {
+        let ((), reported) = track(|| {
+            let progress = start(Some(4));
+            progress.advance();
+            thread::scope(|scope| {
+                scope.spawn(|| progress.advance());
+            });
+            progress.best(7, Goal::Max);
+            progress.best(5, Goal::Max);
+        });
+
+        assert_eq!(
+            reported,
+            Some(Progress {
+                done: 2,
+                total: Some(4),
+                best: Some(7),
+            })
+        );
+        assert_eq!(show_while(false, || 1), 1);
+    }
+
+    #[test]
+    fn min() {
+        let ((), reported) = track(|| {
+            let progress = start(None);
+            progress.best(7, Goal::Min);
+            progress.best(0, Goal::Min);
+            progress.best(5, Goal::Min);
+        });
+
+        assert_eq!(reported.and_then(|progress| progress.best), Some(0));
+    }
+
+    #[test]
+    fn untracked() {
+        let progress = start(Some(1));
+        progress.advance();
+
+        assert_eq!(track(|| Reporter::current().0.is_some()), (true, None));
+        assert!(Reporter::current().0.is_none());
+    }
+
+    #[test]
+    fn restored_after_panic() {
+        let panicked = std::panic::catch_unwind(|| track(|| panic!("solve panicked")));
+
+        assert!(panicked.is_err());
+        assert!(Reporter::current().0.is_none());
+    }
+
+    #[test]
+    fn separate_solves() {
+        let solve = |done| {
+            track(move || {
+                let progress = start(None);
+                for _ in 0..done {
+                    progress.advance();
+                }
+            })
+            .1
+            .map(|progress| progress.done)
+        };
+        let solves = thread::scope(|scope| {
+            let solves = [3, 5].map(|done| scope.spawn(move || solve(done)));
+
+            solves.map(|solve| solve.join().ok().flatten())
+        });
+
+        assert_eq!(solves, [Some(3), Some(5)]);
+    }
+}
@@ -0,0 +1,235 @@
+use std::{fs, path::Path};
+
+use anyhow::{Result, anyhow};
+
+/// [red, green, blue]
+pub type Colour = [u8; 3];
+
+pub const WALL: Colour = [48, 48, 48];
+pub const OPEN: Colour = [224, 224, 224];
+pub const HIGHLIGHT: Colour = [230, 80, 40];
+pub const SECONDARY: Colour = [60, 140, 220];
+
+/// The side of each tile in pixels, when an [Image] is written as PPM or PNG.
+pub const TILE_PIXELS: usize = 4;
+
+/// A grid of coloured tiles, one tile per grid position.
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub tiles: Vec<Colour>,
+}
+
+impl Image {
+    /// Creates an [Image] of the given size, filled with colour.
+    pub fn new(width: usize, height: usize, colour: Colour) -> Self {
+        Self {
+            width,
+            height,
+            tiles: vec![colour; width * height],
+        }
+    }
+
+    /// Creates an [Image] from the bytes of a grid puzzle, colouring `#` as [WALL] and everything
+    /// else as [OPEN].
+    pub fn from_layout(layout: &[Vec<u8>]) -> Self {
+        let width = layout.first().map_or(0, |row| row.len());
+        let mut image = Self::new(width, layout.len(), OPEN);
+        for (row, bytes) in layout.iter().enumerate() {
+            for (col, byte) in bytes.iter().enumerate().take(width) {
+                if *byte == b'#' {
+                    image.set(row, col, WALL);
+                }
+            }
+        }
+
+        image
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Colour {
+        self.tiles[row * self.width + col]
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, colour: Colour) {
+        self.tiles[row * self.width + col] = colour;
+    }
+
+    /// Counts the tiles of the given colour.
+    pub fn count(&self, colour: Colour) -> usize {
+        self.tiles.iter().filter(|tile| **tile == colour).count()
+    }
+
+    /// Writes this [Image] to path, in the format given by its extension: ppm, png or svg.
+    pub fn save(&self, path: &str) -> Result<()> {
+        let extension = Path::new(path)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default();
+
+        match extension.to_ascii_lowercase().as_str() {
+            "ppm" => fs::write(path, self.to_ppm())?,
+            "png" => fs::write(path, self.to_png())?,
+            "svg" => fs::write(path, self.to_svg())?,
+            _ => return Err(anyhow!("Invalid image format: {}", path)),
+        }
+
+        Ok(())
+    }
+
+    /// Scales each tile up to [TILE_PIXELS] x [TILE_PIXELS], giving the rows of RGB bytes.
+    fn scanlines(&self) -> Vec<Vec<u8>> {
+        (0..self.height)
+            .flat_map(|row| {
+                let scanline = (0..self.width)
+                    .flat_map(|col| std::iter::repeat_n(self.get(row, col), TILE_PIXELS).flatten())
+                    .collect::<Vec<_>>();
+
+                std::iter::repeat_n(scanline, TILE_PIXELS)
+            })
+            .collect()
+    }
+
+    /// Encodes this [Image] as a binary PPM (P6).
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut bytes = format!(
+            "P6\n{} {}\n255\n",
+            self.width * TILE_PIXELS,
+            self.height * TILE_PIXELS
+        )
+        .into_bytes();
+        bytes.extend(self.scanlines().into_iter().flatten());
+
+        bytes
+    }
+
+    /// Encodes this [Image] as an 8-bit RGB PNG.
+    ///
+    /// The image data is stored uncompressed, which keeps the encoder small at the cost of larger
+    /// files.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut header = Vec::new();
+        header.extend(((self.width * TILE_PIXELS) as u32).to_be_bytes());
+        header.extend(((self.height * TILE_PIXELS) as u32).to_be_bytes());
+        // Bit depth 8, colour type RGB, default compression, filter and interlace methods.
+        header.extend([8, 2, 0, 0, 0]);
+
+        // Each scanline is prefixed by its filter type, which is always None.
+        let data = self
+            .scanlines()
+            .into_iter()
+            .flat_map(|scanline| std::iter::once(0).chain(scanline))
+            .collect::<Vec<_>>();
+
+        let mut bytes = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+        write_png_chunk(&mut bytes, b"IHDR", &header);
+        write_png_chunk(&mut bytes, b"IDAT", &zlib_stored(&data));
+        write_png_chunk(&mut bytes, b"IEND", &[]);
+
+        bytes
+    }
+
+    /// Encodes this [Image] as an SVG, with one rect per tile.
+    pub fn to_svg(&self) -> String {
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {} {}\" width=\"{}\" height=\"{}\" shape-rendering=\"crispEdges\">\n",
+            self.width,
+            self.height,
+            self.width * TILE_PIXELS,
+            self.height * TILE_PIXELS
+        );
+        for row in 0..self.height {
+            for col in 0..self.width {
+                let [red, green, blue] = self.get(row, col);
+                svg += &format!(
+                    "<rect x=\"{col}\" y=\"{row}\" width=\"1\" height=\"1\" fill=\"#{red:02x}{green:02x}{blue:02x}\"/>\n"
+                );
+            }
+        }
+        svg += "</svg>\n";
+
+        svg
+    }
+}
+
+fn write_png_chunk(bytes: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    bytes.extend((data.len() as u32).to_be_bytes());
+    let start = bytes.len();
+    bytes.extend(chunk_type);
+    bytes.extend(data);
+    let crc = crc32(&bytes[start..]);
+    bytes.extend(crc.to_be_bytes());
+}
+
+/// Wraps data in a zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // Deflate, 32K window, no preset dictionary, with the check bits making the header a multiple
+    // of 31.
+    let mut bytes = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        bytes.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let len = block.len() as u16;
+        bytes.push(blocks.peek().is_none() as u8);
+        bytes.extend(len.to_le_bytes());
+        bytes.extend((!len).to_le_bytes());
+        bytes.extend(block);
+    }
+    bytes.extend(adler32(data).to_be_bytes());
+
+    bytes
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(u32::MAX, |crc, byte| {
+        (0..8).fold(crc ^ *byte as u32, |crc, _| {
+            if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb88320
+            } else {
+                crc >> 1
+            }
+        })
+    })
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1u32, 0u32), |(a, b), byte| {
+        let a = (a + *byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae426082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+    }
+
+    #[test]
+    fn ppm() {
+        let mut image = Image::new(2, 1, OPEN);
+        image.set(0, 1, WALL);
+        let ppm = image.to_ppm();
+
+        assert!(ppm.starts_with(b"P6\n8 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 8 * 4 * 3);
+        assert_eq!(ppm[11..14], OPEN);
+        assert_eq!(ppm[ppm.len() - 3..], WALL);
+    }
+
+    #[test]
+    fn png() {
+        let image = Image::from_layout(&[b"#.".to_vec(), b".#".to_vec()]);
+        let png = image.to_png();
+
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x08\0\0\0\x08"));
+        assert!(png.ends_with(b"\0\0\0\0IEND\xae\x42\x60\x82"));
+    }
+}
@@ -0,0 +1,202 @@
+//! Checks of a [Solver] against its examples and generated inputs, for the tests of the workspace.
+//! Only built with the `test-support` feature.
+
+use std::path::Path;
+
+use anyhow::{Result, anyhow};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::{Solver, Unsound, config::Settings, examples, parallel};
+
+impl Solver {
+    /// Checks that the answers to every example fixture in dir match the expected answers, where
+    /// known.
+    ///
+    /// Returns the num of answers checked.
+    pub fn verify_examples(&self, dir: &Path) -> Result<usize> {
+        let mut checked = 0;
+        for (path, example) in examples::load_fixtures(dir)? {
+            for (part, expected) in (1..=2).zip(example.answers) {
+                let Some(expected) = expected else {
+                    continue;
+                };
+
+                let answer = self.solve_with(part, &example.input, &example.settings)?;
+                if answer != expected {
+                    return Err(anyhow!(
+                        "Wrong answer to part {} of {}: {} != {}",
+                        part,
+                        path.display(),
+                        answer,
+                        expected
+                    ));
+                }
+                checked += 1;
+            }
+        }
+
+        Ok(checked)
+    }
+
+    /// Checks that the answers to a generated puzzle input match the expected answers, where known.
+    pub fn verify_generated(&self, seed: u64, size: usize) -> Result<()> {
+        let generated = self.generate(seed, size);
+        for (part, expected) in (1..=2).zip(generated.answers) {
+            let Some(expected) = expected else {
+                continue;
+            };
+
+            let answer = self.solve(part, &generated.input)?;
+            if answer != expected {
+                return Err(anyhow!(
+                    "Wrong answer to part {} with seed {} and size {}: {} != {}",
+                    part,
+                    seed,
+                    size,
+                    answer,
+                    expected
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that solving a generated puzzle input a line at a time gives the same answers as
+    /// solving it whole.
+    pub fn verify_stream(&self, seed: u64, size: usize) -> Result<()> {
+        let generated = self.generate(seed, size);
+        for part in 1..=2 {
+            let answer =
+                self.solve_stream(part, &mut generated.input.as_bytes(), &Settings::default())?;
+            let expected = self.solve(part, &generated.input)?;
+            if answer != expected {
+                return Err(anyhow!(
+                    "Wrong streamed answer to part {} with seed {} and size {}: {} != {}",
+                    part,
+                    seed,
+                    size,
+                    answer,
+                    expected
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the witness to the given part of a generated puzzle input passes the checker,
+    /// and that the answer matches the one solved without a witness.
+    pub fn verify_certified(&self, part: u8, seed: u64, size: usize) -> Result<()> {
+        let generated = self.generate(seed, size);
+        let certificate = self.certify(part, &generated.input)?;
+        self.check(part, &generated.input, &certificate)
+            .map_err(|error| anyhow!("{} in part {} with seed {}", error, part, seed))?;
+        certificate.expect_answer(self.solve(part, &generated.input)?)?;
+
+        Ok(())
+    }
+
+    /// Checks that both parts of a generated puzzle input get the same answers on 1 thread as on
+    /// several, or the same errors for inputs the solver does not support.
+    pub fn verify_threads(&self, seed: u64, size: usize) -> Result<()> {
+        let generated = self.generate(seed, size);
+        let num_parts = if self.part_2.is_some() { 2 } else { 1 };
+        let outcome = |threads, part| {
+            parallel::with_threads(threads, || {
+                self.solve(part, &generated.input)
+                    .unwrap_or_else(|error| format!("error: {error}"))
+            })
+        };
+        for part in 1..=num_parts {
+            let answer = outcome(1, part)?;
+            let expected = outcome(4, part)?;
+            if answer != expected {
+                return Err(anyhow!(
+                    "Answer to part {} with seed {} and size {} depends on threads: {} != {}",
+                    part,
+                    seed,
+                    size,
+                    answer,
+                    expected
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that truncated and corrupted versions of a generated puzzle input are answered or
+    /// rejected with an error, rather than panicking or running for more than timeout seconds.
+    pub fn verify_malformed(&self, seed: u64, size: usize, timeout: u64) -> Result<()> {
+        let generated = self.generate(seed, size);
+        let num_parts = if self.part_2.is_some() { 2 } else { 1 };
+        let mut rng = StdRng::seed_from_u64(seed);
+        for input in malform(&generated.input, &mut rng) {
+            for part in 1..=num_parts {
+                let answer = self.solve_with_timeout(
+                    part,
+                    input.clone(),
+                    Settings::default(),
+                    Some(timeout),
+                );
+                if let Err(error) = answer
+                    && error.is::<Unsound>()
+                {
+                    return Err(anyhow!(
+                        "{} in part {} with seed {} and size {} on input {:?}",
+                        error,
+                        part,
+                        seed,
+                        size,
+                        input
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Derives malformed inputs from a well-formed one: the empty input, truncations at random points,
+/// and copies with a character replaced or removed, or a line removed or repeated.
+fn malform(input: &str, rng: &mut StdRng) -> Vec<String> {
+    const REPLACEMENTS: [char; 12] = ['0', '9', '-', 'x', 'Z', ' ', ',', '=', '#', '.', '\n', 'é'];
+
+    let mut inputs = vec![String::new()];
+    let chars = input.chars().collect::<Vec<_>>();
+    let lines = input.lines().collect::<Vec<_>>();
+    if chars.is_empty() {
+        return inputs;
+    }
+
+    for _ in 0..8 {
+        let len = rng.random_range(0..chars.len());
+        inputs.push(chars[..len].iter().collect());
+    }
+    for _ in 0..8 {
+        let index = rng.random_range(0..chars.len());
+
+        let mut replaced = chars.clone();
+        replaced[index] = REPLACEMENTS[rng.random_range(0..REPLACEMENTS.len())];
+        inputs.push(replaced.into_iter().collect());
+
+        let mut removed = chars.clone();
+        removed.remove(index);
+        inputs.push(removed.into_iter().collect());
+    }
+    for _ in 0..4 {
+        let index = rng.random_range(0..lines.len());
+
+        let mut removed = lines.clone();
+        removed.remove(index);
+        inputs.push(removed.join("\n"));
+
+        let mut repeated = lines.clone();
+        repeated.insert(index, lines[index]);
+        inputs.push(repeated.join("\n"));
+    }
+
+    inputs
+}
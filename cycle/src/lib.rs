@@ -0,0 +1,229 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// The shape of the sequence x_0, f(x_0), f(f(x_0)), ... produced by iterating a step function.
+///
+/// x_i == x_(i + cycle_len) for every i >= prefix_len.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    /// The num of states before the cycle is entered.
+    pub prefix_len: usize,
+
+    /// The num of states in the cycle.
+    pub cycle_len: usize,
+}
+
+impl Cycle {
+    /// Reduces iterations to the smallest num of iterations that reaches the same state.
+    pub fn equivalent_iterations(&self, iterations: usize) -> usize {
+        if iterations < self.prefix_len {
+            iterations
+        } else {
+            self.prefix_len + (iterations - self.prefix_len) % self.cycle_len
+        }
+    }
+}
+
+/// Finds the [Cycle] by remembering every state seen so far.
+///
+/// Each state is only stepped once, but all states are kept in memory.
+pub fn find_cycle<T, F>(initial: T, step: F) -> Cycle
+where
+    T: Clone + Eq + Hash,
+    F: FnMut(&T) -> T,
+{
+    walk_history(initial, None, step).0
+}
+
+/// Finds the [Cycle] using Floyd's tortoise and hare.
+///
+/// Only a couple of states are kept in memory, but states are stepped about 3 times each.
+pub fn find_cycle_floyd<T, F>(initial: T, mut step: F) -> Cycle
+where
+    T: Clone + Eq,
+    F: FnMut(&T) -> T,
+{
+    // Find some x_i == x_2i, where i is a multiple of the cycle len.
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+    }
+
+    // Restart the tortoise: both now meet at the start of the cycle.
+    let mut prefix_len = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix_len += 1;
+    }
+
+    let mut cycle_len = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        cycle_len += 1;
+    }
+
+    Cycle {
+        prefix_len,
+        cycle_len,
+    }
+}
+
+/// Finds the [Cycle] using Brent's algorithm.
+///
+/// Only a couple of states are kept in memory, and usually needs fewer steps than
+/// [find_cycle_floyd].
+pub fn find_cycle_brent<T, F>(initial: T, mut step: F) -> Cycle
+where
+    T: Clone + Eq,
+    F: FnMut(&T) -> T,
+{
+    // Search successive powers of 2 for the cycle len.
+    let mut power = 1;
+    let mut cycle_len = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == cycle_len {
+            tortoise = hare.clone();
+            power *= 2;
+            cycle_len = 0;
+        }
+
+        hare = step(&hare);
+        cycle_len += 1;
+    }
+
+    // Keep the hare cycle_len ahead of the tortoise: both now meet at the start of the cycle.
+    let mut prefix_len = 0;
+    tortoise = initial.clone();
+    hare = initial;
+    for _ in 0..cycle_len {
+        hare = step(&hare);
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix_len += 1;
+    }
+
+    Cycle {
+        prefix_len,
+        cycle_len,
+    }
+}
+
+/// Gets the state after applying step the given num of iterations, skipping over the repeats once a
+/// [Cycle] is found.
+pub fn state_after<T, F>(initial: T, iterations: usize, step: F) -> T
+where
+    T: Clone + Eq + Hash,
+    F: FnMut(&T) -> T,
+{
+    let (cycle, mut history) = walk_history(initial, Some(iterations), step);
+
+    history.swap_remove(cycle.equivalent_iterations(iterations))
+}
+
+/// Steps through states until either a repeat is found, or the iteration limit is reached.
+///
+/// Returns the [Cycle] found so far, and every state seen in order.
+fn walk_history<T, F>(initial: T, limit: Option<usize>, mut step: F) -> (Cycle, Vec<T>)
+where
+    T: Clone + Eq + Hash,
+    F: FnMut(&T) -> T,
+{
+    let mut seen = HashMap::new();
+    let mut history = Vec::new();
+    let mut state = initial;
+    loop {
+        let index = history.len();
+
+        if let Some(&prefix_len) = seen.get(&state) {
+            let cycle = Cycle {
+                prefix_len,
+                cycle_len: index - prefix_len,
+            };
+
+            return (cycle, history);
+        }
+
+        seen.insert(state.clone(), index);
+
+        if limit.is_some_and(|limit| index == limit) {
+            // No repeat yet, so treat the limit as the end of a prefix whose cycle is never entered.
+            let cycle = Cycle {
+                prefix_len: index + 1,
+                cycle_len: 1,
+            };
+            history.push(state);
+
+            return (cycle, history);
+        }
+
+        let next = step(&state);
+        history.push(state);
+        state = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 7 -> 3 -> ...
+    fn rho(state: &u32) -> u32 {
+        if *state == 7 { 3 } else { state + 1 }
+    }
+
+    #[test]
+    fn all_finders_agree() {
+        let expected = Cycle {
+            prefix_len: 3,
+            cycle_len: 5,
+        };
+
+        assert_eq!(find_cycle(0, rho), expected);
+        assert_eq!(find_cycle_floyd(0, rho), expected);
+        assert_eq!(find_cycle_brent(0, rho), expected);
+    }
+
+    #[test]
+    fn pure_cycle() {
+        let expected = Cycle {
+            prefix_len: 0,
+            cycle_len: 4,
+        };
+        let step = |state: &u32| (state + 1) % 4;
+
+        assert_eq!(find_cycle(0, step), expected);
+        assert_eq!(find_cycle_floyd(0, step), expected);
+        assert_eq!(find_cycle_brent(0, step), expected);
+    }
+
+    #[test]
+    fn fixed_point() {
+        let expected = Cycle {
+            prefix_len: 0,
+            cycle_len: 1,
+        };
+
+        assert_eq!(find_cycle(9, |state: &u32| *state), expected);
+        assert_eq!(find_cycle_floyd(9, |state: &u32| *state), expected);
+        assert_eq!(find_cycle_brent(9, |state: &u32| *state), expected);
+    }
+
+    #[test]
+    fn states_after_iterations() {
+        let naive = |iterations| (0..iterations).fold(0, |state, _| rho(&state));
+
+        for iterations in 0..30 {
+            assert_eq!(state_after(0, iterations, rho), naive(iterations));
+        }
+        assert_eq!(state_after(0, 1000000000, rho), 3 + (1000000000 - 3) % 5);
+    }
+}
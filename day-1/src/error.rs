@@ -0,0 +1,28 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind, answer::Overflow};
+
+/// Why a day 1 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    /// A line without any digit, which has no calibration value.
+    #[error("Cannot find first digit in {0:?}")]
+    NoFirstDigit(String),
+
+    /// A line of a vocabulary file that is not a word and its value.
+    #[error("Invalid vocabulary line {0:?}")]
+    InvalidVocabulary(String),
+
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::InvalidVocabulary(_) => ErrorKind::Parse,
+            Error::NoFirstDigit(_) => ErrorKind::Validation,
+            Error::Overflow(_) => ErrorKind::Unsolvable,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use rand::{Rng, rngs::StdRng, seq::IndexedRandom};
+
+use cli::Generated;
+
+/// Letters that do not appear in any digit word, so they can never form one by accident.
+const FILLER: &[u8] = b"abcdjklmpqy";
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Pairs of digit words that share letters, with the values of the first and the last word.
+const OVERLAPS: [(&str, u32, u32); 8] = [
+    ("oneight", 1, 8),
+    ("twone", 2, 1),
+    ("threeight", 3, 8),
+    ("fiveight", 5, 8),
+    ("sevenine", 7, 9),
+    ("eightwo", 8, 2),
+    ("eighthree", 8, 3),
+    ("nineight", 9, 8),
+];
+
+/// Generates size lines of filler, digits, digit words and overlapping digit words.
+///
+/// Every line has at least one digit, so both parts can be answered.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let mut lines = Vec::new();
+    let (mut sum_1, mut sum_2) = (0, 0);
+    for _ in 0..size {
+        let mut line = String::new();
+        let mut digits = Vec::new();
+        let mut values = Vec::new();
+
+        let num_tokens = rng.random_range(1..=8);
+        let digit_token = rng.random_range(0..num_tokens);
+        for token in 0..num_tokens {
+            for _ in 0..rng.random_range(0..=3) {
+                line.push(*FILLER.choose(rng).unwrap() as char);
+            }
+
+            if token == digit_token || rng.random_bool(0.3) {
+                let digit = rng.random_range(1..=9);
+                line.push(char::from_digit(digit, 10).unwrap());
+                digits.push(digit);
+                values.push((digit, digit));
+            } else {
+                // Separate words from anything before them, so no other word is formed.
+                line.push(*FILLER.choose(rng).unwrap() as char);
+                if rng.random_bool(0.7) {
+                    let digit = rng.random_range(1..=9);
+                    line.push_str(WORDS[digit as usize - 1]);
+                    values.push((digit, digit));
+                } else {
+                    let (word, first, last) = *OVERLAPS.choose(rng).unwrap();
+                    line.push_str(word);
+                    values.push((first, last));
+                }
+                line.push(*FILLER.choose(rng).unwrap() as char);
+            }
+        }
+
+        sum_1 += digits[0] * 10 + digits[digits.len() - 1];
+        sum_2 += values[0].0 * 10 + values[values.len() - 1].1;
+        lines.push(line);
+    }
+
+    Generated {
+        input: lines.join("\n"),
+        answers: [Some(sum_1.to_string()), Some(sum_2.to_string())],
+    }
+}
@@ -0,0 +1,166 @@
+use std::borrow::Cow;
+
+use anyhow::{Result, anyhow};
+
+use cli::{
+    Solver,
+    answer::{Answer, with_width},
+    config::Settings,
+};
+
+pub use error::Error;
+use scanner::Scanner;
+
+mod error;
+pub mod generator;
+pub mod report;
+pub mod scanner;
+pub mod stream;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 1,
+    version: env!("SOLVER_VERSION"),
+    part_1: |input, settings| with_width(settings, || part_1(input), || part_1_as::<u128>(input)),
+    part_2: Some(|input, settings| {
+        let scanner = spelled_scanner(settings)?;
+
+        with_width(
+            settings,
+            || sum_calibration_values::<u32>(input, &scanner),
+            || sum_calibration_values::<u128>(input, &scanner),
+        )
+    }),
+    generator: generator::generate,
+    render: None,
+    animate: None,
+    dump: None,
+    stream: Some(stream::stream),
+    certify: None,
+    report: Some(report::report),
+    classify: cli::classify::<Error>,
+};
+
+/// The setting with a vocabulary, whose words replace zero to nine in part 2. See
+/// [Scanner::with_vocabulary]. In aoc.toml, it is read from a file, as
+/// `vocabulary = { file = "<PATH>" }`.
+pub const VOCABULARY: &str = "vocabulary";
+
+pub fn part_1(input: &str) -> Result<u32, Error> {
+    part_1_as(input)
+}
+
+pub fn part_2(input: &str) -> Result<u32, Error> {
+    part_2_as(input)
+}
+
+/// Sums the calibration values in A.
+pub fn part_1_as<A: Answer + From<u32>>(input: &str) -> Result<A, Error> {
+    sum_calibration_values(input, Scanner::digits())
+}
+
+/// Sums the spelled calibration values in A.
+pub fn part_2_as<A: Answer + From<u32>>(input: &str) -> Result<A, Error> {
+    sum_calibration_values(input, Scanner::digits_and_words())
+}
+
+/// Sums in A the calibration values of the lines, as found by the [Scanner].
+pub fn sum_calibration_values<A: Answer + From<u32>>(
+    input: &str,
+    scanner: &Scanner,
+) -> Result<A, Error> {
+    input.lines().try_fold(A::default(), |sum, line| {
+        Ok(sum.try_add(A::from(scanner.calibration_value(line)?))?)
+    })
+}
+
+/// Gets the [Scanner] of the given part.
+pub fn part_scanner(part: u8, settings: &Settings) -> Result<Cow<'static, Scanner>> {
+    match part {
+        1 => Ok(Cow::Borrowed(Scanner::digits())),
+        2 => spelled_scanner(settings),
+        _ => Err(anyhow!("Invalid part number: {}", part)),
+    }
+}
+
+/// Gets the [Scanner] of part 2, of the [VOCABULARY] in the settings if there is one.
+pub fn spelled_scanner(settings: &Settings) -> Result<Cow<'static, Scanner>> {
+    Ok(match settings.get::<String>(VOCABULARY)? {
+        Some(vocabulary) => Cow::Owned(Scanner::with_vocabulary(&vocabulary)?),
+        None => Cow::Borrowed(Scanner::digits_and_words()),
+    })
+}
+
+/// Combines the first and the last digit of the line.
+pub fn calibration_value(line: &str) -> Result<u32, Error> {
+    Scanner::digits().calibration_value(line)
+}
+
+/// Combines the first and the last digit of the line, where digits may also be spelled out.
+pub fn spelled_calibration_value(line: &str) -> Result<u32, Error> {
+    Scanner::digits_and_words().calibration_value(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::trim_newlines;
+
+    use super::*;
+
+    #[test]
+    fn example_1() -> Result<()> {
+        let example = r"
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+";
+
+        assert_eq!(part_1(trim_newlines(example))?, 142);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        let example = r"
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+        assert_eq!(part_2(trim_newlines(example))?, 281);
+
+        Ok(())
+    }
+
+    #[test]
+    fn vocabulary() -> Result<()> {
+        let mut settings = Settings::default();
+        settings.set(VOCABULARY, "un 1\ndeux 2\ntrois 3\nonze 11\n");
+
+        assert_eq!(
+            SOLVER.solve_with(2, "undeux\ntwo3onze\ntrois", &settings)?,
+            (12 + 311 + 33).to_string()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn line_without_digits() {
+        assert_eq!(
+            part_1("1abc2\npqrstu"),
+            Err(Error::NoFirstDigit(String::from("pqrstu")))
+        );
+        assert_eq!(
+            part_2("two1nine\nxyz"),
+            Err(Error::NoFirstDigit(String::from("xyz")))
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+
+use cli::config::Settings;
+
+use crate::{part_scanner, scanner::combine};
+
+/// Reports, for every line, the first and the last token found by the scanner of the given part,
+/// with their byte offsets, and the calibration value they make. Every line without digits is
+/// flagged, rather than stopping at the first.
+pub fn report(input: &str, part: u8, settings: &Settings) -> Result<String> {
+    let scanner = part_scanner(part, settings)?;
+
+    let mut report = String::new();
+    let mut num_without_digits = 0;
+    for (index, line) in input.lines().enumerate() {
+        write!(report, "line {}: ", index + 1)?;
+        let Some((first, last)) = scanner.first_and_last(line) else {
+            num_without_digits += 1;
+            writeln!(report, "NO DIGITS in {line:?}")?;
+            continue;
+        };
+
+        let token = |start, len| &line[start..start + len];
+        let value =
+            combine(first, last).map_or_else(|error| error.to_string(), |value| value.to_string());
+        writeln!(
+            report,
+            "first {:?} at {} = {}, last {:?} at {} = {}, value {}",
+            token(first.start, first.len),
+            first.start,
+            first.value,
+            token(last.start, last.len),
+            last.start,
+            last.value,
+            value
+        )?;
+    }
+    write!(
+        report,
+        "{} of {} lines without digits",
+        num_without_digits,
+        input.lines().count()
+    )?;
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_line() -> Result<()> {
+        let report = report("two1nine\nxyz\neightwo\nabc", 2, &Settings::default())?;
+
+        assert_eq!(
+            report,
+            r#"line 1: first "two" at 0 = 2, last "nine" at 4 = 9, value 29
+line 2: NO DIGITS in "xyz"
+line 3: first "eight" at 0 = 8, last "two" at 4 = 2, value 82
+line 4: NO DIGITS in "abc"
+2 of 4 lines without digits"#
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn digits_only() -> Result<()> {
+        let report = report("two1nine", 1, &Settings::default())?;
+
+        assert!(report.starts_with(r#"line 1: first "1" at 3 = 1, last "1" at 3 = 1, value 11"#));
+
+        Ok(())
+    }
+}
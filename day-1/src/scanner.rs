@@ -0,0 +1,322 @@
+use std::{cmp::Reverse, collections::VecDeque, sync::LazyLock};
+
+use cli::answer::{Answer, Overflow};
+
+use crate::Error;
+
+const DIGITS: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+
+const WORDS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+static DIGITS_ONLY: LazyLock<Scanner> = LazyLock::new(|| Scanner::new(DIGITS.into_iter().zip(0..)));
+
+static DIGITS_AND_WORDS: LazyLock<Scanner> = LazyLock::new(|| {
+    Scanner::new(
+        DIGITS
+            .into_iter()
+            .zip(0..)
+            .chain(WORDS.into_iter().zip(0..)),
+    )
+});
+
+/// A pattern found in a line, such as a digit or a word for one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Token {
+    /// The byte offset of the token in the line.
+    pub start: usize,
+
+    /// In bytes.
+    pub len: usize,
+
+    pub value: u32,
+}
+
+/// Finds every pattern in a line in a single pass, including patterns that overlap, such as the
+/// eight and the two in "eightwo".
+///
+/// This is an Aho-Corasick automaton: a trie of the patterns, where a byte that does not continue
+/// the text matched so far falls back to the longest suffix of it that does, compiled into a table
+/// of the next state for every state and byte.
+#[derive(Clone)]
+pub struct Scanner {
+    /// state => byte => state
+    transitions: Vec<[u32; 256]>,
+
+    /// state => the patterns that end there, by index
+    outputs: Vec<Vec<usize>>,
+
+    /// pattern => (len, value)
+    patterns: Vec<(usize, u32)>,
+}
+
+impl Scanner {
+    /// Builds the [Scanner] of the patterns, each with its value. Empty patterns are skipped, as
+    /// they would be found everywhere.
+    pub fn new<'a>(patterns: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        // The trie, where a transition to the root is no edge, as no edge leads back to it.
+        let mut transitions = vec![[0u32; 256]];
+        let mut outputs = vec![Vec::new()];
+        let mut lens_and_values = Vec::new();
+        for (pattern, value) in patterns
+            .into_iter()
+            .filter(|(pattern, _)| !pattern.is_empty())
+        {
+            let mut state = 0;
+            for byte in pattern.bytes() {
+                if transitions[state][byte as usize] == 0 {
+                    transitions[state][byte as usize] = transitions.len() as u32;
+                    transitions.push([0; 256]);
+                    outputs.push(Vec::new());
+                }
+                state = transitions[state][byte as usize] as usize;
+            }
+            outputs[state].push(lens_and_values.len());
+            lens_and_values.push((pattern.len(), value));
+        }
+
+        // Breadth first, so that the state each one falls back to is complete before it: the
+        // missing transitions are those of the fallback, and the patterns found there are also
+        // found here.
+        let mut fallbacks = vec![0; transitions.len()];
+        let mut queue = transitions[0]
+            .iter()
+            .filter(|&&next| next != 0)
+            .map(|&next| next as usize)
+            .collect::<VecDeque<_>>();
+        while let Some(state) = queue.pop_front() {
+            let fallback = fallbacks[state];
+            let inherited = outputs[fallback].clone();
+            outputs[state].extend(inherited);
+
+            let fallback_transitions = transitions[fallback];
+            for (next, &fallback_next) in transitions[state].iter_mut().zip(&fallback_transitions) {
+                if *next == 0 {
+                    *next = fallback_next;
+                } else {
+                    fallbacks[*next as usize] = fallback_next as usize;
+                    queue.push_back(*next as usize);
+                }
+            }
+        }
+
+        Self {
+            transitions,
+            outputs,
+            patterns: lens_and_values,
+        }
+    }
+
+    /// Builds the [Scanner] of the digits 0 to 9 and the words of a vocabulary, which has a word
+    /// and its value on each line, such as `eins 1` or `eleven 11`. Blank lines are skipped.
+    pub fn with_vocabulary(vocabulary: &str) -> Result<Self, Error> {
+        let words = vocabulary
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let (word, value) = line
+                    .split_once(char::is_whitespace)
+                    .ok_or_else(|| Error::InvalidVocabulary(line.to_string()))?;
+                let value = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| Error::InvalidVocabulary(line.to_string()))?;
+
+                Ok((word, value))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok(Self::new(DIGITS.into_iter().zip(0..).chain(words)))
+    }
+
+    /// Gets the [Scanner] of the digits 0 to 9.
+    pub fn digits() -> &'static Scanner {
+        &DIGITS_ONLY
+    }
+
+    /// Gets the [Scanner] of the digits 0 to 9 and their words, zero to nine.
+    pub fn digits_and_words() -> &'static Scanner {
+        &DIGITS_AND_WORDS
+    }
+
+    /// Finds every [Token] in the line, in the order they end.
+    pub fn tokens<'a>(&'a self, line: &'a str) -> impl Iterator<Item = Token> + 'a {
+        let mut state = 0;
+        line.bytes().enumerate().flat_map(move |(index, byte)| {
+            state = self.transitions[state][byte as usize] as usize;
+            self.outputs[state].iter().map(move |&pattern| {
+                let (len, value) = self.patterns[pattern];
+                Token {
+                    start: index + 1 - len,
+                    len,
+                    value,
+                }
+            })
+        })
+    }
+
+    /// Finds the first and the last [Token] in the line, if there are any. Of tokens that start
+    /// at the same byte, the longest is taken.
+    pub fn first_and_last(&self, line: &str) -> Option<(Token, Token)> {
+        let mut tokens = self.tokens(line);
+        let token = tokens.next()?;
+
+        Some(tokens.fold((token, token), |(first, last), token| {
+            (
+                if (token.start, Reverse(token.len)) < (first.start, Reverse(first.len)) {
+                    token
+                } else {
+                    first
+                },
+                if (token.start, token.len) > (last.start, last.len) {
+                    token
+                } else {
+                    last
+                },
+            )
+        }))
+    }
+
+    /// Combines the values of the first and the last [Token] in the line with [combine], so that
+    /// eleven and 3 make 113.
+    pub fn calibration_value(&self, line: &str) -> Result<u32, Error> {
+        let (first, last) = self
+            .first_and_last(line)
+            .ok_or_else(|| Error::NoFirstDigit(line.to_string()))?;
+
+        Ok(combine(first, last)?)
+    }
+}
+
+/// Writes the digits of the value of last after those of first.
+pub fn combine(first: Token, last: Token) -> Result<u32, Overflow> {
+    let shift = 10u32.try_pow(last.value.checked_ilog10().unwrap_or(0) + 1)?;
+
+    first.value.try_mul(shift)?.try_add(last.value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(start: usize, len: usize, value: u32) -> Token {
+        Token { start, len, value }
+    }
+
+    #[test]
+    fn overlapping() {
+        let tokens = Scanner::digits_and_words()
+            .tokens("eightwo3")
+            .collect::<Vec<_>>();
+
+        assert_eq!(tokens, [token(0, 5, 8), token(4, 3, 2), token(7, 1, 3)]);
+    }
+
+    #[test]
+    fn digits_only() {
+        assert_eq!(
+            Scanner::digits().first_and_last("one2three4five"),
+            Some((token(3, 1, 2), token(9, 1, 4)))
+        );
+        assert_eq!(Scanner::digits().first_and_last("onetwo"), None);
+    }
+
+    #[test]
+    fn fallback() {
+        // Once "seve" fails to continue as "seven", the "e" may still start "eight".
+        let scanner = Scanner::digits_and_words();
+
+        assert_eq!(scanner.calibration_value("seveight"), Ok(88));
+        assert_eq!(scanner.calibration_value("ninine"), Ok(99));
+        assert_eq!(scanner.calibration_value("zerone"), Ok(1));
+    }
+
+    #[test]
+    fn longest_at_same_start() {
+        let scanner = Scanner::new([("t", 1), ("ten", 10), ("", 0)]);
+
+        assert_eq!(
+            scanner.first_and_last("tent"),
+            Some((token(0, 3, 10), token(3, 1, 1)))
+        );
+    }
+
+    #[test]
+    fn vocabulary() -> Result<(), Error> {
+        let scanner = Scanner::with_vocabulary("eins 1\nzwölf 12\n\ndeux  2\n")?;
+
+        assert_eq!(scanner.calibration_value("zweinsx"), Ok(11));
+        assert_eq!(scanner.calibration_value("3zwölfe"), Ok(312));
+        assert_eq!(scanner.calibration_value("zwölfdeux"), Ok(122));
+        assert_eq!(
+            scanner.first_and_last("äeins"),
+            Some((token(2, 4, 1), token(2, 4, 1)))
+        );
+        assert_eq!(
+            scanner.calibration_value("one"),
+            Err(Error::NoFirstDigit(String::from("one")))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_vocabulary() {
+        assert!(matches!(
+            Scanner::with_vocabulary("eins"),
+            Err(Error::InvalidVocabulary(_))
+        ));
+        assert!(matches!(
+            Scanner::with_vocabulary("eins one"),
+            Err(Error::InvalidVocabulary(_))
+        ));
+    }
+
+    #[test]
+    fn multi_digit_overflow() {
+        let scanner = Scanner::new([("big", 4_000_000_000), ("1", 1)]);
+
+        assert_eq!(scanner.calibration_value("1big1"), Ok(11));
+        assert_eq!(
+            scanner.calibration_value("big1"),
+            Err(Error::Overflow(Overflow("u32")))
+        );
+        assert_eq!(
+            scanner.calibration_value("1big"),
+            Err(Error::Overflow(Overflow("u32")))
+        );
+    }
+
+    #[test]
+    fn matches_prefix_search() {
+        // The first and last digit as found by trying every word at every offset.
+        let prefix_search = |line: &str| {
+            let find = |index: usize| {
+                (0..10).find(|&digit| {
+                    line[index..].starts_with(DIGITS[digit])
+                        || line[index..].starts_with(WORDS[digit])
+                })
+            };
+            let first = (0..line.len()).find_map(find)?;
+            let last = (0..line.len()).rev().find_map(find)?;
+
+            Some(first as u32 * 10 + last as u32)
+        };
+
+        for line in [
+            "two1nine",
+            "xtwone3four",
+            "zoneight234",
+            "7pqrstsixteen",
+            "abc",
+            "ei8ht",
+        ] {
+            assert_eq!(
+                Scanner::digits_and_words().calibration_value(line).ok(),
+                prefix_search(line)
+            );
+        }
+    }
+}
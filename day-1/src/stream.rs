@@ -0,0 +1,69 @@
+use std::io::BufRead;
+
+use anyhow::Result;
+
+use cli::{
+    answer::{Answer, is_wide},
+    config::Settings,
+    input::for_each_line,
+};
+
+use crate::part_scanner;
+
+/// Sums the calibration values a line at a time, in 128 bits if [is_wide].
+pub fn stream(reader: &mut dyn BufRead, part: u8, settings: &Settings) -> Result<String> {
+    if is_wide(settings)? {
+        Ok(stream_as::<u128>(reader, part, settings)?.to_string())
+    } else {
+        Ok(stream_as::<u32>(reader, part, settings)?.to_string())
+    }
+}
+
+/// Sums the calibration values in A a line at a time.
+fn stream_as<A: Answer + From<u32>>(
+    reader: &mut dyn BufRead,
+    part: u8,
+    settings: &Settings,
+) -> Result<A> {
+    let scanner = part_scanner(part, settings)?;
+
+    let mut sum = A::default();
+    for_each_line(reader, |line| {
+        sum = sum.try_add(A::from(scanner.calibration_value(line)?))?;
+
+        Ok(())
+    })?;
+
+    Ok(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use cli::answer::{Overflow, WIDE};
+
+    use super::*;
+    use crate::VOCABULARY;
+
+    #[test]
+    fn wide() -> Result<()> {
+        // Each line is 1 followed by 999999999, which only overflows once summed.
+        let input = "1x\n1x\n1x";
+        let mut settings = Settings::default();
+        settings.set(VOCABULARY, "x 999999999");
+        let narrow = stream(&mut input.as_bytes(), 2, &settings);
+        settings.set(WIDE, true);
+
+        assert_eq!(
+            narrow.map_err(|error| error.downcast::<Overflow>().ok()),
+            Err(Some(Overflow("u32")))
+        );
+        assert_eq!(
+            stream(&mut input.as_bytes(), 2, &settings)?,
+            (3 * 1_999_999_999u64).to_string()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind};
+use geometry::Point;
+
+/// Why a day 10 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    /// Every row of the grid must be as wide as the first.
+    #[error("Row is not as wide as the first: {0}")]
+    UnevenRow(String),
+
+    #[error("Cannot find start tile S")]
+    NoStart,
+
+    /// S must connect to exactly 2 pipes.
+    #[error("Cannot determine exits for S: {0:?}")]
+    AmbiguousStart(Point),
+
+    /// A tile on the loop that is not a pipe, or a pipe leading off the grid.
+    #[error("Invalid pipe {pipe}: {point:?}")]
+    InvalidPipe { pipe: char, point: Point },
+
+    /// The loop enters a pipe at a side that is not one of its exits.
+    #[error("Cannot enter pipe at {point:?} from {from:?}")]
+    BrokenLoop { point: Point, from: Point },
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::UnevenRow(_) => ErrorKind::Parse,
+            Error::NoStart
+            | Error::AmbiguousStart(_)
+            | Error::InvalidPipe { .. }
+            | Error::BrokenLoop { .. } => ErrorKind::Validation,
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use rand::{Rng, rngs::StdRng, seq::IndexedRandom};
+
+use cli::Generated;
+
+/// Generates a (size + 1) x (size + 1) grid, with a loop around a random region of size x size cells,
+/// and junk pipes everywhere else.
+///
+/// The region is grown one cell at a time, keeping it free of holes and of cells that only touch at
+/// corners, so that its boundary is a single loop through the corners of the cells. Each tile of the
+/// grid is one of those corners.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let cells = size.max(1);
+    let region = grow_region(rng, cells);
+    let in_region = |row: isize, col: isize| {
+        row >= 0
+            && col >= 0
+            && (row as usize) < cells
+            && (col as usize) < cells
+            && region[row as usize][col as usize]
+    };
+
+    let tiles = cells + 1;
+    let mut layout = vec![vec![b'.'; tiles]; tiles];
+    let mut loop_tiles = Vec::new();
+    let mut enclosed_tiles = 0;
+    for (row, tiles) in layout.iter_mut().enumerate() {
+        for (col, tile) in tiles.iter_mut().enumerate() {
+            let (r, c) = (row as isize, col as isize);
+            let up = in_region(r - 1, c - 1) != in_region(r - 1, c);
+            let down = in_region(r, c - 1) != in_region(r, c);
+            let left = in_region(r - 1, c - 1) != in_region(r, c - 1);
+            let right = in_region(r - 1, c) != in_region(r, c);
+
+            *tile = match (up, right, down, left) {
+                (true, false, true, false) => b'|',
+                (false, true, false, true) => b'-',
+                (true, true, false, false) => b'L',
+                (true, false, false, true) => b'J',
+                (false, false, true, true) => b'7',
+                (false, true, true, false) => b'F',
+                _ => {
+                    if in_region(r, c) {
+                        enclosed_tiles += 1;
+                    }
+                    *b"|-LJ7F....".choose(rng).unwrap()
+                }
+            };
+            if up || right || down || left {
+                loop_tiles.push((row, col));
+            }
+        }
+    }
+
+    // Clear the junk next to the start, so that only the loop connects to it.
+    let &(row, col) = loop_tiles.choose(rng).unwrap();
+    for (r, c) in [
+        (row.wrapping_sub(1), col),
+        (row + 1, col),
+        (row, col.wrapping_sub(1)),
+        (row, col + 1),
+    ] {
+        if r < tiles && c < tiles && !loop_tiles.contains(&(r, c)) {
+            layout[r][c] = b'.';
+        }
+    }
+    layout[row][col] = b'S';
+
+    let input = layout
+        .into_iter()
+        .map(|row| String::from_utf8(row).unwrap())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Generated {
+        input,
+        answers: [
+            Some((loop_tiles.len() / 2).to_string()),
+            Some(enclosed_tiles.to_string()),
+        ],
+    }
+}
+
+/// Grows a random region of cells, without holes or cells that only touch at corners.
+fn grow_region(rng: &mut StdRng, cells: usize) -> Vec<Vec<bool>> {
+    let mut region = vec![vec![false; cells]; cells];
+    let mut members = vec![(rng.random_range(0..cells), rng.random_range(0..cells))];
+    region[members[0].0][members[0].1] = true;
+
+    for _ in 0..3 * cells * cells {
+        let &(row, col) = members.choose(rng).unwrap();
+        let (row, col) = match rng.random_range(0..4) {
+            0 => (row.wrapping_sub(1), col),
+            1 => (row, col + 1),
+            2 => (row + 1, col),
+            _ => (row, col.wrapping_sub(1)),
+        };
+        if row >= cells || col >= cells || region[row][col] {
+            continue;
+        }
+
+        region[row][col] = true;
+        if is_simple(&region) {
+            members.push((row, col));
+        } else {
+            region[row][col] = false;
+        }
+    }
+
+    region
+}
+
+/// Checks that no cells only touch at corners, and that every cell outside the region can reach
+/// the outside of the grid.
+fn is_simple(region: &[Vec<bool>]) -> bool {
+    let cells = region.len() as isize;
+    let in_region = |row: isize, col: isize| {
+        row >= 0 && col >= 0 && row < cells && col < cells && region[row as usize][col as usize]
+    };
+
+    for row in -1..cells {
+        for col in -1..cells {
+            let (nw, ne) = (in_region(row, col), in_region(row, col + 1));
+            let (sw, se) = (in_region(row + 1, col), in_region(row + 1, col + 1));
+            if nw == se && ne == sw && nw != ne {
+                return false;
+            }
+        }
+    }
+
+    // Flood fill from outside, with a margin of 1 cell around the grid.
+    let side = cells + 2;
+    let mut seen = vec![false; (side * side) as usize];
+    let mut stack = vec![(-1, -1)];
+    seen[0] = true;
+    while let Some((row, col)) = stack.pop() {
+        for (r, c) in [
+            (row - 1, col),
+            (row + 1, col),
+            (row, col - 1),
+            (row, col + 1),
+        ] {
+            if r < -1 || c < -1 || r > cells || c > cells || in_region(r, c) {
+                continue;
+            }
+            let index = ((r + 1) * side + c + 1) as usize;
+            if !seen[index] {
+                seen[index] = true;
+                stack.push((r, c));
+            }
+        }
+    }
+
+    let outside = seen.iter().filter(|seen| **seen).count() as isize;
+    let inside = region.iter().flatten().filter(|cell| **cell).count() as isize;
+
+    outside + inside == side * side
+}
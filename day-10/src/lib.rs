@@ -0,0 +1,405 @@
+use std::{collections::HashSet, str::FromStr};
+
+use serde::Serialize;
+
+use cli::{Solver, to_json};
+use geometry::{Direction, Point, Size};
+
+pub use error::Error;
+
+mod error;
+pub mod generator;
+pub mod render;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 10,
+    version: env!("SOLVER_VERSION"),
+    // Both parts count tiles of the grid, which is held in memory, so they fit in usize.
+    part_1: |input, _| Ok(part_1(input)?.to_string()),
+    part_2: Some(|input, _| Ok(part_2(input)?.to_string())),
+    generator: generator::generate,
+    render: Some(render::render),
+    animate: None,
+    dump: Some(|input| to_json(&Grid::from_str(input)?)),
+    stream: None,
+    certify: None,
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<usize, Error> {
+    let grid = Grid::from_str(input)?;
+
+    Ok(grid.as_loop_points()?.len() / 2)
+}
+
+pub fn part_2(input: &str) -> Result<usize, Error> {
+    let grid = Grid::from_str(input)?;
+    let loop_points = grid.as_loop_points()?;
+
+    Ok(grid.find_enclosed_tiles(&loop_points)?.len())
+}
+
+#[derive(Serialize)]
+pub struct Grid {
+    #[serde(serialize_with = "cli::serialize_rows")]
+    pub layout: Vec<Vec<u8>>,
+    pub start: Point,
+    pub size: Size,
+}
+
+impl FromStr for Grid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let lines = s.lines().collect::<Vec<_>>();
+
+        let rows = lines.len();
+        let cols = lines.first().map_or(0, |line| line.len());
+        let size = Size::new(rows, cols);
+        if let Some(line) = lines.iter().find(|line| line.len() != cols) {
+            return Err(Error::UnevenRow(line.to_string()));
+        }
+
+        let row = lines
+            .iter()
+            .position(|row| row.contains('S'))
+            .ok_or(Error::NoStart)?;
+        let col = lines[row].find('S').ok_or(Error::NoStart)?;
+        let start = Point::new(row, col);
+
+        let layout = lines
+            .into_iter()
+            .map(|line| line.bytes().collect())
+            .collect();
+
+        Ok(Self {
+            layout,
+            start,
+            size,
+        })
+    }
+}
+
+/// The pipes, each with the 2 [Direction]s it connects.
+const PIPES: [(u8, [Direction; 2]); 6] = [
+    (b'|', [Direction::Up, Direction::Down]),
+    (b'-', [Direction::Right, Direction::Left]),
+    (b'L', [Direction::Up, Direction::Right]),
+    (b'J', [Direction::Up, Direction::Left]),
+    (b'7', [Direction::Down, Direction::Left]),
+    (b'F', [Direction::Right, Direction::Down]),
+];
+
+/// Gets the [Direction]s that the given pipe connects, or None if it is not a pipe.
+pub fn pipe_directions(pipe: u8) -> Option<[Direction; 2]> {
+    PIPES
+        .iter()
+        .find(|(other, _)| *other == pipe)
+        .map(|(_, directions)| *directions)
+}
+
+impl Grid {
+    /// Finds the tiles enclosed by the loop, scanning each row for crossings of the loop.
+    pub fn find_enclosed_tiles(&self, loop_points: &HashSet<Point>) -> Result<Vec<Point>, Error> {
+        let start_pipe = self.find_start_pipe()?;
+
+        let mut enclosed_tiles = Vec::new();
+        for row in 0..self.size.rows {
+            let mut is_within_loop = false;
+            let mut top_exit = false;
+            let mut bottom_exit = false;
+            for col in 0..self.size.cols {
+                let point = Point::new(row, col);
+                if loop_points.contains(&point) {
+                    // Check if we cross the loop circuit. Be careful of loop U-turns.
+                    let pipe = if point == self.start {
+                        start_pipe
+                    } else {
+                        self.layout[row][col]
+                    };
+                    match pipe {
+                        b'|' => {
+                            top_exit = true;
+                            bottom_exit = true;
+                        }
+                        b'L' | b'J' => {
+                            top_exit = !top_exit;
+                        }
+                        b'F' | b'7' => {
+                            bottom_exit = !bottom_exit;
+                        }
+                        _ => (),
+                    }
+
+                    if top_exit && bottom_exit {
+                        top_exit = false;
+                        bottom_exit = false;
+                        is_within_loop = !is_within_loop;
+                    }
+                } else if is_within_loop {
+                    enclosed_tiles.push(point);
+                }
+            }
+        }
+
+        Ok(enclosed_tiles)
+    }
+
+    pub fn as_loop_points(&self) -> Result<HashSet<Point>, Error> {
+        let start_exits = self.find_pipe_exits(self.start)?;
+
+        let mut loop_points = HashSet::new();
+        loop_points.insert(self.start);
+        loop_points.extend(start_exits);
+
+        let mut prev_point = self.start;
+        let mut point = start_exits[0];
+        while point != start_exits[1] {
+            (prev_point, point) = (point, self.traverse_pipe(point, prev_point)?);
+            loop_points.insert(point);
+        }
+
+        Ok(loop_points)
+    }
+
+    /// Finds the 2 tiles that the pipe at point leads to. S leads to the neighbours whose pipes
+    /// lead back to it, which must be exactly 2.
+    pub fn find_pipe_exits(&self, point: Point) -> Result<[Point; 2], Error> {
+        let pipe = self.layout[point.row][point.col];
+        if pipe == b'S' {
+            let exits = point
+                .neighbours(self.size)
+                .filter(|(direction, neighbour)| {
+                    pipe_directions(self.layout[neighbour.row][neighbour.col])
+                        .is_some_and(|directions| directions.contains(&direction.reverse()))
+                })
+                .map(|(_, neighbour)| neighbour)
+                .collect::<Vec<_>>();
+
+            return match exits[..] {
+                [first, second] => Ok([first, second]),
+                _ => Err(Error::AmbiguousStart(point)),
+            };
+        }
+
+        let invalid_pipe = || Error::InvalidPipe {
+            pipe: pipe as char,
+            point,
+        };
+        let [first, second] = pipe_directions(pipe).ok_or_else(invalid_pipe)?;
+
+        Ok([
+            point.step(first, self.size).ok_or_else(invalid_pipe)?,
+            point.step(second, self.size).ok_or_else(invalid_pipe)?,
+        ])
+    }
+
+    /// Finds the pipe hidden under S from the exits of S.
+    pub fn find_start_pipe(&self) -> Result<u8, Error> {
+        let exits = self.find_pipe_exits(self.start)?;
+        let directions = self
+            .start
+            .neighbours(self.size)
+            .filter(|(_, neighbour)| exits.contains(neighbour))
+            .map(|(direction, _)| direction)
+            .collect::<Vec<_>>();
+
+        PIPES
+            .iter()
+            .find(|(_, pipe_directions)| {
+                directions
+                    .iter()
+                    .all(|direction| pipe_directions.contains(direction))
+            })
+            .map(|(pipe, _)| *pipe)
+            .ok_or(Error::AmbiguousStart(self.start))
+    }
+
+    pub fn traverse_pipe(&self, point: Point, prev_point: Point) -> Result<Point, Error> {
+        let pipe_exits = self.find_pipe_exits(point)?;
+
+        match pipe_exits {
+            [entrance, exit] | [exit, entrance] if prev_point == entrance => Ok(exit),
+            _ => Err(Error::BrokenLoop {
+                point,
+                from: prev_point,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::trim_newlines;
+
+    use super::*;
+
+    #[test]
+    fn example_1a() -> Result<()> {
+        let example = r"
+.....
+.S-7.
+.|.|.
+.L-J.
+.....
+";
+
+        assert_eq!(part_1(trim_newlines(example))?, 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_1b() -> Result<()> {
+        let example = r"
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+";
+
+        assert_eq!(part_1(trim_newlines(example))?, 8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2a() -> Result<()> {
+        let example = r"
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+";
+
+        assert_eq!(part_2(trim_newlines(example))?, 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2b() -> Result<()> {
+        let example = r"
+..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........
+";
+
+        assert_eq!(part_2(trim_newlines(example))?, 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2c() -> Result<()> {
+        let example = r"
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+";
+
+        assert_eq!(part_2(trim_newlines(example))?, 8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2d() -> Result<()> {
+        let example = r"
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+";
+
+        assert_eq!(part_2(trim_newlines(example))?, 10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn start_on_vertical_pipe() -> Result<()> {
+        let example = r"
+......
+.F--7.
+.S..|.
+.L--J.
+......
+";
+
+        assert_eq!(part_2(trim_newlines(example))?, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn start_in_place_of_each_pipe() -> Result<()> {
+        // S takes the place of each pipe of a loop around 1 tile in turn, and is crossed as that
+        // pipe when counting the tiles within the loop.
+        let example = ".....\n.F-7.\n.|.|.\n.L-J.\n.....";
+        for (index, pipe) in example
+            .char_indices()
+            .filter(|(_, c)| !matches!(c, '.' | '\n'))
+        {
+            let mut input = example.to_string();
+            input.replace_range(index..index + 1, "S");
+
+            assert_eq!(Grid::from_str(&input)?.find_start_pipe()?, pipe as u8);
+            assert_eq!(part_2(&input)?, 1);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn dump() -> Result<()> {
+        let expected = r#"
+{
+  "layout": [
+    ".S7",
+    ".LJ"
+  ],
+  "size": {
+    "cols": 3,
+    "rows": 2
+  },
+  "start": {
+    "col": 1,
+    "row": 0
+  }
+}
+"#;
+
+        assert_eq!(SOLVER.dump(".S7\n.LJ")?, trim_newlines(expected));
+
+        Ok(())
+    }
+}
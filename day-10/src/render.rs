@@ -0,0 +1,61 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use cli::render::{HIGHLIGHT, Image, OPEN, SECONDARY, WALL};
+
+use crate::Grid;
+
+/// Draws the main loop, and the tiles it encloses. Pipes that are not part of the loop are drawn
+/// as walls. Both parts are drawn the same way.
+pub fn render(input: &str, _part: u8) -> Result<Image> {
+    let grid = Grid::from_str(input)?;
+    let loop_points = grid.as_loop_points()?;
+
+    let mut image = Image::new(grid.size.cols, grid.size.rows, OPEN);
+    for point in grid.size.points() {
+        if loop_points.contains(&point) {
+            image.set(point.row, point.col, HIGHLIGHT);
+        } else if grid.layout[point.row][point.col] != b'.' {
+            image.set(point.row, point.col, WALL);
+        }
+    }
+    for point in grid.find_enclosed_tiles(&loop_points)? {
+        image.set(point.row, point.col, SECONDARY);
+    }
+
+    Ok(image)
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::trim_newlines;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+";
+
+    #[test]
+    fn loop_and_enclosed_tiles() -> Result<()> {
+        let image = render(trim_newlines(EXAMPLE), 2)?;
+
+        assert_eq!(
+            image.count(HIGHLIGHT),
+            2 * crate::part_1(trim_newlines(EXAMPLE))?
+        );
+        assert_eq!(image.count(SECONDARY), 8);
+
+        Ok(())
+    }
+}
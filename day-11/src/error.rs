@@ -0,0 +1,27 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind, answer::Overflow};
+use geometry::Point;
+
+/// Why a day 11 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    /// The image must be as tall as it is wide.
+    #[error("Image is not square: {0}")]
+    NotSquare(String),
+
+    #[error("Invalid tile {tile}: {point:?}")]
+    InvalidTile { tile: char, point: Point },
+
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::NotSquare(_) | Error::InvalidTile { .. } => ErrorKind::Parse,
+            Error::Overflow(_) => ErrorKind::Unsolvable,
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use rand::{Rng, rngs::StdRng};
+
+use cli::Generated;
+
+/// Generates a size x size image, with whole rows and cols left empty for expansion.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let empty_rows = (0..size).map(|_| rng.random_bool(0.2)).collect::<Vec<_>>();
+    let empty_cols = (0..size).map(|_| rng.random_bool(0.2)).collect::<Vec<_>>();
+
+    let mut layout = vec![vec![b'.'; size]; size];
+    let mut galaxies = Vec::new();
+    for (row, tiles) in layout.iter_mut().enumerate() {
+        for (col, tile) in tiles.iter_mut().enumerate() {
+            if !empty_rows[row] && !empty_cols[col] && rng.random_bool(0.15) {
+                *tile = b'#';
+                galaxies.push((row, col));
+            }
+        }
+    }
+
+    // Count the empty rows and cols crossed by each pair of galaxies, one at a time.
+    let distances = |replace_size: usize| {
+        let mut sum = 0;
+        for (index, &(row, col)) in galaxies.iter().enumerate() {
+            for &(other_row, other_col) in &galaxies[index + 1..] {
+                let (row, other_row) = (row.min(other_row), row.max(other_row));
+                let (col, other_col) = (col.min(other_col), col.max(other_col));
+                let crossed = (row..other_row)
+                    .filter(|row| is_empty_row(&layout, *row))
+                    .count()
+                    + (col..other_col)
+                        .filter(|col| is_empty_col(&layout, *col))
+                        .count();
+
+                sum += other_row - row + other_col - col + crossed * (replace_size - 1);
+            }
+        }
+
+        sum
+    };
+
+    Generated {
+        input: layout
+            .iter()
+            .map(|row| String::from_utf8_lossy(row))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        answers: [
+            Some(distances(2).to_string()),
+            Some(distances(1000000).to_string()),
+        ],
+    }
+}
+
+fn is_empty_row(layout: &[Vec<u8>], row: usize) -> bool {
+    layout[row].iter().all(|tile| *tile == b'.')
+}
+
+fn is_empty_col(layout: &[Vec<u8>], col: usize) -> bool {
+    layout.iter().all(|tiles| tiles[col] == b'.')
+}
@@ -0,0 +1,294 @@
+use std::{collections::HashSet, str::FromStr};
+
+use itertools::Itertools;
+use serde::Serialize;
+
+use cli::{
+    Solver,
+    answer::{Answer, try_sum, widen, with_width},
+    to_json,
+};
+use geometry::Point;
+
+pub use error::Error;
+
+mod error;
+pub mod generator;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 11,
+    version: env!("SOLVER_VERSION"),
+    part_1: |input, settings| {
+        with_width(
+            settings,
+            || part_1(input),
+            || distances_after_expansion_as::<u128>(input, 2),
+        )
+    },
+    part_2: Some(|input, settings| {
+        with_width(
+            settings,
+            || part_2(input),
+            || distances_after_expansion_as::<u128>(input, 1000000),
+        )
+    }),
+    generator: generator::generate,
+    render: None,
+    animate: None,
+    dump: Some(|input| to_json(&Grid::from_str(input)?)),
+    stream: None,
+    certify: None,
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<usize, Error> {
+    distances_after_expansion(input, 2)
+}
+
+pub fn part_2(input: &str) -> Result<usize, Error> {
+    distances_after_expansion(input, 1000000)
+}
+
+pub fn distances_after_expansion(input: &str, replace_size: usize) -> Result<usize, Error> {
+    distances_after_expansion_as(input, replace_size)
+}
+
+/// Sums the distances between every pair of galaxies in A.
+pub fn distances_after_expansion_as<A: Answer + TryFrom<usize>>(
+    input: &str,
+    replace_size: usize,
+) -> Result<A, Error> {
+    let mut grid = Grid::from_str(input)?;
+    grid.expand(replace_size);
+
+    let distances = grid
+        .galaxies
+        .iter()
+        .tuple_combinations()
+        .map(|(galaxy, other)| {
+            // The distance itself may not fit in usize, let alone the sum.
+            widen::<A, _>(galaxy.row.abs_diff(other.row))?
+                .try_add(widen(galaxy.col.abs_diff(other.col))?)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(try_sum(distances)?)
+}
+
+#[derive(Serialize)]
+pub struct Grid {
+    #[serde(serialize_with = "cli::serialize_sorted")]
+    pub galaxies: HashSet<Point>,
+    pub size: usize,
+}
+
+impl FromStr for Grid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let lines = s.lines().collect::<Vec<_>>();
+
+        let size = lines.len();
+        if let Some(line) = lines.iter().find(|line| line.len() != size) {
+            return Err(Error::NotSquare(line.to_string()));
+        }
+
+        let galaxies = lines
+            .into_iter()
+            .enumerate()
+            .flat_map(|(row, line)| {
+                line.bytes()
+                    .enumerate()
+                    .filter_map(move |(col, byte)| match byte {
+                        b'#' => Some(Ok(Point::new(row, col))),
+                        b'.' => None,
+                        _ => Some(Err(Error::InvalidTile {
+                            tile: byte as char,
+                            point: Point::new(row, col),
+                        })),
+                    })
+            })
+            .collect::<Result<HashSet<_>, _>>()?;
+
+        Ok(Self { galaxies, size })
+    }
+}
+
+impl Grid {
+    /// Expands this [Grid] by replacing every empty row by replace_size rows, and every empty col
+    /// by replace_size cols.
+    ///
+    /// replace_size must be >= 1. replace_size of 1 implies no expansion, as each empty row or
+    /// col is simply replaced by itself.
+    pub fn expand(&mut self, replace_size: usize) {
+        if replace_size <= 1 {
+            return;
+        }
+
+        // Mark pos of new rows and cols.
+        let mut empty_rows = vec![0; self.size];
+        let mut empty_cols = vec![0; self.size];
+        empty_rows.iter_mut().enumerate().for_each(|(index, row)| {
+            if !self.galaxies.iter().any(|galaxy| galaxy.row == index) {
+                *row = replace_size - 1;
+            }
+        });
+        empty_cols.iter_mut().enumerate().for_each(|(index, col)| {
+            if !self.galaxies.iter().any(|galaxy| galaxy.col == index) {
+                *col = replace_size - 1;
+            }
+        });
+
+        // Calculate accumulative count of new rows and cols.
+        empty_rows = empty_rows
+            .into_iter()
+            .scan(0, |state, row| {
+                *state += row;
+                Some(*state)
+            })
+            .collect();
+        empty_cols = empty_cols
+            .into_iter()
+            .scan(0, |state, col| {
+                *state += col;
+                Some(*state)
+            })
+            .collect();
+
+        self.galaxies = self
+            .galaxies
+            .iter()
+            .map(|galaxy| {
+                Point::new(
+                    galaxy.row + empty_rows[galaxy.row],
+                    galaxy.col + empty_cols[galaxy.col],
+                )
+            })
+            .collect();
+        self.size += empty_rows.last().unwrap_or(&0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::trim_newlines;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+";
+
+    #[test]
+    fn example_1() -> Result<()> {
+        assert_eq!(part_1(trim_newlines(EXAMPLE))?, 374);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        assert_eq!(
+            distances_after_expansion(trim_newlines(EXAMPLE), 100)?,
+            8410
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn overflow() -> Result<()> {
+        let input = "#...\n....\n....\n...#";
+        let replace_size = 1 << 62;
+
+        assert_eq!(
+            distances_after_expansion(input, replace_size),
+            Err(Error::Overflow(cli::answer::Overflow("usize")))
+        );
+        assert_eq!(
+            distances_after_expansion_as::<u128>(input, replace_size)?,
+            (1 << 64) + 2
+        );
+
+        Ok(())
+    }
+
+    /// Expands the image by copying each empty row and col, then measures the distances on the
+    /// expanded image.
+    fn distances_after_expansion_naive(image: &[Vec<bool>], replace_size: usize) -> usize {
+        let mut expanded = Vec::new();
+        for row in image {
+            let copies = if row.contains(&true) { 1 } else { replace_size };
+            expanded.extend(std::iter::repeat_n(row.clone(), copies));
+        }
+
+        let mut transposed = Vec::new();
+        for col in 0..image.len() {
+            let cells = expanded.iter().map(|row| row[col]).collect::<Vec<_>>();
+            let copies = if cells.contains(&true) {
+                1
+            } else {
+                replace_size
+            };
+            transposed.extend(std::iter::repeat_n(cells, copies));
+        }
+
+        let galaxies = transposed
+            .iter()
+            .enumerate()
+            .flat_map(|(col, rows)| {
+                rows.iter()
+                    .enumerate()
+                    .filter(|(_, galaxy)| **galaxy)
+                    .map(move |(row, _)| Point::new(row, col))
+            })
+            .collect::<Vec<_>>();
+
+        galaxies
+            .iter()
+            .tuple_combinations()
+            .map(|(galaxy, other)| galaxy.manhattan_distance(*other))
+            .sum()
+    }
+
+    fn image() -> impl Strategy<Value = Vec<Vec<bool>>> {
+        (1..=8usize).prop_flat_map(|size| {
+            prop::collection::vec(prop::collection::vec(prop::bool::weighted(0.2), size), size)
+        })
+    }
+
+    fn as_input(image: &[Vec<bool>]) -> String {
+        image
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|galaxy| if *galaxy { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    proptest! {
+        #[test]
+        fn distances_after_expansion_matches_naive(image in image(), replace_size in 1..=5usize) {
+            prop_assert_eq!(
+                distances_after_expansion(&as_input(&image), replace_size).unwrap(),
+                distances_after_expansion_naive(&image, replace_size)
+            );
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind, NumberError, answer::Overflow};
+
+/// Why a day 12 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    #[error("Cannot split input into pattern and record: {0}")]
+    MissingRecord(String),
+
+    /// A pattern may only have ?, # and . springs.
+    #[error("Invalid char in pattern: {0}")]
+    InvalidPattern(String),
+
+    #[error(transparent)]
+    Number(#[from] NumberError),
+
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::MissingRecord(_) | Error::InvalidPattern(_) | Error::Number(_) => {
+                ErrorKind::Parse
+            }
+            Error::Overflow(_) => ErrorKind::Unsolvable,
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use rand::{Rng, rngs::StdRng};
+
+use cli::Generated;
+
+/// The most unknown springs in a row, so that every arrangement can be tried.
+const MAX_UNKNOWN: usize = 12;
+
+/// Generates size rows of up to 20 springs, hiding some springs of a random arrangement behind ?.
+///
+/// Part 1 is answered by trying every arrangement. Part 2 is not answered, as the unfolded rows are
+/// too long to brute force.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let mut lines = Vec::new();
+    let mut arrangements = 0;
+    for _ in 0..size {
+        let springs = loop {
+            let springs = (0..rng.random_range(1..=20))
+                .map(|_| if rng.random_bool(0.5) { b'#' } else { b'.' })
+                .collect::<Vec<_>>();
+            if springs.contains(&b'#') {
+                break springs;
+            }
+        };
+        let record = as_record(&springs);
+
+        let mut pattern = springs;
+        let mut unknown = 0;
+        for spring in pattern.iter_mut() {
+            if unknown < MAX_UNKNOWN && rng.random_bool(0.5) {
+                *spring = b'?';
+                unknown += 1;
+            }
+        }
+        arrangements += count_arrangements_brute_force(&pattern, &record);
+
+        lines.push(format!(
+            "{} {}",
+            String::from_utf8_lossy(&pattern),
+            record
+                .iter()
+                .map(|len| len.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        ));
+    }
+
+    Generated {
+        input: lines.join("\n"),
+        answers: [Some(arrangements.to_string()), None],
+    }
+}
+
+/// Gets the lens of the contiguous groups of damaged springs.
+fn as_record(springs: &[u8]) -> Vec<usize> {
+    springs
+        .split(|spring| *spring == b'.')
+        .filter(|group| !group.is_empty())
+        .map(|group| group.len())
+        .collect()
+}
+
+/// Tries every assignment of the unknown springs.
+pub(crate) fn count_arrangements_brute_force(pattern: &[u8], record: &[usize]) -> u64 {
+    let unknown = pattern
+        .iter()
+        .enumerate()
+        .filter(|(_, spring)| **spring == b'?')
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+
+    (0..1u32 << unknown.len())
+        .filter(|mask| {
+            let mut springs = pattern.to_vec();
+            for (bit, &index) in unknown.iter().enumerate() {
+                springs[index] = if mask & (1 << bit) != 0 { b'#' } else { b'.' };
+            }
+
+            as_record(&springs) == record
+        })
+        .count() as u64
+}
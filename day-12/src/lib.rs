@@ -0,0 +1,246 @@
+use std::{collections::HashMap, str::FromStr};
+
+use serde::Serialize;
+
+use cli::{
+    Solver,
+    answer::{Answer, Overflow, try_sum, with_width},
+    dump_lines, parallel, parse_number,
+};
+
+pub use error::Error;
+
+mod error;
+pub mod generator;
+pub mod stream;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 12,
+    version: env!("SOLVER_VERSION"),
+    part_1: |input, settings| with_width(settings, || part_1(input), || part_1_as::<u128>(input)),
+    part_2: Some(|input, settings| {
+        with_width(settings, || part_2(input), || part_2_as::<u128>(input))
+    }),
+    generator: generator::generate,
+    render: None,
+    animate: None,
+    dump: Some(|input| dump_lines(input, Row::from_str)),
+    stream: Some(stream::stream),
+    certify: None,
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<u64, Error> {
+    part_1_as(input)
+}
+
+pub fn part_2(input: &str) -> Result<u64, Error> {
+    part_2_as(input)
+}
+
+/// Sums the arrangements of every row in A.
+pub fn part_1_as<A: Answer>(input: &str) -> Result<A, Error> {
+    let rows = input
+        .lines()
+        .map(Row::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(count_all_arrangements(&rows)?)
+}
+
+/// Sums the arrangements of every unfolded row in A.
+pub fn part_2_as<A: Answer>(input: &str) -> Result<A, Error> {
+    let mut rows = input
+        .lines()
+        .map(Row::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for row in rows.iter_mut() {
+        row.unfold();
+    }
+
+    Ok(count_all_arrangements(&rows)?)
+}
+
+/// Sums the arrangements of every [Row], spread over the threads with a cache per [Row].
+pub fn count_all_arrangements<A: Answer>(rows: &[Row]) -> Result<A, Overflow> {
+    try_sum(
+        parallel::map(rows, |row| {
+            count_arrangements(&row.pattern, &row.record, &mut HashMap::new())
+        })
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?,
+    )
+}
+
+#[derive(Serialize)]
+pub struct Row {
+    pub pattern: String,
+    pub record: Vec<usize>,
+}
+
+impl FromStr for Row {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let Some((pattern, record)) = s.split_once(" ") else {
+            return Err(Error::MissingRecord(s.to_string()));
+        };
+        if !pattern.chars().all(|c| "?#.".contains(c)) {
+            return Err(Error::InvalidPattern(pattern.to_string()));
+        }
+
+        let pattern = String::from(pattern);
+        let record = record
+            .split_terminator(",")
+            .map(parse_number::<usize>)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { pattern, record })
+    }
+}
+
+impl Row {
+    /// Replaces the pattern by 5 copies joined by ?, and the record by 5 copies.
+    pub fn unfold(&mut self) {
+        self.pattern.push('?');
+        self.pattern = self.pattern.repeat(5);
+        self.pattern.pop(); // trailing ?
+
+        self.record = self.record.repeat(5);
+    }
+}
+
+pub fn count_arrangements<'a, A: Answer>(
+    pattern: &'a str,
+    record: &'a [usize],
+    cache: &mut HashMap<(&'a str, &'a [usize]), A>,
+) -> Result<A, Overflow> {
+    if let Some(arrangements) = cache.get(&(pattern, record)) {
+        return Ok(*arrangements);
+    }
+
+    if pattern.is_empty() {
+        return Ok(*cache
+            .entry((pattern, record))
+            .or_insert(A::from(record.is_empty() as u8)));
+    }
+
+    if record.is_empty() {
+        return Ok(*cache
+            .entry((pattern, record))
+            .or_insert(A::from(all_operational_springs(pattern) as u8)));
+    }
+
+    // pattern must have sufficient space to cover all damaged springs with at least 1 separator in
+    // between each cluster.
+    if pattern.len() < record.iter().sum::<usize>() + record.len() - 1 {
+        return Ok(*cache.entry((pattern, record)).or_default());
+    }
+
+    let mut arrangements = A::default();
+    if record.len() > 1 && starts_with_damaged_springs_plus_separator(pattern, record[0]) {
+        arrangements = arrangements.try_add(count_arrangements(
+            &pattern[record[0] + 1..],
+            &record[1..],
+            cache,
+        )?)?;
+    }
+    if record.len() == 1 && starts_with_damaged_springs(pattern, record[0]) {
+        arrangements = arrangements.try_add(count_arrangements(
+            &pattern[record[0]..],
+            &record[1..],
+            cache,
+        )?)?;
+    }
+    if starts_with_separator(pattern) {
+        arrangements = arrangements.try_add(count_arrangements(&pattern[1..], record, cache)?)?;
+    }
+
+    Ok(*cache.entry((pattern, record)).or_insert(arrangements))
+}
+
+fn all_operational_springs(pattern: &str) -> bool {
+    pattern.chars().all(|c| "?.".contains(c))
+}
+
+fn all_damaged_springs(pattern: &str) -> bool {
+    pattern.chars().all(|c| "?#".contains(c))
+}
+
+fn starts_with_damaged_springs_plus_separator(pattern: &str, damaged_springs: usize) -> bool {
+    starts_with_damaged_springs(pattern, damaged_springs)
+        && starts_with_separator(&pattern[damaged_springs..])
+}
+
+fn starts_with_damaged_springs(pattern: &str, damaged_springs: usize) -> bool {
+    all_damaged_springs(&pattern[..damaged_springs])
+}
+
+fn starts_with_separator(pattern: &str) -> bool {
+    all_operational_springs(&pattern[0..1])
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::trim_newlines;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::generator::count_arrangements_brute_force;
+
+    const EXAMPLE: &str = r"
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+
+    #[test]
+    fn example_1() -> Result<()> {
+        assert_eq!(part_1(trim_newlines(EXAMPLE))?, 21);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        assert_eq!(part_2(trim_newlines(EXAMPLE))?, 525152);
+
+        Ok(())
+    }
+
+    #[test]
+    fn overflow() -> Result<()> {
+        let wide = "wide = true".parse()?;
+        // Any 35 of the 70 places left for the damaged springs once each has a separator.
+        let input = format!("{} {}", "?".repeat(104), ["1"; 35].join(","));
+
+        assert_eq!(part_1(&input), Err(Error::Overflow(Overflow("u64"))));
+        assert_eq!(part_1_as::<u128>(&input)?, 112186277816662845432);
+        assert_eq!(
+            stream::stream(&mut input.as_bytes(), 1, &wide)?,
+            part_1_as::<u128>(&input)?.to_string()
+        );
+
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn count_arrangements_matches_brute_force(
+            pattern in "[.#?]{1,12}",
+            record in prop::collection::vec(1..=4usize, 0..=4),
+        ) {
+            prop_assert_eq!(
+                count_arrangements(&pattern, &record, &mut HashMap::new()),
+                Ok(count_arrangements_brute_force(pattern.as_bytes(), &record))
+            );
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use std::{collections::HashMap, io::BufRead, str::FromStr};
+
+use anyhow::{Result, anyhow};
+
+use cli::{
+    answer::{Answer, is_wide},
+    config::Settings,
+    input::for_each_line,
+};
+
+use crate::{Row, count_arrangements};
+
+/// Sums the arrangements a row at a time, with a cache for each row, in 128 bits if [is_wide].
+pub fn stream(reader: &mut dyn BufRead, part: u8, settings: &Settings) -> Result<String> {
+    if is_wide(settings)? {
+        Ok(stream_as::<u128>(reader, part)?.to_string())
+    } else {
+        Ok(stream_as::<u64>(reader, part)?.to_string())
+    }
+}
+
+/// Sums the arrangements in A a row at a time.
+fn stream_as<A: Answer>(reader: &mut dyn BufRead, part: u8) -> Result<A> {
+    if !(1..=2).contains(&part) {
+        return Err(anyhow!("Invalid part number: {}", part));
+    }
+
+    let mut sum = A::default();
+    for_each_line(reader, |line| {
+        let mut row = Row::from_str(line)?;
+        if part == 2 {
+            row.unfold();
+        }
+        sum = sum.try_add(count_arrangements(
+            &row.pattern,
+            &row.record,
+            &mut HashMap::new(),
+        )?)?;
+
+        Ok(())
+    })?;
+
+    Ok(sum)
+}
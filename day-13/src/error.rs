@@ -0,0 +1,26 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind, answer::Overflow};
+
+/// Why a day 13 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    /// Every row of a pattern must be as wide as the first.
+    #[error("Row is not as wide as the first: {0}")]
+    UnevenRow(String),
+
+    #[error("Cannot find mirror:\n{0}")]
+    NoMirror(String),
+
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::UnevenRow(_) => ErrorKind::Parse,
+            Error::NoMirror(_) | Error::Overflow(_) => ErrorKind::Unsolvable,
+        }
+    }
+}
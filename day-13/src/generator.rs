@@ -0,0 +1,171 @@
+use std::collections::HashSet;
+
+use rand::{Rng, rngs::StdRng};
+
+use cli::Generated;
+
+/// (horizontal, split after row or col)
+type Line = (bool, usize);
+
+/// Generates size patterns, each with one reflection, and one other reflection after cleaning a
+/// smudge.
+///
+/// Both reflections are planted across rows, by making rows equal, apart from one cell for the
+/// second reflection. Half the patterns are then transposed. Patterns where either reflection turns
+/// out not to be unique are thrown away.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let mut patterns = Vec::new();
+    let (mut sum_1, mut sum_2) = (0, 0);
+    while patterns.len() < size {
+        let Some((pattern, line_1, line_2)) = plant_reflections(rng) else {
+            continue;
+        };
+
+        let summarise = |(horizontal, after): Line| {
+            if horizontal {
+                100 * (after + 1)
+            } else {
+                after + 1
+            }
+        };
+        sum_1 += summarise(line_1);
+        sum_2 += summarise(line_2);
+        patterns.push(
+            pattern
+                .iter()
+                .map(|row| String::from_utf8_lossy(row))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+    }
+
+    Generated {
+        input: patterns.join("\n\n"),
+        answers: [Some(sum_1.to_string()), Some(sum_2.to_string())],
+    }
+}
+
+/// Tries to plant reflections into a random pattern, returning the pattern and both reflections if
+/// they are unique.
+fn plant_reflections(rng: &mut StdRng) -> Option<(Vec<Vec<u8>>, Line, Line)> {
+    let rows = rng.random_range(3..=17);
+    let cols = rng.random_range(3..=17);
+    let after_1 = rng.random_range(0..rows - 1);
+    let after_2 = rng.random_range(0..rows - 1);
+    if after_1 == after_2 {
+        return None;
+    }
+
+    // Group rows that must be equal, leaving out one pair of the second reflection for the smudge.
+    let mut group = (0..rows).collect::<Vec<_>>();
+    let pairs = |after: usize| {
+        (0..=after)
+            .map(move |top| (top, 2 * after + 1 - top))
+            .filter(move |(_, bottom)| *bottom < rows)
+    };
+    for (top, bottom) in pairs(after_1) {
+        let (top, bottom) = (find(&mut group, top), find(&mut group, bottom));
+        group[top] = bottom;
+    }
+    let smudge_pairs = pairs(after_2).collect::<Vec<_>>();
+    let smudge_pair = smudge_pairs[rng.random_range(0..smudge_pairs.len())];
+    for &(top, bottom) in &smudge_pairs {
+        if (top, bottom) != smudge_pair {
+            let (top, bottom) = (find(&mut group, top), find(&mut group, bottom));
+            group[top] = bottom;
+        }
+    }
+    let (smudge_top, smudge_bottom) = (
+        find(&mut group, smudge_pair.0),
+        find(&mut group, smudge_pair.1),
+    );
+    if smudge_top == smudge_bottom {
+        return None;
+    }
+
+    let mut group_rows = (0..rows)
+        .map(|_| {
+            (0..cols)
+                .map(|_| if rng.random_bool(0.5) { b'#' } else { b'.' })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    group_rows[smudge_bottom] = group_rows[smudge_top].clone();
+    let col = rng.random_range(0..cols);
+    group_rows[smudge_bottom][col] = if group_rows[smudge_top][col] == b'#' {
+        b'.'
+    } else {
+        b'#'
+    };
+
+    let mut pattern = (0..rows)
+        .map(|row| group_rows[find(&mut group, row)].clone())
+        .collect::<Vec<_>>();
+    let mut line_1 = (true, after_1);
+    let mut line_2 = (true, after_2);
+    if rng.random_bool(0.5) {
+        pattern = (0..cols)
+            .map(|col| pattern.iter().map(|row| row[col]).collect())
+            .collect();
+        line_1.0 = false;
+        line_2.0 = false;
+    }
+
+    if reflections(&pattern) != HashSet::from([line_1]) {
+        return None;
+    }
+    let mut smudged_lines = HashSet::new();
+    for row in 0..pattern.len() {
+        for col in 0..pattern[0].len() {
+            let mut smudged = pattern.clone();
+            smudged[row][col] = if smudged[row][col] == b'#' {
+                b'.'
+            } else {
+                b'#'
+            };
+            smudged_lines.extend(reflections(&smudged));
+        }
+    }
+    smudged_lines.remove(&line_1);
+    if smudged_lines != HashSet::from([line_2]) {
+        return None;
+    }
+
+    Some((pattern, line_1, line_2))
+}
+
+/// Finds the group of row, with path compression.
+fn find(group: &mut [usize], row: usize) -> usize {
+    if group[row] != row {
+        group[row] = find(group, group[row]);
+    }
+    group[row]
+}
+
+/// Finds every reflection by comparing each pair of rows or cols either side of each line.
+fn reflections(pattern: &[Vec<u8>]) -> HashSet<Line> {
+    let (rows, cols) = (pattern.len(), pattern[0].len());
+    let mut lines = HashSet::new();
+    for after in 0..rows - 1 {
+        if (0..=after)
+            .filter(|top| 2 * after + 1 - top < rows)
+            .all(|top| pattern[top] == pattern[2 * after + 1 - top])
+        {
+            lines.insert((true, after));
+        }
+    }
+    for after in 0..cols - 1 {
+        if (0..=after)
+            .filter(|left| 2 * after + 1 - left < cols)
+            .all(|left| {
+                pattern
+                    .iter()
+                    .all(|row| row[left] == row[2 * after + 1 - left])
+            })
+        {
+            lines.insert((false, after));
+        }
+    }
+
+    lines
+}
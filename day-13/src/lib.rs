@@ -0,0 +1,248 @@
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use cli::{
+    Solver,
+    answer::{Answer, try_sum, widen, with_width},
+    to_json,
+};
+use geometry::Size;
+
+pub use error::Error;
+
+mod error;
+pub mod generator;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 13,
+    version: env!("SOLVER_VERSION"),
+    part_1: |input, settings| with_width(settings, || part_1(input), || part_1_as::<u128>(input)),
+    part_2: Some(|input, settings| {
+        with_width(settings, || part_2(input), || part_2_as::<u128>(input))
+    }),
+    generator: generator::generate,
+    render: None,
+    animate: None,
+    dump: Some(|input| {
+        to_json(
+            &input
+                .split_terminator("\n\n")
+                .map(Grid::from_str)
+                .collect::<Result<Vec<_>, _>>()?,
+        )
+    }),
+    stream: None,
+    certify: None,
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<usize, Error> {
+    part_1_as(input)
+}
+
+pub fn part_2(input: &str) -> Result<usize, Error> {
+    part_2_as(input)
+}
+
+/// Sums the summaries of the reflections in A.
+pub fn part_1_as<A: Answer + TryFrom<usize>>(input: &str) -> Result<A, Error> {
+    summarise(input, |grid| grid.find_reflection(None))
+}
+
+/// Sums the summaries of the reflections after cleaning the smudges in A.
+pub fn part_2_as<A: Answer + TryFrom<usize>>(input: &str) -> Result<A, Error> {
+    summarise(input, clean_smudge_and_find_new_reflection)
+}
+
+/// Sums 100 times the rows above each horizontal mirror and the cols left of each vertical mirror.
+fn summarise<A: Answer + TryFrom<usize>>(
+    input: &str,
+    find_reflection: impl Fn(&Grid) -> Option<Mirror>,
+) -> Result<A, Error> {
+    let summaries = input
+        .split_terminator("\n\n")
+        .map(Grid::from_str)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .map(|grid| match find_reflection(&grid) {
+            Some(Mirror::Horizontal(row)) => Ok(widen::<A, _>(row + 1)?.try_mul(A::from(100))?),
+            Some(Mirror::Vertical(col)) => Ok(widen(col + 1)?),
+            _ => Err(Error::NoMirror(grid.as_text())),
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    Ok(try_sum(summaries)?)
+}
+
+#[derive(Clone, Serialize)]
+pub struct Grid {
+    #[serde(serialize_with = "cli::serialize_rows")]
+    pub pattern: Vec<Vec<u8>>,
+    pub size: Size,
+}
+
+impl FromStr for Grid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let lines = s.lines().collect::<Vec<_>>();
+
+        let rows = lines.len();
+        let cols = lines.first().map_or(0, |line| line.len());
+        let size = Size::new(rows, cols);
+        if let Some(line) = lines.iter().find(|line| line.len() != cols) {
+            return Err(Error::UnevenRow(line.to_string()));
+        }
+
+        let pattern = lines
+            .into_iter()
+            .map(|line| line.bytes().collect())
+            .collect();
+
+        Ok(Self { pattern, size })
+    }
+}
+
+impl Grid {
+    pub fn as_text(&self) -> String {
+        self.pattern
+            .iter()
+            .map(|row| String::from_utf8_lossy(row))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub fn find_reflection(&self, ignore_mirror: Option<Mirror>) -> Option<Mirror> {
+        self.find_horizontal_reflection(ignore_mirror)
+            .or_else(|| self.find_vertical_reflection(ignore_mirror))
+    }
+
+    pub fn find_horizontal_reflection(&self, ignore_mirror: Option<Mirror>) -> Option<Mirror> {
+        for split_after_row in 0..self.pattern.len().saturating_sub(1) {
+            if ignore_mirror == Some(Mirror::Horizontal(split_after_row)) {
+                continue;
+            }
+
+            if Self::split_and_compare(&self.pattern, split_after_row) {
+                return Some(Mirror::Horizontal(split_after_row));
+            }
+        }
+
+        None
+    }
+
+    pub fn find_vertical_reflection(&self, ignore_mirror: Option<Mirror>) -> Option<Mirror> {
+        let transposed = self.transpose_pattern();
+
+        for split_after_col in 0..transposed.len().saturating_sub(1) {
+            if ignore_mirror == Some(Mirror::Vertical(split_after_col)) {
+                continue;
+            }
+
+            if Self::split_and_compare(&transposed, split_after_col) {
+                return Some(Mirror::Vertical(split_after_col));
+            }
+        }
+
+        None
+    }
+
+    pub fn transpose_pattern(&self) -> Vec<Vec<u8>> {
+        let mut transposed = vec![vec![0; self.size.rows]; self.size.cols];
+
+        for (row, bytes) in self.pattern.iter().enumerate() {
+            for (col, byte) in bytes.iter().enumerate() {
+                transposed[col][row] = *byte;
+            }
+        }
+
+        transposed
+    }
+
+    pub fn split_and_compare(pattern: &[Vec<u8>], split_after_row: usize) -> bool {
+        let (top_half, bottom_half) = if split_after_row < pattern.len() / 2 {
+            (
+                &pattern[..=split_after_row],
+                &pattern[split_after_row + 1..=split_after_row * 2 + 1],
+            )
+        } else {
+            (
+                &pattern[2 * split_after_row + 2 - pattern.len()..=split_after_row],
+                &pattern[split_after_row + 1..],
+            )
+        };
+
+        top_half
+            .iter()
+            .enumerate()
+            .all(|(index, row)| *row == bottom_half[bottom_half.len() - 1 - index])
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Mirror {
+    /// Horizontal mirror after row index.
+    Horizontal(usize),
+
+    /// Vertical mirror after col index.
+    Vertical(usize),
+}
+
+pub fn clean_smudge_and_find_new_reflection(grid: &Grid) -> Option<Mirror> {
+    let orig_mirror = grid.find_reflection(None)?;
+
+    for point in grid.size.points() {
+        let mut grid = grid.clone();
+        let tile = &mut grid.pattern[point.row][point.col];
+        *tile = if *tile == b'#' { b'.' } else { b'#' };
+
+        if let Some(new_mirror) = grid.find_reflection(Some(orig_mirror)) {
+            return Some(new_mirror);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::trim_newlines;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+";
+
+    #[test]
+    fn example_1() -> Result<()> {
+        assert_eq!(part_1(trim_newlines(EXAMPLE))?, 405);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        assert_eq!(part_2(trim_newlines(EXAMPLE))?, 400);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use std::{collections::HashSet, str::FromStr};
+
+use anyhow::{Result, anyhow};
+
+use cli::animate::{Frame, Frames};
+
+use crate::Grid;
+
+/// Tilts the platform one way.
+type Tilt = fn(&mut Grid);
+
+/// The tilts of a spin, in order.
+const TILTS: [(&str, Tilt); 4] = [
+    ("north", Grid::tilt_north),
+    ("west", Grid::tilt_west),
+    ("south", Grid::tilt_south),
+    ("east", Grid::tilt_east),
+];
+
+/// Plays back each tilt of the platform. Part 2 spins the platform until it returns to a layout it
+/// has been in before, since the spins repeat from then on.
+pub fn animate(input: &str, part: u8) -> Result<Frames<'_>> {
+    let mut grid = Grid::from_str(input)?;
+    let start = frame("Start", &grid);
+
+    match part {
+        1 => {
+            grid.tilt_north();
+
+            Ok(Box::new([start, frame("Tilted north", &grid)].into_iter()))
+        }
+        2 => {
+            let mut seen = HashSet::from([grid.layout.clone()]);
+            let mut tilts = (1..).flat_map(|spin| TILTS.map(|tilt| (spin, tilt)));
+            let mut repeated = false;
+            let spins = std::iter::from_fn(move || {
+                if repeated {
+                    return None;
+                }
+
+                let (spin, (direction, tilt)) = tilts.next()?;
+                tilt(&mut grid);
+                if direction == "east" {
+                    repeated = !seen.insert(grid.layout.clone());
+                }
+
+                Some(frame(&format!("Spin {spin}, tilted {direction}"), &grid))
+            });
+
+            Ok(Box::new(std::iter::once(start).chain(spins)))
+        }
+        _ => Err(anyhow!("Invalid part number: {}", part)),
+    }
+}
+
+fn frame(caption: &str, grid: &Grid) -> Frame {
+    let text = grid
+        .layout
+        .iter()
+        .map(|row| String::from_utf8_lossy(row))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let load = grid
+        .load::<u128>()
+        .map(|load| format!(" (load {load})"))
+        .unwrap_or_default();
+
+    Frame::new(format!("{caption}{load}"), text)
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::trim_newlines;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+";
+
+    #[test]
+    fn tilts() -> Result<()> {
+        let frames = animate(trim_newlines(EXAMPLE), 1)?.collect::<Vec<_>>();
+
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[1].caption, "Tilted north (load 136)");
+
+        Ok(())
+    }
+
+    #[test]
+    fn spins_until_repeated() -> Result<()> {
+        let frames = animate(trim_newlines(EXAMPLE), 2)?.collect::<Vec<_>>();
+
+        // The example spins into a cycle of 7 after 3 spins.
+        assert_eq!(frames.len(), 1 + 10 * 4);
+        assert_eq!(frames[4].caption, "Spin 1, tilted east (load 87)");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use rand::{Rng, rngs::StdRng};
+
+use cli::Generated;
+
+/// Generates a size x size platform of round and cube-shaped rocks.
+///
+/// Both parts are answered by rolling rocks one row at a time, spinning by rotating the platform,
+/// and keeping every platform seen to find when the spins repeat.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let layout = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| match rng.random_range(0..10) {
+                    0..2 => b'O',
+                    2 => b'#',
+                    _ => b'.',
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut tilted = layout.clone();
+    tilt_north(&mut tilted);
+
+    let mut history = vec![layout.clone()];
+    let mut spun = layout.clone();
+    let spins = loop {
+        for _ in 0..4 {
+            tilt_north(&mut spun);
+            spun = rotate_clockwise(&spun);
+        }
+
+        if let Some(start) = history.iter().position(|seen| *seen == spun) {
+            let cycle_len = history.len() - start;
+            break &history[start + (1000000000 - start) % cycle_len];
+        }
+        history.push(spun.clone());
+    };
+
+    Generated {
+        input: layout
+            .iter()
+            .map(|row| String::from_utf8_lossy(row))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        answers: [
+            Some(load(&tilted).to_string()),
+            Some(load(spins).to_string()),
+        ],
+    }
+}
+
+/// Rolls every round rock up one row at a time, until none can move.
+fn tilt_north(layout: &mut [Vec<u8>]) {
+    let mut moved = true;
+    while moved {
+        moved = false;
+        for row in 1..layout.len() {
+            for col in 0..layout[row].len() {
+                if layout[row][col] == b'O' && layout[row - 1][col] == b'.' {
+                    layout[row - 1][col] = b'O';
+                    layout[row][col] = b'.';
+                    moved = true;
+                }
+            }
+        }
+    }
+}
+
+fn rotate_clockwise(layout: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    let size = layout.len();
+
+    (0..size)
+        .map(|row| (0..size).map(|col| layout[size - 1 - col][row]).collect())
+        .collect()
+}
+
+fn load(layout: &[Vec<u8>]) -> usize {
+    layout
+        .iter()
+        .enumerate()
+        .map(|(row, tiles)| {
+            (layout.len() - row) * tiles.iter().filter(|tile| **tile == b'O').count()
+        })
+        .sum()
+}
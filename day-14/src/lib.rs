@@ -0,0 +1,277 @@
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use cli::{
+    Solver,
+    answer::{Answer, Overflow, try_sum, widen, with_width},
+    to_json,
+};
+use cycle::state_after;
+
+pub use error::Error;
+
+pub mod animate;
+mod error;
+pub mod generator;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 14,
+    version: env!("SOLVER_VERSION"),
+    part_1: |input, settings| with_width(settings, || part_1(input), || part_1_as::<u128>(input)),
+    part_2: Some(|input, settings| {
+        with_width(settings, || part_2(input), || part_2_as::<u128>(input))
+    }),
+    generator: generator::generate,
+    render: None,
+    animate: Some(animate::animate),
+    dump: Some(|input| to_json(&Grid::from_str(input)?)),
+    stream: None,
+    certify: None,
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<usize, Error> {
+    part_1_as(input)
+}
+
+pub fn part_2(input: &str) -> Result<usize, Error> {
+    part_2_as(input)
+}
+
+/// Finds the load after tilting north in A.
+pub fn part_1_as<A: Answer + TryFrom<usize>>(input: &str) -> Result<A, Error> {
+    let mut grid = Grid::from_str(input)?;
+
+    grid.tilt_north();
+
+    Ok(grid.load()?)
+}
+
+/// Finds the load after a billion spins in A.
+pub fn part_2_as<A: Answer + TryFrom<usize>>(input: &str) -> Result<A, Error> {
+    let mut grid = Grid::from_str(input)?;
+
+    // The spins fall into a cycle long before reaching the target.
+    let size = grid.size;
+    grid.layout = state_after(grid.layout, 1000000000, |layout| {
+        let mut grid = Grid {
+            layout: layout.clone(),
+            size,
+        };
+        grid.spin();
+
+        grid.layout
+    });
+
+    Ok(grid.load()?)
+}
+
+#[derive(Serialize)]
+pub struct Grid {
+    #[serde(serialize_with = "cli::serialize_rows")]
+    pub layout: Vec<Vec<u8>>,
+    pub size: usize,
+}
+
+impl FromStr for Grid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let lines = s.lines().collect::<Vec<_>>();
+
+        let size = lines.len();
+        if let Some(line) = lines.iter().find(|line| line.len() != size) {
+            return Err(Error::NotSquare(line.to_string()));
+        }
+
+        let layout = lines
+            .into_iter()
+            .map(|line| line.bytes().collect())
+            .collect();
+
+        Ok(Self { layout, size })
+    }
+}
+
+impl Grid {
+    pub fn tilt_north(&mut self) {
+        for col in 0..self.size {
+            let mut empty_space = None;
+
+            let mut row = 0;
+            while row < self.size {
+                match self.layout[row][col] {
+                    b'.' => empty_space = empty_space.or(Some(row)),
+                    b'#' => empty_space = None,
+                    b'O' => {
+                        if let Some(empty_row) = empty_space {
+                            (self.layout[empty_row][col], self.layout[row][col]) =
+                                (self.layout[row][col], self.layout[empty_row][col]);
+
+                            empty_space = None;
+                            row = empty_row + 1;
+                            continue;
+                        }
+                    }
+                    _ => (),
+                }
+
+                row += 1;
+            }
+        }
+    }
+
+    pub fn tilt_east(&mut self) {
+        for row in 0..self.size {
+            let mut empty_space = None;
+
+            let mut col = self.size - 1;
+            loop {
+                match self.layout[row][col] {
+                    b'.' => empty_space = empty_space.or(Some(col)),
+                    b'#' => empty_space = None,
+                    b'O' => {
+                        if let Some(empty_col) = empty_space {
+                            (self.layout[row][empty_col], self.layout[row][col]) =
+                                (self.layout[row][col], self.layout[row][empty_col]);
+
+                            empty_space = None;
+                            col = empty_col - 1;
+                            continue;
+                        }
+                    }
+                    _ => (),
+                }
+
+                if col == 0 {
+                    break;
+                } else {
+                    col -= 1;
+                }
+            }
+        }
+    }
+
+    pub fn tilt_south(&mut self) {
+        for col in 0..self.size {
+            let mut empty_space = None;
+
+            let mut row = self.size - 1;
+            loop {
+                match self.layout[row][col] {
+                    b'.' => empty_space = empty_space.or(Some(row)),
+                    b'#' => empty_space = None,
+                    b'O' => {
+                        if let Some(empty_row) = empty_space {
+                            (self.layout[empty_row][col], self.layout[row][col]) =
+                                (self.layout[row][col], self.layout[empty_row][col]);
+
+                            empty_space = None;
+                            row = empty_row - 1;
+                            continue;
+                        }
+                    }
+                    _ => (),
+                }
+
+                if row == 0 {
+                    break;
+                } else {
+                    row -= 1;
+                }
+            }
+        }
+    }
+
+    pub fn tilt_west(&mut self) {
+        for row in 0..self.size {
+            let mut empty_space = None;
+
+            let mut col = 0;
+            while col < self.size {
+                match self.layout[row][col] {
+                    b'.' => empty_space = empty_space.or(Some(col)),
+                    b'#' => empty_space = None,
+                    b'O' => {
+                        if let Some(empty_col) = empty_space {
+                            (self.layout[row][empty_col], self.layout[row][col]) =
+                                (self.layout[row][col], self.layout[row][empty_col]);
+
+                            empty_space = None;
+                            col = empty_col + 1;
+                            continue;
+                        }
+                    }
+                    _ => (),
+                }
+
+                col += 1;
+            }
+        }
+    }
+
+    /// Sums the rows from each round rock to the south edge in A.
+    pub fn load<A: Answer + TryFrom<usize>>(&self) -> Result<A, Overflow> {
+        let loads = self
+            .layout
+            .iter()
+            .enumerate()
+            .flat_map(|(row, bytes)| {
+                bytes.iter().filter_map(move |byte| {
+                    if *byte == b'O' {
+                        Some(widen(self.size - row))
+                    } else {
+                        None
+                    }
+                })
+            })
+            .collect::<Result<Vec<A>, _>>()?;
+
+        try_sum(loads)
+    }
+
+    pub fn spin(&mut self) {
+        self.tilt_north();
+        self.tilt_west();
+        self.tilt_south();
+        self.tilt_east();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::trim_newlines;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+";
+
+    #[test]
+    fn example_1() -> Result<()> {
+        assert_eq!(part_1(trim_newlines(EXAMPLE))?, 136);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        assert_eq!(part_2(trim_newlines(EXAMPLE))?, 64);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind, NumberError, answer::Overflow};
+
+/// Why a day 15 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    /// The HASH algorithm is only defined on ASCII.
+    #[error("Invalid byte {byte} in {step}")]
+    NotAscii { byte: u8, step: String },
+
+    /// A step that neither sets a lens with = nor removes one with -.
+    #[error("Invalid step: {0}")]
+    InvalidStep(String),
+
+    #[error(transparent)]
+    Number(#[from] NumberError),
+
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::NotAscii { .. } | Error::InvalidStep(_) | Error::Number(_) => ErrorKind::Parse,
+            Error::Overflow(_) => ErrorKind::Unsolvable,
+        }
+    }
+}
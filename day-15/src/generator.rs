@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+
+use rand::{Rng, rngs::StdRng, seq::IndexedRandom};
+
+use cli::Generated;
+
+/// Generates size steps over a small pool of labels, so that lenses are often replaced and removed.
+///
+/// Part 2 is answered by tracking when each lens was first put into its box, instead of keeping the
+/// boxes in order.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let labels = (0..size / 4 + 1)
+        .map(|_| {
+            (0..rng.random_range(1..=6))
+                .map(|_| rng.random_range(b'a'..=b'z') as char)
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut steps = Vec::new();
+    // label -> (time put into box, focal len)
+    let mut lenses = HashMap::new();
+    for time in 0..size {
+        let label = labels.choose(rng).unwrap();
+        if rng.random_bool(0.3) {
+            lenses.remove(label);
+            steps.push(format!("{label}-"));
+        } else {
+            let focal_len = rng.random_range(1..=9);
+            lenses
+                .entry(label)
+                .and_modify(|lens: &mut (usize, usize)| lens.1 = focal_len)
+                .or_insert((time, focal_len));
+            steps.push(format!("{label}={focal_len}"));
+        }
+    }
+
+    let hash = |s: &str| {
+        s.bytes()
+            .fold(0, |acc, byte| (acc + byte as usize) * 17 % 256)
+    };
+    let sum_hashes = steps.iter().map(|step| hash(step)).sum::<usize>();
+
+    let mut focusing_power = 0;
+    for box_index in 0..256 {
+        let mut lenses = lenses
+            .iter()
+            .filter(|(label, _)| hash(label) == box_index)
+            .map(|(_, lens)| *lens)
+            .collect::<Vec<_>>();
+        lenses.sort();
+
+        for (slot, (_, focal_len)) in lenses.into_iter().enumerate() {
+            focusing_power += (box_index + 1) * (slot + 1) * focal_len;
+        }
+    }
+
+    Generated {
+        input: steps.join(","),
+        answers: [
+            Some(sum_hashes.to_string()),
+            Some(focusing_power.to_string()),
+        ],
+    }
+}
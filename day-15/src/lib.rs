@@ -0,0 +1,196 @@
+use serde::Serialize;
+
+use cli::{
+    Solver,
+    answer::{Answer, Overflow, try_sum, widen, with_width},
+    parse_number, to_json,
+};
+
+pub use error::Error;
+
+mod error;
+pub mod generator;
+pub mod stream;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 15,
+    version: env!("SOLVER_VERSION"),
+    part_1: |input, settings| with_width(settings, || part_1(input), || part_1_as::<u128>(input)),
+    part_2: Some(|input, settings| {
+        with_width(settings, || part_2(input), || part_2_as::<u128>(input))
+    }),
+    generator: generator::generate,
+    render: None,
+    animate: None,
+    dump: Some(|input| {
+        to_json(
+            &input
+                .split_terminator(",")
+                .map(Step::from_str)
+                .collect::<Result<Vec<_>, _>>()?,
+        )
+    }),
+    stream: Some(stream::stream),
+    certify: None,
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<usize, Error> {
+    part_1_as(input)
+}
+
+pub fn part_2(input: &str) -> Result<usize, Error> {
+    part_2_as(input)
+}
+
+/// Sums the hashes of the steps in A.
+pub fn part_1_as<A: Answer + TryFrom<usize>>(input: &str) -> Result<A, Error> {
+    let hashes = input
+        .split_terminator(",")
+        .map(|step| Ok(widen(hash(step)?)?))
+        .collect::<Result<Vec<A>, Error>>()?;
+
+    Ok(try_sum(hashes)?)
+}
+
+/// Finds the focusing power after every step in A.
+pub fn part_2_as<A: Answer + TryFrom<usize>>(input: &str) -> Result<A, Error> {
+    let steps = input
+        .split_terminator(",")
+        .map(Step::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut boxes: Vec<Vec<Lens>> = vec![Vec::new(); 256];
+    for step in steps {
+        match step {
+            Step::Replace {
+                box_index,
+                label,
+                focal_len,
+            } => {
+                if let Some(pos) = boxes[box_index].iter().position(|lens| lens.0 == label) {
+                    boxes[box_index][pos] = (label, focal_len);
+                } else {
+                    boxes[box_index].push((label, focal_len));
+                }
+            }
+            Step::Remove { box_index, label } => {
+                if let Some(pos) = boxes[box_index].iter().position(|lens| lens.0 == label) {
+                    boxes[box_index].remove(pos);
+                }
+            }
+        }
+    }
+
+    Ok(focusing_power(boxes)?)
+}
+
+pub fn hash(input: &str) -> Result<usize, Error> {
+    let mut curr_value = 0;
+
+    for byte in input.bytes() {
+        if !byte.is_ascii() {
+            return Err(Error::NotAscii {
+                byte,
+                step: input.to_string(),
+            });
+        }
+
+        curr_value = ((curr_value + byte as usize) * 17) % 256;
+    }
+
+    Ok(curr_value)
+}
+
+#[derive(Serialize)]
+pub enum Step<'a> {
+    Replace {
+        box_index: usize,
+        label: &'a str,
+        focal_len: u8,
+    },
+    Remove {
+        box_index: usize,
+        label: &'a str,
+    },
+}
+
+impl<'a> Step<'a> {
+    // Borrows from s, so cannot impl FromStr.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &'a str) -> Result<Self, Error> {
+        match s {
+            s if s.contains('=') => {
+                let Some((label, focal_len)) = s.split_once("=") else {
+                    return Err(Error::InvalidStep(s.to_string()));
+                };
+
+                let box_index = hash(label)?;
+                let focal_len = parse_number(focal_len)?;
+
+                Ok(Self::Replace {
+                    box_index,
+                    label,
+                    focal_len,
+                })
+            }
+            s if s.ends_with('-') => {
+                let label = s
+                    .strip_suffix('-')
+                    .ok_or_else(|| Error::InvalidStep(s.to_string()))?;
+
+                let box_index = hash(label)?;
+
+                Ok(Self::Remove { box_index, label })
+            }
+            _ => Err(Error::InvalidStep(s.to_string())),
+        }
+    }
+}
+
+pub type Lens<'a> = (&'a str, u8);
+
+/// Sums the focusing powers of the lenses in A.
+pub fn focusing_power<A: Answer + TryFrom<usize>>(boxes: Vec<Vec<Lens>>) -> Result<A, Overflow> {
+    let powers = boxes
+        .into_iter()
+        .enumerate()
+        .flat_map(|(box_index, lenses)| {
+            lenses
+                .into_iter()
+                .enumerate()
+                .map(move |(lens_index, lens)| {
+                    widen::<A, _>(1 + box_index)?
+                        .try_mul(widen(1 + lens_index)?)?
+                        .try_mul(A::from(lens.1))
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    try_sum(powers)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+
+    const EXAMPLE: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+    #[test]
+    fn example_1() -> Result<()> {
+        assert_eq!(part_1(EXAMPLE)?, 1320);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        assert_eq!(part_2(EXAMPLE)?, 145);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use std::io::BufRead;
+
+use anyhow::{Result, anyhow};
+
+use cli::{
+    answer::{Answer, is_wide, widen},
+    config::Settings,
+    input::for_each_record,
+};
+
+use crate::{Step, hash};
+
+/// Follows the initialization sequence a step at a time. Only the lenses in the boxes are kept, and
+/// the answer is accumulated in 128 bits, then narrowed to usize unless [is_wide].
+pub fn stream(reader: &mut dyn BufRead, part: u8, settings: &Settings) -> Result<String> {
+    if !(1..=2).contains(&part) {
+        return Err(anyhow!("Invalid part number: {}", part));
+    }
+
+    let mut sum = 0u128;
+    let mut boxes: Vec<Vec<(String, u8)>> = vec![Vec::new(); 256];
+    for_each_record(reader, b',', |step| {
+        if part == 1 {
+            sum = sum.try_add(widen(hash(step)?)?)?;
+            return Ok(());
+        }
+
+        match Step::from_str(step)? {
+            Step::Replace {
+                box_index,
+                label,
+                focal_len,
+            } => {
+                let lenses = &mut boxes[box_index];
+                if let Some(lens) = lenses.iter_mut().find(|lens| lens.0 == label) {
+                    lens.1 = focal_len;
+                } else {
+                    lenses.push((label.to_string(), focal_len));
+                }
+            }
+            Step::Remove { box_index, label } => {
+                boxes[box_index].retain(|lens| lens.0 != label);
+            }
+        }
+
+        Ok(())
+    })?;
+
+    if part == 2 {
+        sum = crate::focusing_power(
+            boxes
+                .iter()
+                .map(|lenses| {
+                    lenses
+                        .iter()
+                        .map(|(label, focal_len)| (label.as_str(), *focal_len))
+                        .collect()
+                })
+                .collect(),
+        )?;
+    }
+
+    if is_wide(settings)? {
+        Ok(sum.to_string())
+    } else {
+        Ok(widen::<usize, _>(sum)?.to_string())
+    }
+}
@@ -0,0 +1,113 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use anyhow::{Result, anyhow};
+
+use cli::animate::{Frame, Frames};
+use geometry::{Direction, Point};
+
+use crate::{Beam, Error, Grid};
+
+/// Plays back the beam spreading through the contraption, a tile per step. Part 1 starts the beam
+/// from the top left, while part 2 starts it from the edge tile that energises the most tiles.
+pub fn animate(input: &str, part: u8) -> Result<Frames<'_>> {
+    let grid = Grid::from_str(input)?;
+    let start = match part {
+        1 => (Point::new(0, 0), Direction::Right),
+        2 => grid
+            .entry_beams()
+            .max_by_key(|beam| grid.count_energised_tiles(*beam))
+            .ok_or(Error::Empty)?,
+        _ => return Err(anyhow!("Invalid part number: {}", part)),
+    };
+
+    let mut beam_trail = HashSet::from([start]);
+    let mut beams = vec![start];
+    let mut step = 0;
+
+    Ok(Box::new(std::iter::from_fn(move || {
+        if beams.is_empty() {
+            return None;
+        }
+
+        let frame = frame(step, &grid, &beam_trail);
+        beams = beams
+            .iter()
+            .flat_map(|beam| grid.traverse_beam(*beam))
+            .filter(|beam| beam_trail.insert(*beam))
+            .collect();
+        step += 1;
+
+        Some(frame)
+    })))
+}
+
+/// Draws each energised empty tile with the direction of the beam through it, or the num of
+/// beams through it if there are several, as in the puzzle.
+fn frame(step: usize, grid: &Grid, beam_trail: &HashSet<Beam>) -> Frame {
+    let mut directions = HashMap::<Point, Vec<Direction>>::new();
+    for (point, direction) in beam_trail {
+        directions.entry(*point).or_default().push(*direction);
+    }
+
+    let mut text = Vec::new();
+    for (row, tiles) in grid.tiles.iter().enumerate() {
+        let line = tiles
+            .iter()
+            .enumerate()
+            .map(|(col, tile)| match directions.get(&Point::new(row, col)) {
+                Some(directions) if *tile == b'.' => match directions.as_slice() {
+                    [Direction::Up] => '^',
+                    [Direction::Right] => '>',
+                    [Direction::Down] => 'v',
+                    [Direction::Left] => '<',
+                    directions => char::from(b'0' + directions.len() as u8),
+                },
+                _ => *tile as char,
+            })
+            .collect::<String>();
+        text.push(line);
+    }
+
+    Frame::new(
+        format!("Step {}, {} tiles energised", step, directions.len()),
+        text.join("\n"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::trim_newlines;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+.|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+";
+
+    #[test]
+    fn beam_spreads() -> Result<()> {
+        let frames = animate(trim_newlines(EXAMPLE), 1)?.collect::<Vec<_>>();
+
+        assert_eq!(frames[0].caption, "Step 0, 1 tiles energised");
+        assert!(frames[0].text.starts_with(">|...\\...."));
+        assert!(
+            frames[frames.len() - 1]
+                .caption
+                .ends_with(", 46 tiles energised")
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind};
+
+/// Why a day 16 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    /// The contraption must be as tall as it is wide.
+    #[error("Contraption is not square: {0}")]
+    NotSquare(String),
+
+    #[error("Contraption is empty")]
+    Empty,
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::NotSquare(_) => ErrorKind::Parse,
+            Error::Empty => ErrorKind::Validation,
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use rand::{Rng, rngs::StdRng};
+
+use cli::Generated;
+
+/// Generates a size x size contraption of mirrors and splitters.
+///
+/// Both parts are answered by a separate simulation that reflects beams as (row, col) deltas.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let layout = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| match rng.random_range(0..20) {
+                    0 => b'/',
+                    1 => b'\\',
+                    2 => b'|',
+                    3 => b'-',
+                    _ => b'.',
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let size = size as isize;
+    let max_energised = (0..size)
+        .flat_map(|index| {
+            [
+                (0, index, 1, 0),
+                (index, size - 1, 0, -1),
+                (size - 1, index, -1, 0),
+                (index, 0, 0, 1),
+            ]
+        })
+        .map(|beam| count_energised(&layout, beam))
+        .max()
+        .unwrap_or(0);
+
+    Generated {
+        input: layout
+            .iter()
+            .map(|row| String::from_utf8_lossy(row))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        answers: [
+            Some(count_energised(&layout, (0, 0, 0, 1)).to_string()),
+            Some(max_energised.to_string()),
+        ],
+    }
+}
+
+/// Follows every beam of (row, col, row delta, col delta), marking each tile and delta seen.
+fn count_energised(layout: &[Vec<u8>], beam: (isize, isize, isize, isize)) -> usize {
+    let size = layout.len() as isize;
+    let delta_index = |dr: isize, dc: isize| ((dr + 1) + (dc + 1) / 2 * 2) as usize;
+    let mut seen = vec![[false; 4]; layout.len() * layout.len()];
+    let mut beams = vec![beam];
+    while let Some((row, col, dr, dc)) = beams.pop() {
+        if row < 0 || col < 0 || row >= size || col >= size {
+            continue;
+        }
+        let seen = &mut seen[(row * size + col) as usize][delta_index(dr, dc)];
+        if *seen {
+            continue;
+        }
+        *seen = true;
+
+        let deltas = match layout[row as usize][col as usize] {
+            b'/' => vec![(-dc, -dr)],
+            b'\\' => vec![(dc, dr)],
+            b'|' if dc != 0 => vec![(-1, 0), (1, 0)],
+            b'-' if dr != 0 => vec![(0, -1), (0, 1)],
+            _ => vec![(dr, dc)],
+        };
+        for (dr, dc) in deltas {
+            beams.push((row + dr, col + dc, dr, dc));
+        }
+    }
+
+    seen.into_iter()
+        .filter(|deltas| deltas.contains(&true))
+        .count()
+}
@@ -0,0 +1,196 @@
+use std::{collections::HashSet, str::FromStr};
+
+use serde::Serialize;
+
+use cli::{
+    Solver, parallel,
+    progress::{self, Goal},
+    to_json,
+};
+use geometry::{Direction, Point, Size};
+
+pub use error::Error;
+
+pub mod animate;
+mod error;
+pub mod generator;
+pub mod render;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 16,
+    version: env!("SOLVER_VERSION"),
+    // Energised tiles are counted once each, so there are no more than the grid holds.
+    part_1: |input, _| Ok(part_1(input)?.to_string()),
+    part_2: Some(|input, _| Ok(part_2(input)?.to_string())),
+    generator: generator::generate,
+    render: Some(render::render),
+    animate: Some(animate::animate),
+    dump: Some(|input| to_json(&Grid::from_str(input)?)),
+    stream: None,
+    certify: None,
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<usize, Error> {
+    let grid = Grid::from_str(input)?;
+
+    Ok(grid.count_energised_tiles((Point::new(0, 0), Direction::Right)))
+}
+
+pub fn part_2(input: &str) -> Result<usize, Error> {
+    let grid = Grid::from_str(input)?;
+
+    let entry_beams = grid.entry_beams().collect::<Vec<_>>();
+
+    let progress = progress::start(Some(entry_beams.len()));
+    parallel::map(&entry_beams, |beam| {
+        let energised_tiles = grid.count_energised_tiles(*beam);
+        progress.advance();
+        progress.best(energised_tiles, Goal::Max);
+
+        energised_tiles
+    })
+    .into_iter()
+    .max()
+    .ok_or(Error::Empty)
+}
+
+#[derive(Serialize)]
+pub struct Grid {
+    #[serde(serialize_with = "cli::serialize_rows")]
+    pub tiles: Vec<Vec<u8>>,
+    pub size: usize,
+}
+
+impl FromStr for Grid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let lines = s.lines().collect::<Vec<_>>();
+
+        let size = lines.len();
+        if size == 0 {
+            return Err(Error::Empty);
+        }
+        if let Some(line) = lines.iter().find(|line| line.len() != size) {
+            return Err(Error::NotSquare(line.to_string()));
+        }
+
+        let tiles = lines
+            .into_iter()
+            .map(|line| line.bytes().collect())
+            .collect();
+
+        Ok(Self { tiles, size })
+    }
+}
+
+impl Grid {
+    pub fn traverse_beam(&self, beam: Beam) -> Vec<Beam> {
+        let (point, direction) = beam;
+
+        let directions = match (self.tiles[point.row][point.col], direction) {
+            (b'|', Direction::Right | Direction::Left) => vec![Direction::Up, Direction::Down],
+            (b'-', Direction::Up | Direction::Down) => vec![Direction::Right, Direction::Left],
+            (b'/', Direction::Up | Direction::Down) => vec![direction.turn_right()],
+            (b'/', Direction::Right | Direction::Left) => vec![direction.turn_left()],
+            (b'\\', Direction::Up | Direction::Down) => vec![direction.turn_left()],
+            (b'\\', Direction::Right | Direction::Left) => vec![direction.turn_right()],
+            (b'.' | b'|' | b'-', _) => vec![direction],
+            _ => Vec::new(),
+        };
+
+        directions
+            .into_iter()
+            .filter_map(|direction| Some((point.step(direction, self.bounds())?, direction)))
+            .collect()
+    }
+
+    /// Gets every [Beam] that enters the grid from an edge.
+    pub fn entry_beams(&self) -> impl Iterator<Item = Beam> {
+        (0..self.size).flat_map(|index| {
+            [
+                (Point::new(0, index), Direction::Down),
+                (Point::new(index, self.size - 1), Direction::Left),
+                (Point::new(self.size - 1, index), Direction::Up),
+                (Point::new(index, 0), Direction::Right),
+            ]
+        })
+    }
+
+    pub fn count_energised_tiles(&self, beam: Beam) -> usize {
+        self.energised_tiles(beam).len()
+    }
+
+    pub fn energised_tiles(&self, beam: Beam) -> HashSet<Point> {
+        let mut beam_trail = HashSet::new();
+        let mut beams = vec![beam];
+        while let Some(beam) = beams.pop() {
+            if beam_trail.insert(beam) {
+                beams.extend(self.traverse_beam(beam));
+            }
+        }
+
+        beam_trail.into_iter().map(|beam| beam.0).collect()
+    }
+
+    pub fn bounds(&self) -> Size {
+        Size::square(self.size)
+    }
+}
+
+pub type Beam = (Point, Direction);
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::{progress::Progress, trim_newlines};
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+.|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+";
+
+    #[test]
+    fn example_1() -> Result<()> {
+        assert_eq!(part_1(trim_newlines(EXAMPLE))?, 46);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        assert_eq!(part_2(trim_newlines(EXAMPLE))?, 51);
+
+        Ok(())
+    }
+
+    #[test]
+    fn progress() -> Result<()> {
+        let (answer, reported) = progress::track(|| part_2(trim_newlines(EXAMPLE)));
+
+        assert_eq!(answer?, 51);
+        assert_eq!(
+            reported,
+            Some(Progress {
+                done: 40,
+                total: Some(40),
+                best: Some(51),
+            })
+        );
+
+        Ok(())
+    }
+}
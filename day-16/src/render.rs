@@ -0,0 +1,64 @@
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+
+use cli::render::{HIGHLIGHT, Image, OPEN, WALL};
+use geometry::{Direction, Point};
+
+use crate::{Error, Grid};
+
+/// Draws the energised tiles over the mirrors and splitters. Part 1 starts the beam from the top
+/// left, while part 2 starts it from the edge tile that energises the most tiles.
+pub fn render(input: &str, part: u8) -> Result<Image> {
+    let grid = Grid::from_str(input)?;
+    let energised_tiles = match part {
+        1 => grid.energised_tiles((Point::new(0, 0), Direction::Right)),
+        2 => grid
+            .entry_beams()
+            .map(|beam| grid.energised_tiles(beam))
+            .max_by_key(|tiles| tiles.len())
+            .ok_or(Error::Empty)?,
+        _ => return Err(anyhow!("Invalid part number: {}", part)),
+    };
+
+    let mut image = Image::new(grid.size, grid.size, OPEN);
+    for (row, bytes) in grid.tiles.iter().enumerate() {
+        for (col, byte) in bytes.iter().enumerate() {
+            if energised_tiles.contains(&Point::new(row, col)) {
+                image.set(row, col, HIGHLIGHT);
+            } else if *byte != b'.' {
+                image.set(row, col, WALL);
+            }
+        }
+    }
+
+    Ok(image)
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::trim_newlines;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+.|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+";
+
+    #[test]
+    fn energised_tiles() -> Result<()> {
+        assert_eq!(render(trim_newlines(EXAMPLE), 1)?.count(HIGHLIGHT), 46);
+        assert_eq!(render(trim_newlines(EXAMPLE), 2)?.count(HIGHLIGHT), 51);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+
+use cli::certify::Certificate;
+
+use crate::{CrucibleType, Error, Grid, check::Witness, shortest_path};
+
+/// Solves the given part along with the path with the least heat loss.
+pub fn certify(input: &str, part: u8) -> Result<Certificate> {
+    let crucible_type = match part {
+        1 => CrucibleType::Normal,
+        2 => CrucibleType::Ultra,
+        _ => return Err(anyhow!("Invalid part number: {}", part)),
+    };
+
+    let grid = Grid::from_str(input)?;
+    let (nodes, heat_loss) =
+        shortest_path(&grid, crucible_type).ok_or(Error::NoPath(crucible_type))?;
+    let path = nodes
+        .into_iter()
+        .skip(1)
+        .map(|(point, _)| [point.row, point.col])
+        .collect();
+
+    Certificate::new(heat_loss, &Witness { path })
+}
@@ -0,0 +1,133 @@
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+use cli::certify::Certificate;
+
+use crate::Grid;
+
+/// A path that the crucible can take, which has the heat loss of the answer. It is not checked that
+/// there is no better path.
+#[derive(Deserialize, Serialize)]
+pub struct Witness {
+    /// [row, col] of every block the crucible enters, from the top left to the bottom right.
+    pub path: Vec<[usize; 2]>,
+}
+
+/// Follows the path a block at a time, checking every straight line against the limits of the
+/// crucible, and adds up the heat loss.
+pub fn check(input: &str, part: u8, certificate: &Certificate) -> Result<()> {
+    let (min_line, max_line) = match part {
+        1 => (1, 3),
+        2 => (4, 10),
+        _ => return Err(anyhow!("Invalid part number: {}", part)),
+    };
+
+    let grid = Grid::from_str(input)?;
+    let Witness { path } = certificate.witness()?;
+
+    let mut heat_loss = 0u64;
+    // (step, len)
+    let mut lines: Vec<([isize; 2], usize)> = Vec::new();
+    let mut prev = [0, 0];
+    for &[row, col] in &path {
+        if row >= grid.size || col >= grid.size {
+            return Err(anyhow!("Leaves the city at {:?}", [row, col]));
+        }
+
+        let step = [
+            row as isize - prev[0] as isize,
+            col as isize - prev[1] as isize,
+        ];
+        if step[0].abs() + step[1].abs() != 1 {
+            return Err(anyhow!("Jumps from {:?} to {:?}", prev, [row, col]));
+        }
+        match lines.last_mut() {
+            Some((last, len)) if *last == step => *len += 1,
+            Some((last, _)) if *last == [-step[0], -step[1]] => {
+                return Err(anyhow!("Reverses at {:?}", prev));
+            }
+            _ => lines.push((step, 1)),
+        }
+
+        heat_loss += u64::from(grid.heat_loss[row][col]);
+        prev = [row, col];
+    }
+
+    if prev != [grid.size - 1, grid.size - 1] {
+        return Err(anyhow!("Ends at {:?} rather than the bottom right", prev));
+    }
+    if let Some((_, len)) = lines
+        .iter()
+        .find(|(_, len)| !(min_line..=max_line).contains(len))
+    {
+        return Err(anyhow!(
+            "Moves {} blocks in a straight line, rather than {} to {}",
+            len,
+            min_line,
+            max_line
+        ));
+    }
+
+    certificate.expect_answer(heat_loss)
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::trim_newlines;
+
+    use crate::certify::certify;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+";
+
+    #[test]
+    fn example() -> Result<()> {
+        let input = trim_newlines(EXAMPLE);
+        for (part, answer) in [(1, "102"), (2, "94")] {
+            let certificate = certify(input, part)?;
+
+            assert_eq!(certificate.answer, answer);
+            check(input, part, &certificate)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_paths() -> Result<()> {
+        let input = "123\n456\n789";
+        let check_path = |part, answer, path: &[[usize; 2]]| {
+            let witness = Witness {
+                path: path.to_vec(),
+            };
+
+            check(input, part, &Certificate::new(answer, &witness)?)
+        };
+
+        check_path(1, 20, &[[0, 1], [0, 2], [1, 2], [2, 2]])?;
+        assert!(check_path(1, 19, &[[0, 1], [0, 2], [1, 2], [2, 2]]).is_err());
+        assert!(check_path(1, 15, &[[1, 1], [2, 2]]).is_err());
+        assert!(check_path(1, 14, &[[0, 1], [0, 2], [1, 2]]).is_err());
+        assert!(check_path(1, 20, &[[0, 1], [0, 2], [0, 1], [1, 1], [2, 1], [2, 2]]).is_err());
+        assert!(check_path(2, 20, &[[0, 1], [0, 2], [1, 2], [2, 2]]).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind};
+
+use crate::CrucibleType;
+
+/// Why a day 17 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    /// The city must be as tall as it is wide.
+    #[error("City is not square: {0}")]
+    NotSquare(String),
+
+    #[error("City is empty")]
+    Empty,
+
+    #[error("Invalid heat loss: {0}")]
+    InvalidHeatLoss(char),
+
+    /// The crucible cannot reach the bottom right within its limits on moving and turning.
+    #[error("Cannot find shortest path for {0:?} crucible")]
+    NoPath(CrucibleType),
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::NotSquare(_) | Error::InvalidHeatLoss(_) => ErrorKind::Parse,
+            Error::Empty => ErrorKind::Validation,
+            Error::NoPath(_) => ErrorKind::Unsolvable,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use rand::{Rng, rngs::StdRng};
+
+use cli::Generated;
+
+/// Generates a size x size map of heat loss, at least 5 x 5 so that the ultra crucible can reach the
+/// end.
+///
+/// Both parts are answered by a separate search, where each move is a whole straight run followed by
+/// a turn.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let size = size.max(5);
+    let heat_loss = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| rng.random_range(1..=9))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    Generated {
+        input: heat_loss
+            .iter()
+            .map(|row| row.iter().map(|loss| loss.to_string()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n"),
+        answers: [
+            min_heat_loss(&heat_loss, 1, 3).map(|loss| loss.to_string()),
+            min_heat_loss(&heat_loss, 4, 10).map(|loss| loss.to_string()),
+        ],
+    }
+}
+
+/// Finds the min heat loss with runs of min_run to max_run blocks, alternating between vertical and
+/// horizontal runs.
+fn min_heat_loss(heat_loss: &[Vec<u32>], min_run: isize, max_run: isize) -> Option<u32> {
+    let size = heat_loss.len() as isize;
+    // (row, col, vertical)
+    let mut best = vec![[u32::MAX; 2]; heat_loss.len() * heat_loss.len()];
+    let mut queue = BinaryHeap::from([Reverse((0, 0, 0, false)), Reverse((0, 0, 0, true))]);
+    while let Some(Reverse((loss, row, col, vertical))) = queue.pop() {
+        if row == size - 1 && col == size - 1 {
+            return Some(loss);
+        }
+        if loss > best[(row * size + col) as usize][vertical as usize] {
+            continue;
+        }
+
+        // Turn onto the other axis, either way.
+        for sign in [-1, 1] {
+            let mut next_loss = loss;
+            for run in 1..=max_run {
+                let (next_row, next_col) = if vertical {
+                    (row, col + sign * run)
+                } else {
+                    (row + sign * run, col)
+                };
+                if next_row < 0 || next_col < 0 || next_row >= size || next_col >= size {
+                    break;
+                }
+                next_loss += heat_loss[next_row as usize][next_col as usize];
+
+                let best = &mut best[(next_row * size + next_col) as usize][!vertical as usize];
+                if run >= min_run && next_loss < *best {
+                    *best = next_loss;
+                    queue.push(Reverse((next_loss, next_row, next_col, !vertical)));
+                }
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,236 @@
+use std::str::FromStr;
+
+use pathfinding::prelude::dijkstra;
+use serde::Serialize;
+
+use cli::{Solver, certify::Certifier, to_json};
+use geometry::{Direction, Point, Size};
+
+pub use error::Error;
+
+pub mod certify;
+pub mod check;
+mod error;
+pub mod generator;
+pub mod render;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 17,
+    version: env!("SOLVER_VERSION"),
+    // The least heat loss is at most 9 for each node of the path, which never repeats a node, so
+    // the u64 sum of the costs cannot overflow.
+    part_1: |input, _| Ok(part_1(input)?.to_string()),
+    part_2: Some(|input, _| Ok(part_2(input)?.to_string())),
+    generator: generator::generate,
+    render: Some(render::render),
+    animate: None,
+    dump: Some(|input| to_json(&Grid::from_str(input)?)),
+    stream: None,
+    certify: Some(Certifier {
+        parts: &[1, 2],
+        witness: certify::certify,
+        check: check::check,
+    }),
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<u64, Error> {
+    let grid = Grid::from_str(input)?;
+
+    shortest_path(&grid, CrucibleType::Normal)
+        .map(|shortest_path| shortest_path.1)
+        .ok_or(Error::NoPath(CrucibleType::Normal))
+}
+
+pub fn part_2(input: &str) -> Result<u64, Error> {
+    let grid = Grid::from_str(input)?;
+
+    shortest_path(&grid, CrucibleType::Ultra)
+        .map(|shortest_path| shortest_path.1)
+        .ok_or(Error::NoPath(CrucibleType::Ultra))
+}
+
+/// Finds the path with the least heat loss from the top left to the bottom right, returning every
+/// [Node] on the path and the heat loss.
+pub fn shortest_path(grid: &Grid, crucible_type: CrucibleType) -> Option<(Vec<Node>, u64)> {
+    dijkstra(
+        &(
+            Point::new(0, 0),
+            Crucible {
+                crucible_type,
+                ..Default::default()
+            },
+        ),
+        |node| successors(node, grid),
+        |node| success(node, grid),
+    )
+}
+
+#[derive(Serialize)]
+pub struct Grid {
+    pub heat_loss: Vec<Vec<u8>>,
+    pub size: usize,
+}
+
+impl FromStr for Grid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let lines = s.lines().collect::<Vec<_>>();
+
+        let size = lines.len();
+        if size == 0 {
+            return Err(Error::Empty);
+        }
+        if let Some(line) = lines.iter().find(|line| line.len() != size) {
+            return Err(Error::NotSquare(line.to_string()));
+        }
+
+        let heat_loss = lines
+            .into_iter()
+            .map(|line| {
+                line.bytes()
+                    .map(|byte| {
+                        if byte.is_ascii_digit() {
+                            Ok(byte - b'0')
+                        } else {
+                            Err(Error::InvalidHeatLoss(byte as char))
+                        }
+                    })
+                    .collect()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { heat_loss, size })
+    }
+}
+
+pub type Node = (Point, Crucible);
+
+pub fn successors(node: &Node, grid: &Grid) -> Vec<(Node, u64)> {
+    let &(point, crucible) = node;
+
+    point
+        .neighbours(Size::square(grid.size))
+        .filter(|(direction, _)| crucible.can_proceed_in_direction(*direction))
+        .map(|(direction, next)| {
+            (
+                (next, crucible.proceed_in_direction(direction)),
+                u64::from(grid.heat_loss[next.row][next.col]),
+            )
+        })
+        .collect()
+}
+
+pub fn success(node: &Node, grid: &Grid) -> bool {
+    let &(point, crucible) = node;
+
+    point == Point::new(grid.size - 1, grid.size - 1) && crucible.can_stop()
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum CrucibleType {
+    #[default]
+    Normal,
+    Ultra,
+}
+
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct Crucible {
+    pub crucible_type: CrucibleType,
+    /// None before the first move.
+    pub direction: Option<Direction>,
+    pub count: usize,
+}
+
+impl Default for Crucible {
+    fn default() -> Self {
+        Self {
+            crucible_type: Default::default(),
+            direction: None,
+            count: 1,
+        }
+    }
+}
+
+impl Crucible {
+    pub fn can_proceed_in_direction(&self, direction: Direction) -> bool {
+        let Some(current) = self.direction else {
+            return true;
+        };
+
+        match (self.crucible_type, current, direction) {
+            (_, a, b) if a.reverse() == b => false,
+            (CrucibleType::Normal, a, b) if a == b => self.count < 3,
+            (CrucibleType::Normal, _, _) => true,
+            (CrucibleType::Ultra, a, b) if a == b => self.count < 10,
+            (CrucibleType::Ultra, _, _) => self.count >= 4,
+        }
+    }
+
+    pub fn proceed_in_direction(&self, direction: Direction) -> Self {
+        Self {
+            crucible_type: self.crucible_type,
+            direction: Some(direction),
+            count: if self.direction == Some(direction) {
+                self.count + 1
+            } else {
+                1
+            },
+        }
+    }
+
+    pub fn can_stop(&self) -> bool {
+        match self.crucible_type {
+            CrucibleType::Normal => true,
+            CrucibleType::Ultra => self.count >= 4,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::trim_newlines;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+";
+
+    #[test]
+    fn example_1() -> Result<()> {
+        assert_eq!(part_1(trim_newlines(EXAMPLE))?, 102);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        assert_eq!(part_2(trim_newlines(EXAMPLE))?, 94);
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_path() {
+        assert_eq!(part_1("12\n34"), Ok(6));
+        assert_eq!(part_2("12\n34"), Err(Error::NoPath(CrucibleType::Ultra)));
+        assert_eq!(part_1("12\n3"), Err(Error::NotSquare(String::from("3"))));
+    }
+}
@@ -0,0 +1,79 @@
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+
+use cli::render::{Colour, HIGHLIGHT, Image, OPEN};
+
+use crate::{CrucibleType, Error, Grid, shortest_path};
+
+/// Draws the path with the least heat loss over the city blocks, which are shaded from light (heat
+/// loss of 1) to dark (heat loss of 9).
+pub fn render(input: &str, part: u8) -> Result<Image> {
+    let grid = Grid::from_str(input)?;
+    let crucible_type = match part {
+        1 => CrucibleType::Normal,
+        2 => CrucibleType::Ultra,
+        _ => return Err(anyhow!("Invalid part number: {}", part)),
+    };
+    let (path, _) = shortest_path(&grid, crucible_type).ok_or(Error::NoPath(crucible_type))?;
+
+    let mut image = Image::new(grid.size, grid.size, OPEN);
+    for (row, heat_losses) in grid.heat_loss.iter().enumerate() {
+        for (col, heat_loss) in heat_losses.iter().enumerate() {
+            image.set(row, col, shade(*heat_loss));
+        }
+    }
+    for (point, _) in path {
+        image.set(point.row, point.col, HIGHLIGHT);
+    }
+
+    Ok(image)
+}
+
+fn shade(heat_loss: u8) -> Colour {
+    let level = 250 - heat_loss.min(9) * 22;
+
+    [level, level, level]
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::trim_newlines;
+    use geometry::{Point, Size};
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+";
+
+    #[test]
+    fn path_heat_loss() -> Result<()> {
+        let grid = Grid::from_str(trim_newlines(EXAMPLE))?;
+        for (part, expected) in [(1, 102), (2, 94)] {
+            let image = render(trim_newlines(EXAMPLE), part)?;
+            let mut heat_loss = 0;
+            for point in Size::square(grid.size).points() {
+                if image.get(point.row, point.col) == HIGHLIGHT && point != Point::new(0, 0) {
+                    heat_loss += u64::from(grid.heat_loss[point.row][point.col]);
+                }
+            }
+
+            assert_eq!(heat_loss, expected);
+        }
+
+        Ok(())
+    }
+}
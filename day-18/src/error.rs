@@ -0,0 +1,41 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind, NumberError, answer::Overflow};
+use geometry::Vector;
+
+/// Why a day 18 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    #[error("Cannot split input into direction, distance and colour: {0}")]
+    InvalidInstruction(String),
+
+    #[error("Invalid direction: {0}")]
+    InvalidDirection(String),
+
+    #[error(transparent)]
+    Number(#[from] NumberError),
+
+    /// A colour that is not # followed by a 5-digit hex distance and a direction digit.
+    #[error("Invalid colour: {0}")]
+    InvalidColour(String),
+
+    /// The dig plan must end where it started.
+    #[error("Not a closed polygon: {0:?}")]
+    NotClosed(Vec<Vector>),
+
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::InvalidInstruction(_)
+            | Error::InvalidDirection(_)
+            | Error::Number(_)
+            | Error::InvalidColour(_) => ErrorKind::Parse,
+            Error::NotClosed(_) => ErrorKind::Validation,
+            Error::Overflow(_) => ErrorKind::Unsolvable,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use rand::{Rng, rngs::StdRng};
+
+use cli::Generated;
+
+/// (direction, distance)
+type Dig = (char, u64);
+
+/// Generates a dig plan around a lagoon of size cols, and a colour plan around a much larger lagoon
+/// of size cols, pairing up the instructions of both plans.
+///
+/// Each lagoon is a row of cols, each spanning a range of rows that overlaps its neighbours, so that
+/// both plans trace a loop of 4 x size instructions. The lagoons are measured by counting the
+/// trench-sized squares down each col of squares.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let cols = size.max(1);
+    let (plan, lagoon) = dig_lagoon(rng, cols, 10, 20);
+    let (colour_plan, colour_lagoon) = dig_lagoon(rng, cols, 100000, 1000000);
+
+    let lines = plan
+        .into_iter()
+        .zip(colour_plan)
+        .map(
+            |((direction, distance), (colour_direction, colour_distance))| {
+                let colour_direction = "RDLU".find(colour_direction).unwrap();
+                format!("{direction} {distance} (#{colour_distance:05x}{colour_direction})")
+            },
+        )
+        .collect::<Vec<_>>();
+
+    Generated {
+        input: lines.join("\n"),
+        answers: [Some(lagoon.to_string()), Some(colour_lagoon.to_string())],
+    }
+}
+
+/// Digs around cols, each up to max_width wide and spanning rows up to max_height, returning the
+/// plan and the num of squares dug out.
+fn dig_lagoon(rng: &mut StdRng, cols: usize, max_width: u64, max_height: u64) -> (Vec<Dig>, u64) {
+    // (width, top row, bottom row)
+    let mut spans: Vec<(u64, u64, u64)> = Vec::new();
+    while spans.len() < cols {
+        let width = rng.random_range(1..=max_width);
+        let top = rng.random_range(0..max_height);
+        let bottom = rng.random_range(top + 1..=max_height);
+
+        // Neighbouring cols must overlap, and must not line up, so that every instruction moves.
+        let fits = spans.last().is_none_or(|&(_, prev_top, prev_bottom)| {
+            top.max(prev_top) < bottom.min(prev_bottom) && top != prev_top && bottom != prev_bottom
+        });
+        if fits {
+            spans.push((width, top, bottom));
+        }
+    }
+
+    let vertical = |from: u64, to: u64| {
+        if to > from {
+            ('D', to - from)
+        } else {
+            ('U', from - to)
+        }
+    };
+    let mut plan = Vec::new();
+    for (index, &(width, top, _)) in spans.iter().enumerate() {
+        plan.push(('R', width));
+        let next_top = spans.get(index + 1).map_or(spans[index].2, |span| span.1);
+        plan.push(vertical(top, next_top));
+    }
+    for (index, &(width, _, bottom)) in spans.iter().enumerate().rev() {
+        plan.push(('L', width));
+        let next_bottom = match index {
+            0 => spans[0].1,
+            _ => spans[index - 1].2,
+        };
+        plan.push(vertical(bottom, next_bottom));
+    }
+
+    let mut squares = spans[0].2 - spans[0].1 + 1;
+    for (index, &(width, top, bottom)) in spans.iter().enumerate() {
+        squares += (width - 1) * (bottom - top + 1);
+        squares += match spans.get(index + 1) {
+            Some(&(_, next_top, next_bottom)) => bottom.max(next_bottom) - top.min(next_top) + 1,
+            None => bottom - top + 1,
+        };
+    }
+
+    (plan, squares)
+}
@@ -0,0 +1,271 @@
+use serde::Serialize;
+
+use cli::{
+    Solver,
+    answer::{Answer, Overflow, widen, with_width},
+    dump_lines, parse_number,
+};
+use geometry::{Direction, Vector};
+
+pub use error::Error;
+
+mod error;
+pub mod generator;
+pub mod stream;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 18,
+    version: env!("SOLVER_VERSION"),
+    part_1: |input, settings| with_width(settings, || part_1(input), || part_1_as::<i128>(input)),
+    part_2: Some(|input, settings| {
+        with_width(settings, || part_2(input), || part_2_as::<i128>(input))
+    }),
+    generator: generator::generate,
+    render: None,
+    animate: None,
+    dump: Some(|input| dump_lines(input, Instruction::from_str)),
+    stream: Some(stream::stream),
+    certify: None,
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<usize, Error> {
+    Ok(widen(part_1_as::<isize>(input)?)?)
+}
+
+pub fn part_2(input: &str) -> Result<usize, Error> {
+    Ok(widen(part_2_as::<isize>(input)?)?)
+}
+
+/// Finds the area of the lagoon in A, which is signed for the shoelace formula.
+pub fn part_1_as<A: Answer + TryFrom<isize> + TryFrom<usize>>(input: &str) -> Result<A, Error> {
+    // See https://en.wikipedia.org/wiki/Shoelace_formula.
+
+    let dig_plan = input
+        .lines()
+        .map(Instruction::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let vertices = convert_to_vertices(&dig_plan)?;
+
+    Ok(area(&vertices)?)
+}
+
+/// Finds the area of the lagoon dug by the colours in A, which is signed for the shoelace formula.
+pub fn part_2_as<A: Answer + TryFrom<isize> + TryFrom<usize>>(input: &str) -> Result<A, Error> {
+    let dig_plan = input
+        .lines()
+        .map(Instruction::from_str)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .map(|instruction| instruction.convert_from_colour())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let vertices = convert_to_vertices(&dig_plan)?;
+
+    Ok(area(&vertices)?)
+}
+
+#[derive(Serialize)]
+pub struct Instruction<'a> {
+    pub direction: Direction,
+    pub distance: isize,
+    pub colour: &'a str,
+}
+
+impl<'a> Instruction<'a> {
+    // Borrows from s, so cannot impl FromStr.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &'a str) -> Result<Self, Error> {
+        let tokens = s.split_whitespace().collect::<Vec<_>>();
+        let [direction, distance, colour] = tokens.as_slice() else {
+            return Err(Error::InvalidInstruction(s.to_string()));
+        };
+
+        let direction = direction
+            .parse()
+            .map_err(|_| Error::InvalidDirection(direction.to_string()))?;
+        let distance = parse_number(distance)?;
+
+        Ok(Self {
+            direction,
+            distance,
+            colour,
+        })
+    }
+
+    pub fn convert_from_colour(&self) -> Result<Self, Error> {
+        let (distance, direction) = self
+            .colour
+            .strip_prefix("(#")
+            .and_then(|hex| hex.strip_suffix(')'))
+            .filter(|hex| hex.len() == 6 && hex.is_ascii())
+            .map(|hex| hex.split_at(5))
+            .ok_or_else(|| Error::InvalidColour(self.colour.to_string()))?;
+
+        let direction = match direction {
+            "0" => Direction::Right,
+            "1" => Direction::Down,
+            "2" => Direction::Left,
+            "3" => Direction::Up,
+            _ => return Err(Error::InvalidColour(self.colour.to_string())),
+        };
+
+        let distance = isize::from_str_radix(distance, 16)
+            .map_err(|_| Error::InvalidColour(self.colour.to_string()))?;
+
+        Ok(Self {
+            direction,
+            distance,
+            colour: self.colour,
+        })
+    }
+
+    /// Gets the vertex the instruction digs to from vertex, failing on [Overflow].
+    pub fn dig(&self, vertex: Vector) -> Result<Vector, Overflow> {
+        let step = self.direction.as_vector();
+        let overflow = || Overflow("isize");
+
+        Ok(Vector {
+            row: step
+                .row
+                .checked_mul(self.distance)
+                .and_then(|row| vertex.row.checked_add(row))
+                .ok_or_else(overflow)?,
+            col: step
+                .col
+                .checked_mul(self.distance)
+                .and_then(|col| vertex.col.checked_add(col))
+                .ok_or_else(overflow)?,
+        })
+    }
+}
+
+pub fn convert_to_vertices(dig_plan: &[Instruction]) -> Result<Vec<Vector>, Error> {
+    let mut curr_vertex = Vector::default();
+    let mut vertices = vec![curr_vertex];
+    for instruction in dig_plan {
+        curr_vertex = instruction.dig(curr_vertex)?;
+
+        vertices.push(curr_vertex);
+    }
+
+    if vertices.first() != vertices.last() {
+        return Err(Error::NotClosed(vertices));
+    }
+
+    Ok(vertices)
+}
+
+pub fn area<A: Answer + TryFrom<isize> + TryFrom<usize>>(
+    vertices: &[Vector],
+) -> Result<A, Overflow> {
+    // As the coords are at the centre of each 1 x 1 sq unit, we also need to consider the 1/2-unit
+    // border along the perimeter.
+
+    // Use shoelace formula.
+    let mut shoelace = A::default();
+    for window in vertices.windows(2) {
+        shoelace = shoelace.try_add(shoelace_term(window[0], window[1])?)?;
+    }
+    let polygon_area = abs(shoelace)? / A::from(2);
+
+    // Imagine the top-left corner of a sq (it equally applies to 90-deg polygons):
+    //    a   b
+    //      |---
+    //    c | d
+    // We only need to consider a, b, and c:
+    // - If we sum up all the a, we get 1 unit for the whole polygon (4 corners for a sq).
+    // - If we sum up all the edges and then divide by 2, we account for b and c (the 1/2-unit that
+    //   is perpendicular to the perimeter).
+    let mut perimeter = A::default();
+    for window in vertices.windows(2) {
+        perimeter = perimeter.try_add(widen((window[1] - window[0]).manhattan_len())?)?;
+    }
+    let border_area = (perimeter / A::from(2)).try_add(A::from(1))?;
+
+    polygon_area.try_add(border_area)
+}
+
+/// Gets the term of the shoelace formula for the edge from a to b.
+pub fn shoelace_term<A: Answer + TryFrom<isize>>(a: Vector, b: Vector) -> Result<A, Overflow> {
+    widen::<A, _>(a.row)?
+        .try_mul(widen(b.col)?)?
+        .try_sub(widen::<A, _>(b.row)?.try_mul(widen(a.col)?)?)
+}
+
+/// Gets the absolute value of a signed [Answer].
+pub fn abs<A: Answer>(value: A) -> Result<A, Overflow> {
+    if value < A::default() {
+        A::default().try_sub(value)
+    } else {
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::trim_newlines;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+";
+
+    #[test]
+    fn example_1() -> Result<()> {
+        assert_eq!(part_1(trim_newlines(EXAMPLE))?, 62);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        assert_eq!(part_2(trim_newlines(EXAMPLE))?, 952408144115);
+
+        Ok(())
+    }
+
+    #[test]
+    fn short_colour() {
+        assert_eq!(
+            part_2("R 6 (#70c71)"),
+            Err(Error::InvalidColour(String::from("(#70c71)")))
+        );
+    }
+
+    #[test]
+    fn overflow() -> Result<()> {
+        let wide = "wide = true".parse()?;
+        let distance = 1u64 << 32;
+        let input = ["R", "D", "L", "U"]
+            .map(|direction| format!("{direction} {distance} (#000000)"))
+            .join("\n");
+
+        assert_eq!(part_1(&input), Err(Error::Overflow(Overflow("isize"))));
+        assert_eq!(part_1_as::<i128>(&input)?, (1 << 64) + (1 << 33) + 1);
+        assert_eq!(
+            stream::stream(&mut input.as_bytes(), 1, &wide)?,
+            part_1_as::<i128>(&input)?.to_string()
+        );
+
+        Ok(())
+    }
+}
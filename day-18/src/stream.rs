@@ -0,0 +1,59 @@
+use std::io::BufRead;
+
+use anyhow::{Result, anyhow};
+
+use cli::{
+    answer::{Answer, is_wide, widen},
+    config::Settings,
+    input::for_each_line,
+};
+use geometry::Vector;
+
+use crate::{Error, Instruction, abs, shoelace_term};
+
+/// Digs out the lagoon an instruction at a time, keeping running sums of the shoelace formula and
+/// the perimeter in place of the vertices. See [crate::area]. The area is in 128 bits if [is_wide].
+pub fn stream(reader: &mut dyn BufRead, part: u8, settings: &Settings) -> Result<String> {
+    if is_wide(settings)? {
+        Ok(stream_as::<i128>(reader, part)?.to_string())
+    } else {
+        Ok(stream_as::<isize>(reader, part)?.to_string())
+    }
+}
+
+/// Digs out the lagoon with the area in A.
+fn stream_as<A: Answer + TryFrom<isize> + TryFrom<usize>>(
+    reader: &mut dyn BufRead,
+    part: u8,
+) -> Result<A> {
+    if !(1..=2).contains(&part) {
+        return Err(anyhow!("Invalid part number: {}", part));
+    }
+
+    let mut curr_vertex = Vector::default();
+    let mut shoelace = A::default();
+    let mut perimeter = A::default();
+    for_each_line(reader, |line| {
+        let mut instruction = Instruction::from_str(line)?;
+        if part == 2 {
+            instruction = instruction.convert_from_colour()?;
+        }
+
+        let next_vertex = instruction.dig(curr_vertex)?;
+        shoelace = shoelace.try_add(shoelace_term(curr_vertex, next_vertex)?)?;
+        perimeter = perimeter.try_add(widen((next_vertex - curr_vertex).manhattan_len())?)?;
+        curr_vertex = next_vertex;
+
+        Ok(())
+    })?;
+
+    if curr_vertex != Vector::default() {
+        return Err(Error::NotClosed(vec![Vector::default(), curr_vertex]).into());
+    }
+
+    let area = (abs(shoelace)? / A::from(2))
+        .try_add(perimeter / A::from(2))?
+        .try_add(A::from(1))?;
+
+    Ok(area)
+}
@@ -0,0 +1,61 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind, NumberError, answer::Overflow};
+
+/// Why a day 19 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    #[error("Cannot split input into workflows and ratings: {0}")]
+    MissingRatings(String),
+
+    #[error("Cannot split input into id and rules: {0}")]
+    InvalidWorkflow(String),
+
+    #[error("Cannot split input into comparison and outcome: {0}")]
+    InvalidRule(String),
+
+    #[error("Cannot split input into categories and values: {0}")]
+    InvalidRating(String),
+
+    /// A category other than x, m, a and s.
+    #[error("Invalid category: {0}")]
+    InvalidCategory(String),
+
+    #[error("Invalid operator: {0}")]
+    InvalidOperator(String),
+
+    #[error(transparent)]
+    Number(#[from] NumberError),
+
+    /// A workflow that does not end in a rule that always applies.
+    #[error("Cannot get evaluation outcome of workflow {0}")]
+    NoOutcome(String),
+
+    #[error("Unknown workflow: {0}")]
+    UnknownWorkflow(String),
+
+    /// Workflows that send a part back to one already on its way.
+    #[error("Workflows loop: {0:?}")]
+    Loop(Vec<String>),
+
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::MissingRatings(_)
+            | Error::InvalidWorkflow(_)
+            | Error::InvalidRule(_)
+            | Error::InvalidRating(_)
+            | Error::InvalidCategory(_)
+            | Error::InvalidOperator(_)
+            | Error::Number(_) => ErrorKind::Parse,
+            Error::NoOutcome(_) | Error::UnknownWorkflow(_) | Error::Loop(_) => {
+                ErrorKind::Validation
+            }
+            Error::Overflow(_) => ErrorKind::Unsolvable,
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use std::collections::{HashSet, VecDeque};
+
+use rand::{Rng, rngs::StdRng};
+
+use cli::Generated;
+
+const CATEGORIES: [char; 4] = ['x', 'm', 'a', 's'];
+
+/// [x, m, a, s], each (min, max) inclusive.
+type Box = [(u64, u64); 4];
+
+/// (category, less than, operand, outcome)
+type Comparison = (usize, bool, u64, String);
+
+struct Workflow {
+    id: String,
+    comparisons: Vec<Comparison>,
+    fallback: String,
+}
+
+/// Generates a tree of about size workflows rooted at in, and size part ratings.
+///
+/// Each workflow is only reached from one rule, so part 2 is answered by narrowing down the ratings
+/// reaching each rule while building the tree, and adding up those that reach A.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let mut ids = HashSet::from([String::from("in"), String::from("A"), String::from("R")]);
+    let mut workflows = Vec::new();
+    let mut accepted = 0;
+
+    let mut queue = VecDeque::from([(String::from("in"), [(1, 4000); 4])]);
+    while let Some((id, mut ratings)) = queue.pop_front() {
+        let mut outcome = |rng: &mut StdRng, ratings: Box| {
+            if workflows.len() + queue.len() < size && rng.random_bool(0.6) {
+                let id = loop {
+                    let id = (0..rng.random_range(2..=3))
+                        .map(|_| rng.random_range(b'a'..=b'z') as char)
+                        .collect::<String>();
+                    if ids.insert(id.clone()) {
+                        break id;
+                    }
+                };
+                queue.push_back((id.clone(), ratings));
+
+                id
+            } else if rng.random_bool(0.5) {
+                accepted += ratings
+                    .iter()
+                    .map(|(min, max)| max - min + 1)
+                    .product::<u64>();
+
+                String::from("A")
+            } else {
+                String::from("R")
+            }
+        };
+
+        let mut comparisons = Vec::new();
+        for _ in 0..rng.random_range(1..=3) {
+            let category = rng.random_range(0..4);
+            let (min, max) = ratings[category];
+            if min == max {
+                continue;
+            }
+
+            let mut passed = ratings;
+            let less_than = rng.random_bool(0.5);
+            let operand = if less_than {
+                let operand = rng.random_range(min + 1..=max);
+                passed[category] = (min, operand - 1);
+                ratings[category] = (operand, max);
+                operand
+            } else {
+                let operand = rng.random_range(min..max);
+                passed[category] = (operand + 1, max);
+                ratings[category] = (min, operand);
+                operand
+            };
+            comparisons.push((category, less_than, operand, outcome(rng, passed)));
+        }
+        let fallback = outcome(rng, ratings);
+
+        workflows.push(Workflow {
+            id,
+            comparisons,
+            fallback,
+        });
+    }
+
+    let parts = (0..size)
+        .map(|_| std::array::from_fn::<u64, 4, _>(|_| rng.random_range(1..=4000)))
+        .collect::<Vec<_>>();
+    let sum_accepted = parts
+        .iter()
+        .filter(|part| is_accepted(part, &workflows))
+        .map(|part| part.iter().sum::<u64>())
+        .sum::<u64>();
+
+    let workflow_lines = workflows.iter().map(|workflow| {
+        let mut rules = workflow
+            .comparisons
+            .iter()
+            .map(|(category, less_than, operand, outcome)| {
+                let operator = if *less_than { '<' } else { '>' };
+                format!(
+                    "{}{}{}:{}",
+                    CATEGORIES[*category], operator, operand, outcome
+                )
+            })
+            .collect::<Vec<_>>();
+        rules.push(workflow.fallback.clone());
+
+        format!("{}{{{}}}", workflow.id, rules.join(","))
+    });
+    let part_lines = parts.iter().map(|part| {
+        format!(
+            "{{x={},m={},a={},s={}}}",
+            part[0], part[1], part[2], part[3]
+        )
+    });
+
+    Generated {
+        input: format!(
+            "{}\n\n{}",
+            workflow_lines.collect::<Vec<_>>().join("\n"),
+            part_lines.collect::<Vec<_>>().join("\n")
+        ),
+        answers: [Some(sum_accepted.to_string()), Some(accepted.to_string())],
+    }
+}
+
+fn is_accepted(part: &[u64; 4], workflows: &[Workflow]) -> bool {
+    let mut id = "in";
+    loop {
+        let workflow = workflows.iter().find(|workflow| workflow.id == id).unwrap();
+        id = workflow
+            .comparisons
+            .iter()
+            .find(|(category, less_than, operand, _)| {
+                if *less_than {
+                    part[*category] < *operand
+                } else {
+                    part[*category] > *operand
+                }
+            })
+            .map_or(&workflow.fallback, |comparison| &comparison.3);
+
+        match id {
+            "A" => return true,
+            "R" => return false,
+            _ => (),
+        }
+    }
+}
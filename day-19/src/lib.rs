@@ -0,0 +1,562 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::{Deref, RangeInclusive},
+    str::FromStr,
+};
+
+use serde::Serialize;
+
+use cli::{
+    Solver,
+    answer::{Answer, Overflow, try_sum, with_width},
+    parse_number, to_json,
+};
+
+pub use error::Error;
+
+mod error;
+pub mod generator;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 19,
+    version: env!("SOLVER_VERSION"),
+    part_1: |input, settings| with_width(settings, || part_1(input), || part_1_as::<u128>(input)),
+    // Every combination has values from 1 to 4000 in each of 4 categories, so there are at most
+    // 4000^4 of them, well within u64.
+    part_2: Some(|input, _| Ok(part_2(input)?.to_string())),
+    generator: generator::generate,
+    render: None,
+    animate: None,
+    dump: Some(|input| to_json(&parse_input_into_workflows_and_ratings(input)?)),
+    stream: None,
+    certify: None,
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<u64, Error> {
+    part_1_as(input)
+}
+
+/// Sums the ratings of the accepted parts in A.
+pub fn part_1_as<A: Answer + From<u64>>(input: &str) -> Result<A, Error> {
+    let (workflows, ratings) = parse_input_into_workflows_and_ratings(input)?;
+
+    let sums = ratings
+        .into_iter()
+        .filter(|rating| accept_part(rating, &workflows))
+        .map(|rating| rating.sum())
+        .collect::<Result<Vec<A>, _>>()?;
+
+    Ok(try_sum(sums)?)
+}
+
+pub fn part_2(input: &str) -> Result<u64, Error> {
+    let (workflows, _) = parse_input_into_workflows_and_ratings(input)?;
+
+    Ok(count_combinations(
+        std::array::from_fn(|_| vec![1..=4000]), // defaults to all possible values
+        &workflows["in"].rules,
+        &workflows,
+    ))
+}
+
+#[derive(Clone, Copy, Serialize)]
+pub struct Rating {
+    pub categories: [u64; 4],
+}
+
+impl Deref for Rating {
+    type Target = [u64];
+
+    fn deref(&self) -> &Self::Target {
+        &self.categories
+    }
+}
+
+impl FromStr for Rating {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut categories = [0; 4];
+
+        for token in s
+            .strip_prefix("{")
+            .and_then(|s| s.strip_suffix("}"))
+            .map(|s| s.split_terminator(",").collect::<Vec<_>>())
+            .ok_or_else(|| Error::InvalidRating(s.to_string()))?
+        {
+            let Some((category, value)) = token.split_once("=") else {
+                return Err(Error::InvalidRating(token.to_string()));
+            };
+            let value = parse_number(value)?;
+
+            match category {
+                "x" => categories[0] = value,
+                "m" => categories[1] = value,
+                "a" => categories[2] = value,
+                "s" => categories[3] = value,
+                _ => return Err(Error::InvalidCategory(category.to_string())),
+            }
+        }
+
+        Ok(Self { categories })
+    }
+}
+
+impl Rating {
+    /// Sums the categories in A.
+    pub fn sum<A: Answer + From<u64>>(&self) -> Result<A, Overflow> {
+        try_sum(self.categories.map(A::from))
+    }
+}
+
+#[derive(Clone, Copy, Serialize)]
+pub enum Rule<'a> {
+    Comparison {
+        category: usize,
+        operator: &'a str,
+        operand: u64,
+        outcome: &'a str,
+    },
+    Immediate(&'a str),
+}
+
+impl<'a> Rule<'a> {
+    // Borrows from s, so cannot impl FromStr.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &'a str) -> Result<Self, Error> {
+        if !s.contains(":") {
+            return Ok(Self::Immediate(s));
+        }
+
+        let Some((comparison, outcome)) = s.split_once(":") else {
+            return Err(Error::InvalidRule(s.to_string()));
+        };
+
+        let Some((category, comparison)) = comparison.split_at_checked(1) else {
+            return Err(Error::InvalidRule(s.to_string()));
+        };
+        let category = match category {
+            "x" => 0,
+            "m" => 1,
+            "a" => 2,
+            "s" => 3,
+            _ => return Err(Error::InvalidCategory(category.to_string())),
+        };
+
+        let Some((operator, operand)) = comparison.split_at_checked(1) else {
+            return Err(Error::InvalidRule(s.to_string()));
+        };
+        if !"<>".contains(operator) {
+            return Err(Error::InvalidOperator(operator.to_string()));
+        }
+        let operand = parse_number(operand)?;
+
+        Ok(Self::Comparison {
+            category,
+            operator,
+            operand,
+            outcome,
+        })
+    }
+
+    pub fn evaluate(&self, rating: &Rating) -> Option<&'a str> {
+        match self {
+            Rule::Comparison {
+                category,
+                operator,
+                operand,
+                outcome,
+            } => match *operator {
+                "<" => {
+                    if &rating[*category] < operand {
+                        Some(outcome)
+                    } else {
+                        None
+                    }
+                }
+                ">" => {
+                    if &rating[*category] > operand {
+                        Some(outcome)
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            },
+            Rule::Immediate(outcome) => Some(outcome),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct Workflow<'a> {
+    pub id: &'a str,
+    pub rules: Vec<Rule<'a>>,
+}
+
+impl<'a> Workflow<'a> {
+    // Borrows from s, so cannot impl FromStr.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &'a str) -> Result<Self, Error> {
+        let Some((id, rules)) = s.strip_suffix("}").and_then(|s| s.split_once("{")) else {
+            return Err(Error::InvalidWorkflow(s.to_string()));
+        };
+
+        let rules = rules
+            .split_terminator(",")
+            .map(Rule::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+        if !matches!(rules.last(), Some(Rule::Immediate(_))) {
+            return Err(Error::NoOutcome(id.to_string()));
+        }
+
+        Ok(Self { id, rules })
+    }
+
+    pub fn evaluate(&self, rating: &Rating) -> Result<&'a str, Error> {
+        self.rules
+            .iter()
+            .fold(None, |acc, rule| acc.or_else(|| rule.evaluate(rating)))
+            .ok_or_else(|| Error::NoOutcome(self.id.to_string()))
+    }
+}
+
+pub fn parse_input_into_workflows_and_ratings(
+    input: &str,
+) -> Result<(HashMap<&str, Workflow<'_>>, Vec<Rating>), Error> {
+    let Some((workflows, ratings)) = input.split_once("\n\n") else {
+        return Err(Error::MissingRatings(input.to_string()));
+    };
+
+    let workflows = workflows
+        .lines()
+        .map(|line| {
+            let workflow = Workflow::from_str(line)?;
+
+            Ok((workflow.id, workflow))
+        })
+        .collect::<Result<HashMap<_, _>, Error>>()?;
+    check_workflows("in", &workflows, &mut Vec::new(), &mut HashSet::new())?;
+    let ratings = ratings
+        .lines()
+        .map(Rating::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((workflows, ratings))
+}
+
+/// Checks that every workflow reachable from id exists and that none of them loop, so that they
+/// can be indexed and followed without checks.
+fn check_workflows<'a>(
+    id: &'a str,
+    workflows: &HashMap<&str, Workflow<'a>>,
+    path: &mut Vec<&'a str>,
+    checked: &mut HashSet<&'a str>,
+) -> Result<(), Error> {
+    if matches!(id, "A" | "R") || checked.contains(id) {
+        return Ok(());
+    }
+    if path.contains(&id) {
+        path.push(id);
+        return Err(Error::Loop(path.iter().map(|id| id.to_string()).collect()));
+    }
+
+    let workflow = workflows
+        .get(id)
+        .ok_or_else(|| Error::UnknownWorkflow(id.to_string()))?;
+    path.push(id);
+    for rule in &workflow.rules {
+        let (Rule::Comparison { outcome, .. } | Rule::Immediate(outcome)) = rule;
+        check_workflows(outcome, workflows, path, checked)?;
+    }
+    path.pop();
+    checked.insert(id);
+
+    Ok(())
+}
+
+pub fn accept_part(rating: &Rating, workflows: &HashMap<&str, Workflow>) -> bool {
+    let mut workflow_id = "in";
+    loop {
+        match workflows[workflow_id].evaluate(rating) {
+            Ok(outcome) => match outcome {
+                "A" => return true,
+                "R" => return false,
+                _ => workflow_id = outcome,
+            },
+            Err(_) => return false,
+        }
+    }
+}
+
+/// [x_ranges, m_ranges, a_ranges, s_ranges], where the values of a category must lie in all of
+/// its ranges.
+pub type CategoryRanges = [Vec<RangeInclusive<u64>>; 4];
+
+/// Counts the total number of combinations of category values that results in an accepted part.
+pub fn count_combinations(
+    mut category_ranges: CategoryRanges,
+    rules: &[Rule],
+    workflows: &HashMap<&str, Workflow>,
+) -> u64 {
+    // The recursion is always terminated by Rule::Immediate("A"), Rule::Immediate("R"), or a
+    // Rule::Comparison with an outcome of "A" or "R".
+    if rules.is_empty() {
+        unreachable!();
+    }
+
+    match rules[0] {
+        Rule::Immediate("R") => 0,
+        Rule::Immediate("A") => calculate_combinations(category_ranges),
+        Rule::Immediate(workflow_id) => {
+            count_combinations(category_ranges, &workflows[workflow_id].rules, workflows)
+        }
+        Rule::Comparison {
+            category,
+            outcome: "R",
+            ..
+        } => {
+            category_ranges[category].push(get_range_from_rule(&rules[0], false));
+            count_combinations(category_ranges, &rules[1..], workflows)
+        }
+        Rule::Comparison {
+            category,
+            outcome: "A",
+            ..
+        } => {
+            let mut success_category_ranges = category_ranges.clone();
+            success_category_ranges[category].push(get_range_from_rule(&rules[0], true));
+            let success_combinations = calculate_combinations(success_category_ranges);
+
+            category_ranges[category].push(get_range_from_rule(&rules[0], false));
+            success_combinations + count_combinations(category_ranges, &rules[1..], workflows)
+        }
+        Rule::Comparison {
+            category, outcome, ..
+        } => {
+            let mut success_rating_ranges = category_ranges.clone();
+            success_rating_ranges[category].push(get_range_from_rule(&rules[0], true));
+            let success_combinations = count_combinations(
+                success_rating_ranges.clone(),
+                &workflows[outcome].rules,
+                workflows,
+            );
+
+            category_ranges[category].push(get_range_from_rule(&rules[0], false));
+            success_combinations + count_combinations(category_ranges, &rules[1..], workflows)
+        }
+    }
+}
+
+/// Derives the input range so that the given [Rule] evaluates to evaluates_to.
+///
+/// The category of the input is assumed to be the same as the category in [Rule::Comparison].
+fn get_range_from_rule(rule: &Rule, evaluates_to: bool) -> RangeInclusive<u64> {
+    match rule {
+        Rule::Comparison {
+            operator, operand, ..
+        } => match (*operator, evaluates_to) {
+            ("<", true) => 1..=operand.saturating_sub(1),
+            ("<", false) => *operand..=4000,
+            (">", true) => operand.saturating_add(1)..=4000,
+            (">", false) => 1..=*operand,
+            _ => 1..=4000,
+        },
+        Rule::Immediate(_) => 1..=4000,
+    }
+}
+
+/// Calculates the number of combinations that is represented by the given [CategoryRanges].
+fn calculate_combinations(rating_ranges: CategoryRanges) -> u64 {
+    rating_ranges
+        .iter()
+        .map(|category| count_values_in_intersection(category))
+        .product()
+}
+
+/// Counts the values that lie in every one of the ranges. The ranges of a category always
+/// intersect in a single range, which may be empty if the rules contradict each other.
+fn count_values_in_intersection(ranges: &[RangeInclusive<u64>]) -> u64 {
+    let start = ranges.iter().map(|range| *range.start()).max().unwrap_or(1);
+    let end = ranges
+        .iter()
+        .map(|range| *range.end())
+        .min()
+        .unwrap_or(4000);
+
+    (end + 1).saturating_sub(start)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::trim_newlines;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+";
+
+    #[test]
+    fn overflow() -> Result<()> {
+        let input = "in{A}\n\n{x=18446744073709551615,m=1,a=0,s=0}";
+
+        assert_eq!(
+            part_1(input),
+            Err(Error::Overflow(cli::answer::Overflow("u64")))
+        );
+        assert_eq!(part_1_as::<u128>(input)?, 1 << 64);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_1() -> Result<()> {
+        assert_eq!(part_1(trim_newlines(EXAMPLE))?, 19114);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        assert_eq!(part_2(trim_newlines(EXAMPLE))?, 167409079868000);
+
+        Ok(())
+    }
+
+    /// Every category value is scaled down from 1..=4000 to 1..=MAX_VALUE, so that every rating
+    /// can be tried.
+    const MAX_VALUE: u64 = 6;
+
+    /// (category, operator, operand, outcome) of each rule, then the outcome of the final rule.
+    type WorkflowSpec = (Vec<(usize, bool, u64, usize)>, usize);
+
+    /// Builds the workflows section of an input, where an outcome of 0 is "A", 1 is "R", and k is
+    /// the workflow k - 1 places after the current one. Workflows only send parts forwards, so
+    /// every part is eventually accepted or rejected.
+    fn as_input(specs: &[WorkflowSpec]) -> String {
+        let id = |index: usize| {
+            if index == 0 {
+                "in".to_string()
+            } else {
+                format!("w{index}")
+            }
+        };
+        let outcome = |index: usize, outcome: usize| match outcome {
+            0 => "A".to_string(),
+            1 => "R".to_string(),
+            _ if index + outcome - 1 < specs.len() => id(index + outcome - 1),
+            _ => ["A", "R"][outcome % 2].to_string(),
+        };
+
+        specs
+            .iter()
+            .enumerate()
+            .map(|(index, (rules, fallback))| {
+                let rules = rules
+                    .iter()
+                    .map(|&(category, less_than, operand, next)| {
+                        format!(
+                            "{}{}{}:{}",
+                            ["x", "m", "a", "s"][category],
+                            if less_than { "<" } else { ">" },
+                            operand,
+                            outcome(index, next)
+                        )
+                    })
+                    .chain([outcome(index, *fallback)])
+                    .collect::<Vec<_>>();
+
+                format!("{}{{{}}}", id(index), rules.join(","))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+            + "\n\n"
+    }
+
+    fn count_combinations_naive(workflows: &HashMap<&str, Workflow>) -> u64 {
+        let values = || 1..=MAX_VALUE;
+        let mut count = 0;
+        for x in values() {
+            for m in values() {
+                for a in values() {
+                    for s in values() {
+                        let rating = Rating {
+                            categories: [x, m, a, s],
+                        };
+                        if accept_part(&rating, workflows) {
+                            count += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        count
+    }
+
+    fn workflow_specs() -> impl Strategy<Value = Vec<WorkflowSpec>> {
+        let rule = (0..4usize, any::<bool>(), 1..=MAX_VALUE, 0..5usize);
+        let workflow = (prop::collection::vec(rule, 0..=3), 0..5usize);
+
+        prop::collection::vec(workflow, 1..=5)
+    }
+
+    proptest! {
+        #[test]
+        fn count_combinations_matches_naive(specs in workflow_specs()) {
+            let input = as_input(&specs);
+            let (workflows, _) = parse_input_into_workflows_and_ratings(&input).unwrap();
+
+            prop_assert_eq!(
+                count_combinations(
+                    std::array::from_fn(|_| vec![1..=MAX_VALUE]),
+                    &workflows["in"].rules,
+                    &workflows,
+                ),
+                count_combinations_naive(&workflows)
+            );
+        }
+    }
+
+    #[test]
+    fn unsound_workflows() {
+        assert_eq!(
+            part_1("px{a<2006:qkq,R}\n\n{x=787,m=2655,a=1222,s=2876}"),
+            Err(Error::UnknownWorkflow(String::from("in")))
+        );
+        assert_eq!(
+            part_2("in{a<2006:px,R}\npx{m>10:in,A}\n\n"),
+            Err(Error::Loop(vec![
+                String::from("in"),
+                String::from("px"),
+                String::from("in")
+            ]))
+        );
+        assert_eq!(
+            part_2("in{a<2006:px}\n\n"),
+            Err(Error::NoOutcome(String::from("in")))
+        );
+    }
+}
@@ -0,0 +1,29 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind, NumberError, answer::Overflow};
+
+/// Why a day 2 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    #[error("Cannot split input into id and draws: {0}")]
+    InvalidGame(String),
+
+    /// Cubes that are not a count followed by red, green or blue.
+    #[error("Invalid cubes: {0}")]
+    InvalidCubes(String),
+
+    #[error(transparent)]
+    Number(#[from] NumberError),
+
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::InvalidGame(_) | Error::InvalidCubes(_) | Error::Number(_) => ErrorKind::Parse,
+            Error::Overflow(_) => ErrorKind::Unsolvable,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use rand::{Rng, rngs::StdRng, seq::SliceRandom};
+
+use cli::Generated;
+
+const COLOURS: [&str; 3] = ["red", "green", "blue"];
+
+/// Generates size games of 1 to 6 draws, each showing some of the colours in any order.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let mut lines = Vec::new();
+    let (mut sum_ids, mut sum_powers) = (0, 0);
+    for id in 1..=size as u32 {
+        let mut draws = Vec::new();
+        let mut max_cubes = [0; 3];
+        for _ in 0..rng.random_range(1..=6) {
+            let mut colours = vec![0, 1, 2];
+            colours.shuffle(rng);
+            colours.truncate(rng.random_range(1..=3));
+
+            let cubes = colours
+                .into_iter()
+                .map(|colour| {
+                    let num = rng.random_range(1..=20);
+                    max_cubes[colour] = max_cubes[colour].max(num);
+
+                    format!("{} {}", num, COLOURS[colour])
+                })
+                .collect::<Vec<_>>();
+            draws.push(cubes.join(", "));
+        }
+
+        if max_cubes[0] <= 12 && max_cubes[1] <= 13 && max_cubes[2] <= 14 {
+            sum_ids += id;
+        }
+        sum_powers += max_cubes.iter().product::<u32>();
+        lines.push(format!("Game {}: {}", id, draws.join("; ")));
+    }
+
+    Generated {
+        input: lines.join("\n"),
+        answers: [Some(sum_ids.to_string()), Some(sum_powers.to_string())],
+    }
+}
@@ -0,0 +1,194 @@
+use std::{cmp::max, str::FromStr};
+
+use serde::Serialize;
+
+use cli::{
+    Solver,
+    answer::{Answer, try_sum, with_width},
+    dump_lines, parse_number,
+};
+
+pub use error::Error;
+
+mod error;
+pub mod generator;
+pub mod stream;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 2,
+    version: env!("SOLVER_VERSION"),
+    part_1: |input, settings| with_width(settings, || part_1(input), || part_1_as::<u128>(input)),
+    part_2: Some(|input, settings| {
+        with_width(settings, || part_2(input), || part_2_as::<u128>(input))
+    }),
+    generator: generator::generate,
+    render: None,
+    animate: None,
+    dump: Some(|input| dump_lines(input, Game::from_str)),
+    stream: Some(stream::stream),
+    certify: None,
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<u32, Error> {
+    part_1_as(input)
+}
+
+pub fn part_2(input: &str) -> Result<u32, Error> {
+    part_2_as(input)
+}
+
+/// Sums the ids of the possible games in A.
+pub fn part_1_as<A: Answer + From<u32>>(input: &str) -> Result<A, Error> {
+    let games = input
+        .lines()
+        .map(Game::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(try_sum(
+        games
+            .into_iter()
+            .filter(|game| game.is_possible(12, 13, 14))
+            .map(|game| A::from(game.id)),
+    )?)
+}
+
+/// Sums the powers of the games in A.
+pub fn part_2_as<A: Answer + From<u32>>(input: &str) -> Result<A, Error> {
+    let games = input
+        .lines()
+        .map(Game::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(try_sum(games.iter().map(|game| A::from(game.power())))?)
+}
+
+/// [red, green, blue]
+pub type Draw = [u8; 3];
+
+#[derive(Serialize)]
+pub struct Game {
+    pub id: u32,
+    pub draws: Vec<Draw>,
+}
+
+impl FromStr for Game {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let Some((id, draws)) = s.strip_prefix("Game ").and_then(|s| s.split_once(": ")) else {
+            return Err(Error::InvalidGame(s.to_string()));
+        };
+        let id = parse_number(id)?;
+
+        let draws = draws
+            .split_terminator("; ")
+            .map(|token| {
+                let mut draw = [0; 3];
+
+                for token in token.split_terminator(", ") {
+                    match token {
+                        s if s.ends_with("red") => {
+                            draw[0] = parse_number(
+                                s.strip_suffix(" red")
+                                    .ok_or_else(|| Error::InvalidCubes(s.to_string()))?,
+                            )?;
+                        }
+                        s if s.ends_with("green") => {
+                            draw[1] = parse_number(
+                                s.strip_suffix(" green")
+                                    .ok_or_else(|| Error::InvalidCubes(s.to_string()))?,
+                            )?;
+                        }
+                        s if s.ends_with("blue") => {
+                            draw[2] = parse_number(
+                                s.strip_suffix(" blue")
+                                    .ok_or_else(|| Error::InvalidCubes(s.to_string()))?,
+                            )?;
+                        }
+                        _ => return Err(Error::InvalidCubes(token.to_string())),
+                    }
+                }
+
+                Ok(draw)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { id, draws })
+    }
+}
+
+impl Game {
+    pub fn is_possible(&self, red: u8, green: u8, blue: u8) -> bool {
+        self.draws
+            .iter()
+            .all(|draw| draw[0] <= red && draw[1] <= green && draw[2] <= blue)
+    }
+
+    pub fn power(&self) -> u32 {
+        self.draws
+            .iter()
+            .fold([0; 3], |mut acc, draw| {
+                acc[0] = max(acc[0], draw[0] as u32);
+                acc[1] = max(acc[1], draw[1] as u32);
+                acc[2] = max(acc[2], draw[2] as u32);
+
+                acc
+            })
+            .into_iter()
+            .product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::trim_newlines;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    #[test]
+    fn example_1() -> Result<()> {
+        assert_eq!(part_1(trim_newlines(EXAMPLE))?, 8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        assert_eq!(part_2(trim_newlines(EXAMPLE))?, 2286);
+
+        Ok(())
+    }
+
+    #[test]
+    fn overflow() -> Result<()> {
+        let wide = "wide = true".parse()?;
+        let input = (1..=300)
+            .map(|id| format!("Game {id}: 255 red, 255 green, 255 blue"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert_eq!(
+            part_2(&input),
+            Err(Error::Overflow(cli::answer::Overflow("u32")))
+        );
+        assert_eq!(part_2_as::<u128>(&input)?, 300 * 255 * 255 * 255);
+        assert_eq!(
+            stream::stream(&mut input.as_bytes(), 2, &wide)?,
+            part_2_as::<u128>(&input)?.to_string()
+        );
+
+        Ok(())
+    }
+}
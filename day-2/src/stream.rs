@@ -0,0 +1,42 @@
+use std::{io::BufRead, str::FromStr};
+
+use anyhow::{Result, anyhow};
+
+use cli::{
+    answer::{Answer, is_wide},
+    config::Settings,
+    input::for_each_line,
+};
+
+use crate::Game;
+
+/// Sums the ids of the possible games, or the powers of every game, a game at a time, in 128 bits
+/// if [is_wide].
+pub fn stream(reader: &mut dyn BufRead, part: u8, settings: &Settings) -> Result<String> {
+    if is_wide(settings)? {
+        Ok(stream_as::<u128>(reader, part)?.to_string())
+    } else {
+        Ok(stream_as::<u32>(reader, part)?.to_string())
+    }
+}
+
+/// Sums in A a game at a time.
+fn stream_as<A: Answer + From<u32>>(reader: &mut dyn BufRead, part: u8) -> Result<A> {
+    if !(1..=2).contains(&part) {
+        return Err(anyhow!("Invalid part number: {}", part));
+    }
+
+    let mut sum = A::default();
+    for_each_line(reader, |line| {
+        let game = Game::from_str(line)?;
+        sum = sum.try_add(A::from(match part {
+            1 if game.is_possible(12, 13, 14) => game.id,
+            1 => 0,
+            _ => game.power(),
+        }))?;
+
+        Ok(())
+    })?;
+
+    Ok(sum)
+}
@@ -0,0 +1,131 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use anyhow::{Result, anyhow};
+
+use cli::animate::{Frame, Frames};
+
+use crate::{Module, Pulse, find_source_of_rx, parse_input_into_modules};
+
+/// Plays back the pulse cascade a pulse at a time. Part 1 presses the button 1000 times, while
+/// part 2 presses it until each source of the conjunction before rx has sent it a high pulse.
+pub fn animate(input: &str, part: u8) -> Result<Frames<'_>> {
+    let mut modules = parse_input_into_modules(input)?;
+    let (presses, track_dst, mut track_src) = match part {
+        1 => (1000, "", HashSet::new()),
+        2 => {
+            let (track_dst, track_src) = find_source_of_rx(&modules)?;
+            (u64::MAX, track_dst, track_src)
+        }
+        _ => return Err(anyhow!("Invalid part number: {}", part)),
+    };
+
+    let mut ids = modules.keys().copied().collect::<Vec<_>>();
+    ids.sort();
+    let mut pulses = VecDeque::new();
+    let mut press = 0;
+    let mut log = [0; 2];
+
+    Ok(Box::new(std::iter::from_fn(move || {
+        if pulses.is_empty() {
+            if press == presses || (part == 2 && track_src.is_empty()) {
+                return None;
+            }
+
+            press += 1;
+            pulses.push_back(("button", "broadcaster", false));
+        }
+
+        let pulse = pulses.pop_front()?;
+        let (src, dst, value) = pulse;
+        log[value as usize] += 1;
+        if value && dst == track_dst {
+            track_src.remove(src);
+        }
+        if let Some(module) = modules.get_mut(dst) {
+            pulses.extend(module.pulse(pulse));
+        }
+
+        Some(frame(press, pulse, log, &ids, &modules))
+    })))
+}
+
+/// Lists the state of every [Module] after the pulse, marking the [Module] that received it.
+fn frame(
+    press: u64,
+    pulse: Pulse,
+    log: [u64; 2],
+    ids: &[&str],
+    modules: &HashMap<&str, Module>,
+) -> Frame {
+    let (src, dst, value) = pulse;
+    let level = |value: bool| if value { "high" } else { "low" };
+
+    let text = ids
+        .iter()
+        .map(|id| {
+            let marker = if *id == dst { ">" } else { " " };
+            let state = match &modules[id] {
+                Module::FlipFlop { on, .. } => {
+                    format!("%{} {}", id, if *on { "on" } else { "off" })
+                }
+                Module::Conjunction { src, .. } => {
+                    let mut memory = src
+                        .iter()
+                        .map(|(src, value)| format!("{}={}", src, level(*value)))
+                        .collect::<Vec<_>>();
+                    memory.sort();
+
+                    format!("&{} {}", id, memory.join(" "))
+                }
+                Module::Broadcaster { .. } | Module::Output => id.to_string(),
+            };
+
+            format!("{marker} {state}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Frame::new(
+        format!(
+            "Press {}: {} -{}-> {} ({} low, {} high)",
+            press,
+            src,
+            level(value),
+            dst,
+            log[0],
+            log[1]
+        ),
+        text,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::trim_newlines;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output
+";
+
+    #[test]
+    fn pulse_cascade() -> Result<()> {
+        let frames = animate(trim_newlines(EXAMPLE), 1)?.collect::<Vec<_>>();
+
+        assert_eq!(
+            frames[0].caption,
+            "Press 1: button -low-> broadcaster (1 low, 0 high)"
+        );
+        assert_eq!(
+            frames[frames.len() - 1].caption,
+            "Press 1000: con -high-> output (4250 low, 2750 high)"
+        );
+
+        Ok(())
+    }
+}
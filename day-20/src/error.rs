@@ -0,0 +1,51 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind, answer::Overflow};
+
+/// Why a day 20 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    #[error("Cannot split input into ID and destinations: {0}")]
+    InvalidModule(String),
+
+    /// An ID that is neither broadcaster nor prefixed by % or &.
+    #[error("Invalid module ID: {0}")]
+    InvalidModuleId(String),
+
+    #[error("Cannot find broadcaster")]
+    NoBroadcaster,
+
+    /// Part 2 relies on rx being fed by a single conjunction.
+    #[error("Cannot find source of rx")]
+    NoSourceOfRx,
+
+    /// Sources of the conjunction before rx that never send it a high pulse.
+    #[error("No high pulse from {src:?} after {presses} button presses")]
+    NoHighPulse { src: Vec<String>, presses: u64 },
+
+    /// The sources of the conjunction do not cycle in a way that makes the lcm of their periods
+    /// the answer to part 2.
+    #[error("{src} is not periodic with period {period}: cycle of {cycle_len} after {prefix_len}")]
+    NotPeriodic {
+        src: String,
+        period: u64,
+        prefix_len: usize,
+        cycle_len: usize,
+    },
+
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::InvalidModule(_) | Error::InvalidModuleId(_) => ErrorKind::Parse,
+            Error::NoBroadcaster => ErrorKind::Validation,
+            Error::NoSourceOfRx
+            | Error::NoHighPulse { .. }
+            | Error::NotPeriodic { .. }
+            | Error::Overflow(_) => ErrorKind::Unsolvable,
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use rand::{Rng, rngs::StdRng};
+
+use cli::Generated;
+
+/// (type, destinations), where type is '%', '&' or 'b' for the broadcaster.
+type Module = (char, Vec<String>);
+
+/// Generates size.clamp(1, 8) binary counters, each sending a high pulse to a hub once every N
+/// button presses, for an odd N with up to 12 bits. The hub then sends to rx.
+///
+/// Each counter chains flip-flops for its bits. The bits set in N feed a conjunction that resets the
+/// counter, by sending a pulse to the bits not set in N and to the lowest bit. Part 2 is the lcm of
+/// the N, by construction. Part 1 is answered by a separate simulation.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let mut used = HashSet::from([String::from("rx")]);
+    let mut id = |rng: &mut StdRng| loop {
+        let id = (0..2)
+            .map(|_| rng.random_range(b'a'..=b'z') as char)
+            .collect::<String>();
+        if used.insert(id.clone()) {
+            return id;
+        }
+    };
+
+    let hub = id(rng);
+    let mut modules = HashMap::from([
+        (String::from("broadcaster"), ('b', Vec::new())),
+        (hub.clone(), ('&', vec![String::from("rx")])),
+    ]);
+    let mut periods = Vec::new();
+    for _ in 0..size.clamp(1, 8) {
+        let bits = rng.random_range(4..=12);
+        let period = rng.random_range(1u64 << (bits - 1)..1 << bits) | 1;
+
+        let flip_flops = (0..bits).map(|_| id(rng)).collect::<Vec<_>>();
+        let (reset, inverter) = (id(rng), id(rng));
+
+        let mut reset_dst = vec![flip_flops[0].clone()];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut dst = flip_flops
+                .get(bit + 1)
+                .into_iter()
+                .cloned()
+                .collect::<Vec<_>>();
+            if period & (1 << bit) != 0 {
+                dst.push(reset.clone());
+            } else {
+                reset_dst.push(flip_flop.clone());
+            }
+            modules.insert(flip_flop.clone(), ('%', dst));
+        }
+        reset_dst.push(inverter.clone());
+
+        modules.insert(reset, ('&', reset_dst));
+        modules.insert(inverter, ('&', vec![hub.clone()]));
+        modules
+            .get_mut("broadcaster")
+            .unwrap()
+            .1
+            .push(flip_flops[0].clone());
+        periods.push(period);
+    }
+
+    let mut lines = modules
+        .iter()
+        .map(|(id, (kind, dst))| {
+            let id = match kind {
+                'b' => id.clone(),
+                _ => format!("{kind}{id}"),
+            };
+            format!("{} -> {}", id, dst.join(", "))
+        })
+        .collect::<Vec<_>>();
+    lines.sort();
+
+    let lcm = periods
+        .iter()
+        .fold(1, |acc, &period| acc / gcd(acc, period) * period);
+
+    Generated {
+        input: lines.join("\n"),
+        answers: [
+            Some(count_pulses(&modules).to_string()),
+            Some(lcm.to_string()),
+        ],
+    }
+}
+
+/// Presses the button 1000 times, tracking the state of every module in its own map.
+fn count_pulses(modules: &HashMap<String, Module>) -> u64 {
+    let mut on = HashSet::new();
+    let mut memory: HashMap<&str, HashMap<&str, bool>> = HashMap::new();
+    for (id, (_, dst)) in modules {
+        for dst in dst {
+            memory.entry(dst).or_default().insert(id, false);
+        }
+    }
+
+    let (mut low, mut high) = (0, 0);
+    for _ in 0..1000 {
+        let mut pulses = VecDeque::from([("button", "broadcaster", false)]);
+        while let Some((src, id, pulse)) = pulses.pop_front() {
+            if pulse {
+                high += 1;
+            } else {
+                low += 1;
+            }
+
+            let Some((kind, dst)) = modules.get_key_value(id).map(|(_, module)| module) else {
+                continue;
+            };
+            let send = match kind {
+                'b' => pulse,
+                '%' if pulse => continue,
+                '%' => {
+                    if !on.remove(id) {
+                        on.insert(id);
+                    }
+                    on.contains(id)
+                }
+                _ => {
+                    let memory = memory.get_mut(id).unwrap();
+                    memory.insert(src, pulse);
+                    !memory.values().all(|high| *high)
+                }
+            };
+            pulses.extend(dst.iter().map(|dst| (id, dst.as_str(), send)));
+        }
+    }
+
+    low * high
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
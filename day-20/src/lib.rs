@@ -0,0 +1,464 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use serde::Serialize;
+
+use cli::{
+    Solver,
+    answer::{Answer, try_lcm, try_product, with_width},
+    to_json,
+};
+use cycle::{Cycle, find_cycle_brent};
+
+pub use error::Error;
+
+pub mod animate;
+mod error;
+pub mod generator;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 20,
+    version: env!("SOLVER_VERSION"),
+    part_1: |input, settings| with_width(settings, || part_1(input), || part_1_as::<u128>(input)),
+    part_2: Some(|input, settings| {
+        with_width(settings, || part_2(input), || part_2_as::<u128>(input))
+    }),
+    generator: generator::generate,
+    render: None,
+    animate: Some(animate::animate),
+    dump: Some(|input| to_json(&parse_input_into_modules(input)?)),
+    stream: None,
+    certify: None,
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<u64, Error> {
+    part_1_as(input)
+}
+
+/// Multiplies the counts of low and high pulses in A.
+pub fn part_1_as<A: Answer + From<u64>>(input: &str) -> Result<A, Error> {
+    let mut modules = parse_input_into_modules(input)?;
+
+    let mut log = [0; 2];
+    for _ in 0..1000 {
+        button_pulse(&mut modules, &mut log);
+    }
+
+    Ok(try_product(log.map(A::from))?)
+}
+
+pub fn part_2(input: &str) -> Result<u64, Error> {
+    part_2_as(input)
+}
+
+/// Finds the lcm of the periods of the sources of rx in A.
+pub fn part_2_as<A: Answer + From<u64>>(input: &str) -> Result<A, Error> {
+    // rx has only 1 source, which turns out to be a conjunction with 4 sources. Assuming the latter
+    // 4 sources signal high at different periods, we need to find the lcm at which they coincide.
+
+    let mut modules = parse_input_into_modules(input)?;
+
+    let (track_dst, mut track_src) = find_source_of_rx(&modules)?;
+    let cycles = track_src
+        .iter()
+        .map(|src| (*src, find_upstream_cycle(src, &modules)))
+        .collect::<HashMap<_, _>>();
+    let max_presses = cycles
+        .iter()
+        .map(|(src, cycle)| (*src, (cycle.prefix_len + cycle.cycle_len) as u64))
+        .collect();
+    let periods = track_high_signals(&mut modules, track_dst, &mut track_src, &max_presses)?;
+    for (src, period) in &periods {
+        check_periodic(src, *period, &cycles[src])?;
+    }
+
+    Ok(periods
+        .into_values()
+        .try_fold(A::from(1u64), |lcm, period| try_lcm(lcm, A::from(period)))?)
+}
+
+/// Finds the conjunction that is the only source of rx, and the sources of that conjunction.
+pub fn find_source_of_rx<'a>(
+    modules: &HashMap<&'a str, Module<'a>>,
+) -> Result<(&'a str, HashSet<&'a str>), Error> {
+    modules
+        .iter()
+        .find_map(|(id, module)| match module {
+            Module::Conjunction { src, dst } if dst.contains(&"rx") => {
+                Some((*id, src.keys().copied().collect()))
+            }
+            _ => None,
+        })
+        .ok_or(Error::NoSourceOfRx)
+}
+
+/// (from, to, value)
+/// where for the value:
+/// - Low pulse = false
+/// - High pulse = true
+pub type Pulse<'a> = (&'a str, &'a str, bool);
+
+#[derive(Clone, Eq, PartialEq, Serialize)]
+pub enum Module<'a> {
+    FlipFlop {
+        on: bool,
+        dst: Vec<&'a str>,
+    },
+    Conjunction {
+        src: HashMap<&'a str, bool>,
+        dst: Vec<&'a str>,
+    },
+    Broadcaster {
+        dst: Vec<&'a str>,
+    },
+    Output,
+}
+
+impl<'a> Module<'a> {
+    pub fn pulse(&mut self, pulse: Pulse<'a>) -> Vec<Pulse<'a>> {
+        let (from, to, value) = pulse;
+
+        match self {
+            Module::FlipFlop { on, dst } => {
+                if !value {
+                    *on = !*on;
+
+                    dst.iter().map(|dst| (to, *dst, *on)).collect()
+                } else {
+                    Vec::new()
+                }
+            }
+            Module::Conjunction { src, dst } => {
+                src.entry(from).and_modify(|state| *state = value);
+
+                let value = !src.values().all(|state| *state);
+                dst.iter().map(|dst| (to, *dst, value)).collect()
+            }
+            Module::Broadcaster { dst } => dst.iter().map(|dst| (to, *dst, value)).collect(),
+            Module::Output => Vec::new(),
+        }
+    }
+
+    pub fn destinations(&self) -> &[&'a str] {
+        match self {
+            Module::FlipFlop { dst, .. }
+            | Module::Conjunction { dst, .. }
+            | Module::Broadcaster { dst } => dst,
+            Module::Output => &[],
+        }
+    }
+}
+
+pub fn parse_input_into_modules(input: &str) -> Result<HashMap<&str, Module<'_>>, Error> {
+    // Scan through once to get all conjunctions ready to receive src.
+    let mut modules = input
+        .lines()
+        .map(|line| {
+            let Some((id, _)) = line.split_once(" -> ") else {
+                return Err(Error::InvalidModule(line.to_string()));
+            };
+
+            match id {
+                "broadcaster" => Ok((
+                    id,
+                    Module::Broadcaster {
+                        dst: Default::default(),
+                    },
+                )),
+                s if s.starts_with("%") => Ok((
+                    &id[1..],
+                    Module::FlipFlop {
+                        on: Default::default(),
+                        dst: Default::default(),
+                    },
+                )),
+                s if s.starts_with("&") => Ok((
+                    &id[1..],
+                    Module::Conjunction {
+                        src: Default::default(),
+                        dst: Default::default(),
+                    },
+                )),
+                _ => Err(Error::InvalidModuleId(id.to_string())),
+            }
+        })
+        .collect::<Result<HashMap<_, _>, _>>()?;
+    modules.entry("output").or_insert(Module::Output);
+    if !modules.contains_key("broadcaster") {
+        return Err(Error::NoBroadcaster);
+    }
+
+    for line in input.lines() {
+        let Some((id, destinations)) = line.split_once(" -> ") else {
+            return Err(Error::InvalidModule(line.to_string()));
+        };
+
+        // Set all module dst.
+        let destinations = destinations.split_terminator(", ").collect::<Vec<_>>();
+        let id = match id {
+            "broadcaster" => {
+                if let Some(Module::Broadcaster { dst }) = modules.get_mut(id) {
+                    *dst = destinations.clone();
+                }
+
+                id
+            }
+            "output" => id,
+            s if s.starts_with("%") => {
+                if let Some(Module::FlipFlop { dst, .. }) = modules.get_mut(&id[1..]) {
+                    *dst = destinations.clone();
+                }
+
+                &id[1..]
+            }
+            s if s.starts_with("&") => {
+                if let Some(Module::Conjunction { dst, .. }) = modules.get_mut(&id[1..]) {
+                    *dst = destinations.clone();
+                }
+
+                &id[1..]
+            }
+            _ => return Err(Error::InvalidModuleId(id.to_string())),
+        };
+
+        // Set conjuction src.
+        for destination in &destinations {
+            if let Some(Module::Conjunction { src, .. }) = modules.get_mut(destination) {
+                src.entry(id).or_default();
+            }
+        }
+    }
+
+    // Pulses to destinations without a module of their own, like rx, are received by outputs.
+    let outputs = modules
+        .values()
+        .flat_map(|module| module.destinations())
+        .filter(|dst| !modules.contains_key(*dst))
+        .copied()
+        .collect::<Vec<_>>();
+    for dst in outputs {
+        modules.insert(dst, Module::Output);
+    }
+
+    Ok(modules)
+}
+
+pub fn button_pulse(modules: &mut HashMap<&str, Module>, log: &mut [u64; 2]) {
+    let mut pulses = VecDeque::from([("button", "broadcaster", false)]);
+    while let Some(pulse) = pulses.pop_front() {
+        let (_, dst, value) = pulse;
+
+        log[value as usize] += 1;
+
+        if let Some(module) = modules.get_mut(dst) {
+            pulses.extend(module.pulse(pulse));
+        }
+    }
+}
+
+/// Finds the number of button presses where each [Module] from track_src first send a high pulse to
+/// track_dst, giving up on a [Module] after its max_presses, as by then the states of the circuit
+/// upstream of it have all been seen. See [find_upstream_cycle].
+///
+/// Returns the num of button presses for each [Module] in track_src.
+pub fn track_high_signals<'a>(
+    modules: &mut HashMap<&'a str, Module<'a>>,
+    track_dst: &str,
+    track_src: &mut HashSet<&str>,
+    max_presses: &HashMap<&str, u64>,
+) -> Result<HashMap<&'a str, u64>, Error> {
+    let mut periods = HashMap::new();
+    let mut button_presses = 0;
+    while !track_src.is_empty() {
+        let mut src = track_src
+            .iter()
+            .filter(|src| {
+                max_presses
+                    .get(*src)
+                    .is_none_or(|max| button_presses >= *max)
+            })
+            .map(|src| src.to_string())
+            .collect::<Vec<_>>();
+        if !src.is_empty() {
+            src.sort();
+
+            return Err(Error::NoHighPulse {
+                src,
+                presses: button_presses,
+            });
+        }
+        button_presses += 1;
+
+        let mut pulses = VecDeque::from([("button", "broadcaster", false)]);
+        while let Some(pulse) = pulses.pop_front() {
+            let (src, dst, value) = pulse;
+
+            if value && dst == track_dst && track_src.contains(src) {
+                periods.insert(src, button_presses);
+                track_src.remove(src);
+            }
+
+            if let Some(module) = modules.get_mut(dst) {
+                pulses.extend(module.pulse(pulse));
+            }
+        }
+    }
+
+    Ok(periods)
+}
+
+/// Finds the cycle of the states of the part of the circuit upstream of src, from one button press
+/// to the next. Within its prefix and one cycle, every state that src can be in has been seen.
+pub fn find_upstream_cycle(src: &str, modules: &HashMap<&str, Module>) -> Cycle {
+    // Find every module that can influence src.
+    let mut upstream = HashSet::from([src]);
+    let mut queue = vec![src];
+    while let Some(dst) = queue.pop() {
+        for (id, module) in modules {
+            if module.destinations().contains(&dst) && upstream.insert(id) {
+                queue.push(id);
+            }
+        }
+    }
+
+    let sub_circuit = modules
+        .iter()
+        .filter(|(id, _)| upstream.contains(*id))
+        .map(|(id, module)| (*id, module.clone()))
+        .collect::<HashMap<_, _>>();
+
+    find_cycle_brent(sub_circuit, |sub_circuit| {
+        let mut sub_circuit = sub_circuit.clone();
+        button_pulse(&mut sub_circuit, &mut [0; 2]);
+
+        sub_circuit
+    })
+}
+
+/// Checks that the part of the circuit upstream of src, with the given [Cycle], repeats itself
+/// every period button presses, including the press that sends the first high pulse. This is what
+/// makes the lcm of the periods the answer for part 2.
+fn check_periodic(src: &str, period: u64, cycle: &Cycle) -> Result<(), Error> {
+    if cycle.cycle_len as u64 != period || cycle.prefix_len as u64 >= period {
+        return Err(Error::NotPeriodic {
+            src: src.to_string(),
+            period,
+            prefix_len: cycle.prefix_len,
+            cycle_len: cycle.cycle_len,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::trim_newlines;
+
+    use super::*;
+
+    #[test]
+    fn example_1a() -> Result<()> {
+        let example = r"
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a
+";
+
+        assert_eq!(part_1(trim_newlines(example))?, 32000000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_1b() -> Result<()> {
+        let example = r"
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output
+";
+
+        assert_eq!(part_1(trim_newlines(example))?, 11687500);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        // 2 counters that wrap around at 3 and 5 presses.
+        let example = r"
+broadcaster -> a0, b0
+%a0 -> a1, ka
+%a1 -> ka
+&ka -> a0, ia
+&ia -> hub
+%b0 -> b1, kb
+%b1 -> b2
+%b2 -> kb
+&kb -> b0, b1, ib
+&ib -> hub
+&hub -> rx
+";
+
+        assert_eq!(part_2(trim_newlines(example))?, 15);
+
+        Ok(())
+    }
+
+    #[test]
+    fn longer_than_12_bits() -> Result<()> {
+        // A counter that wraps around at 2^13 - 1 presses, with every bit feeding its reset, and
+        // one that wraps around at 3.
+        let mut lines = vec![String::from("broadcaster -> a0, b0")];
+        for bit in 0..13 {
+            let next = if bit < 12 {
+                format!("a{}, ", bit + 1)
+            } else {
+                String::new()
+            };
+            lines.push(format!("%a{bit} -> {next}ka"));
+        }
+        lines.extend(
+            [
+                "&ka -> a0, ia",
+                "&ia -> hub",
+                "%b0 -> b1, kb",
+                "%b1 -> kb",
+                "&kb -> b0, ib",
+                "&ib -> hub",
+                "&hub -> rx",
+            ]
+            .map(String::from),
+        );
+
+        assert_eq!(part_2(&lines.join("\n"))?, 8191 * 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2_not_periodic() {
+        // The second counter never wraps around cleanly.
+        let example = r"
+broadcaster -> a0, b0
+%a0 -> a1, ka
+%a1 -> ka
+&ka -> a0, ia
+&ia -> hub
+%b0 -> b1
+%b1 -> kb
+&kb -> b0, ib
+&ib -> hub
+&hub -> rx
+";
+
+        assert!(part_2(trim_newlines(example)).is_err());
+    }
+}
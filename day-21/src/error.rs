@@ -0,0 +1,29 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind};
+use geometry::Point;
+
+/// Why a day 21 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    #[error("Cannot find start S")]
+    NoStart,
+
+    /// The garden must be as tall as it is wide.
+    #[error("Garden is not square: {0}")]
+    NotSquare(String),
+
+    /// Part 2 relies on the shape of the real puzzle input.
+    #[error("Cannot walk the infinite garden from {start:?} of a {size} x {size} garden")]
+    UnsupportedGarden { size: usize, start: Point },
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::NotSquare(_) => ErrorKind::Parse,
+            Error::NoStart => ErrorKind::Validation,
+            Error::UnsupportedGarden { .. } => ErrorKind::Unsolvable,
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use std::collections::HashSet;
+
+use rand::{Rng, rngs::StdRng};
+
+use cli::Generated;
+
+/// Generates an odd-sized garden of about size x size, with S at the centre, and clear plots along
+/// the border and along the row and col of S, as in the puzzle inputs.
+///
+/// Part 1 is answered by stepping every reachable plot 64 times. Part 2 is not answered, and only
+/// applies to 131 x 131 gardens.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let size = size / 2 * 2 + 1;
+    let centre = size / 2;
+
+    let mut layout = vec![vec![b'.'; size]; size];
+    for (row, tiles) in layout.iter_mut().enumerate() {
+        for (col, tile) in tiles.iter_mut().enumerate() {
+            let is_clear = row == 0 || col == 0 || row == size - 1 || col == size - 1;
+            if !is_clear && row != centre && col != centre && rng.random_bool(0.15) {
+                *tile = b'#';
+            }
+        }
+    }
+    layout[centre][centre] = b'S';
+
+    let mut plots = HashSet::from([(centre, centre)]);
+    for _ in 0..64 {
+        plots = plots
+            .into_iter()
+            .flat_map(|(row, col)| {
+                [
+                    (row.wrapping_sub(1), col),
+                    (row + 1, col),
+                    (row, col.wrapping_sub(1)),
+                    (row, col + 1),
+                ]
+            })
+            .filter(|&(row, col)| row < size && col < size && layout[row][col] != b'#')
+            .collect();
+    }
+
+    Generated {
+        input: layout
+            .iter()
+            .map(|row| String::from_utf8_lossy(row))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        answers: [Some(plots.len().to_string()), None],
+    }
+}
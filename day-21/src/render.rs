@@ -0,0 +1,70 @@
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+
+use cli::render::{HIGHLIGHT, Image, OPEN, SECONDARY, WALL};
+
+use crate::{Grid, compute_shortest_distance};
+
+/// Draws the garden plots reachable in exactly 64 steps, and the start. Part 2 walks across an
+/// infinite map, so only part 1 can be drawn.
+pub fn render(input: &str, part: u8) -> Result<Image> {
+    match part {
+        1 => render_steps(input, 64),
+        2 => Err(anyhow!("Cannot render the infinite map of part 2")),
+        _ => Err(anyhow!("Invalid part number: {}", part)),
+    }
+}
+
+fn render_steps(input: &str, steps: u32) -> Result<Image> {
+    let grid = Grid::from_str(input)?;
+    let shortest_distances = compute_shortest_distance(grid.start, 0, &grid, Some(steps));
+
+    let mut image = Image::new(grid.size, grid.size, OPEN);
+    for rock in &grid.rocks {
+        image.set(rock.row, rock.col, WALL);
+    }
+    for (row, distances) in shortest_distances.iter().enumerate() {
+        for (col, distance) in distances.iter().enumerate() {
+            if distance.is_some_and(|distance| distance & 1 == steps & 1) {
+                image.set(row, col, HIGHLIGHT);
+            }
+        }
+    }
+    if image.get(grid.start.row, grid.start.col) != HIGHLIGHT {
+        image.set(grid.start.row, grid.start.col, SECONDARY);
+    }
+
+    Ok(image)
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::trim_newlines;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+";
+
+    #[test]
+    fn reachable_plots() -> Result<()> {
+        assert_eq!(
+            render_steps(trim_newlines(EXAMPLE), 6)?.count(HIGHLIGHT),
+            16
+        );
+
+        Ok(())
+    }
+}
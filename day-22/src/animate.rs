@@ -0,0 +1,167 @@
+use std::{cmp::max, str::FromStr};
+
+use anyhow::{Result, anyhow};
+
+use cli::animate::{Frame, Frames};
+
+use crate::{Brick, Coord, max_depth, update_depths};
+
+/// Plays back the bricks settling, a brick at a time, drawn from the front (x against z) and the
+/// side (y against z) as in the puzzle. Both parts settle the bricks the same way.
+pub fn animate(input: &str, part: u8) -> Result<Frames<'_>> {
+    if !matches!(part, 1 | 2) {
+        return Err(anyhow!("Invalid part number: {}", part));
+    }
+
+    let mut bricks = input
+        .lines()
+        .map(Brick::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+    bricks.sort();
+
+    let max = bricks.iter().fold((0, 0, 0), |acc, brick| {
+        (
+            max(acc.0, brick.high.0),
+            max(acc.1, brick.high.1),
+            max(acc.2, brick.high.2),
+        )
+    });
+    let new_depths = move || vec![vec![0; max.0 + 1]; max.1 + 1];
+
+    // As in settle_bricks, pass over the bricks until none of them drop.
+    let mut depths = new_depths();
+    let mut index = 0;
+    let mut drop_during_pass = false;
+    let mut started = false;
+
+    Ok(Box::new(std::iter::from_fn(move || {
+        if !started {
+            started = true;
+            return Some(frame("Start".to_string(), &bricks, None, max));
+        }
+
+        loop {
+            if index == bricks.len() {
+                if !drop_during_pass {
+                    return None;
+                }
+
+                depths = new_depths();
+                index = 0;
+                drop_during_pass = false;
+            }
+
+            let brick = &mut bricks[index];
+            let from = brick.low.2;
+            let dropped = brick.drop(max_depth(&depths, brick));
+            update_depths(&mut depths, brick);
+            index += 1;
+
+            if dropped {
+                drop_during_pass = true;
+                let caption = format!(
+                    "Brick {} fell from z={} to z={}",
+                    label(index - 1),
+                    from,
+                    bricks[index - 1].low.2
+                );
+
+                return Some(frame(caption, &bricks, Some(index - 1), max));
+            }
+        }
+    })))
+}
+
+fn label(index: usize) -> char {
+    char::from(b'A' + (index % 26) as u8)
+}
+
+/// Draws both views side by side. Where several bricks line up, the tile is drawn as `?`, unless
+/// one of them is the brick that just fell, which is drawn as `#`.
+fn frame(caption: String, bricks: &[Brick], fallen: Option<usize>, max: Coord) -> Frame {
+    let (max_x, max_y, max_z) = max;
+    // [z][position] => bricks lined up on the tile, for the x view then the y view.
+    let mut views = [
+        vec![vec![Vec::new(); max_x + 1]; max_z + 1],
+        vec![vec![Vec::new(); max_y + 1]; max_z + 1],
+    ];
+    for (index, brick) in bricks.iter().enumerate() {
+        let (low, high) = (brick.low, brick.high);
+        for tiles in &mut views[0][low.2..=high.2] {
+            tiles[low.0..=high.0]
+                .iter_mut()
+                .for_each(|tile| tile.push(index));
+        }
+        for tiles in &mut views[1][low.2..=high.2] {
+            tiles[low.1..=high.1]
+                .iter_mut()
+                .for_each(|tile| tile.push(index));
+        }
+    }
+
+    let draw = |lined_up: &Vec<usize>| match lined_up.as_slice() {
+        [] => '.',
+        lined_up if fallen.is_some_and(|fallen| lined_up.contains(&fallen)) => '#',
+        [index] => label(*index),
+        _ => '?',
+    };
+
+    let mut lines = vec![format!("{:<width$} y", "x", width = max_x + 1)];
+    for z in (1..=max_z).rev() {
+        let x_view = views[0][z].iter().map(draw).collect::<String>();
+        let y_view = views[1][z].iter().map(draw).collect::<String>();
+        lines.push(format!("{x_view} {y_view} {z}"));
+    }
+    lines.push(format!(
+        "{} {} 0",
+        "-".repeat(max_x + 1),
+        "-".repeat(max_y + 1)
+    ));
+
+    Frame::new(caption, lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::trim_newlines;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+";
+
+    #[test]
+    fn bricks_settle() -> Result<()> {
+        let frames = animate(trim_newlines(EXAMPLE), 1)?.collect::<Vec<_>>();
+
+        assert_eq!(frames[1].caption, "Brick C fell from z=3 to z=2");
+        // Settles as in the puzzle, with the last brick to fall drawn as #.
+        assert_eq!(
+            frames[frames.len() - 1].text,
+            trim_newlines(
+                r"
+x   y
+... ... 9
+... ... 8
+... ... 7
+.#. .#. 6
+.#. .#. 5
+FFF .F. 4
+D.E ??? 3
+??? B.C 2
+.A. AAA 1
+--- --- 0
+"
+            )
+        );
+
+        Ok(())
+    }
+}
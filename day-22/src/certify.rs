@@ -0,0 +1,39 @@
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+
+use cli::certify::Certificate;
+
+use crate::{Brick, check::Witness, settle_bricks};
+
+/// Solves part 1 along with the bricks that are safe to disintegrate.
+pub fn certify(input: &str, part: u8) -> Result<Certificate> {
+    if part != 1 {
+        return Err(anyhow!("Cannot certify part {}", part));
+    }
+
+    let mut bricks = input
+        .lines()
+        .map(Brick::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+    // Settle the bricks as in part 1, keeping track of the line of each.
+    let mut lines = (1..=bricks.len()).collect::<Vec<_>>();
+    lines.sort_by_key(|line| bricks[line - 1]);
+    bricks.sort();
+    settle_bricks(&mut bricks);
+    let mut settled = bricks.into_iter().zip(lines).collect::<Vec<_>>();
+    settled.sort();
+    let bricks = settled.iter().map(|(brick, _)| *brick).collect::<Vec<_>>();
+
+    let safe = (0..bricks.len())
+        .filter(|index| {
+            let mut snapshot = bricks.clone();
+            snapshot.remove(*index);
+
+            settle_bricks(&mut snapshot) == 0
+        })
+        .map(|index| settled[index].1)
+        .collect::<Vec<_>>();
+
+    Certificate::new(safe.len(), &Witness { safe })
+}
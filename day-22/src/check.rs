@@ -0,0 +1,148 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    str::FromStr,
+};
+
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+use cli::certify::Certificate;
+
+use crate::Brick;
+
+/// The bricks that are safe to disintegrate.
+#[derive(Deserialize, Serialize)]
+pub struct Witness {
+    /// The line of each brick in the input, counting from 1.
+    pub safe: Vec<usize>,
+}
+
+/// Lowers the bricks a cube at a time, and checks that the safe bricks are exactly those that are
+/// never the only brick under another.
+pub fn check(input: &str, part: u8, certificate: &Certificate) -> Result<()> {
+    if part != 1 {
+        return Err(anyhow!("Cannot check part {}", part));
+    }
+
+    let bricks = input
+        .lines()
+        .map(Brick::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+    let Witness { safe } = certificate.witness()?;
+
+    // cube => index of the brick
+    let mut settled: HashMap<[usize; 3], usize> = HashMap::new();
+    let mut cubes = vec![Vec::new(); bricks.len()];
+    let mut order = (0..bricks.len()).collect::<Vec<_>>();
+    order.sort_by_key(|index| bricks[*index].low.2);
+    for index in order {
+        let brick = bricks[index];
+        let mut brick_cubes = Vec::new();
+        for x in brick.low.0..=brick.high.0 {
+            for y in brick.low.1..=brick.high.1 {
+                for z in brick.low.2..=brick.high.2 {
+                    brick_cubes.push([x, y, z]);
+                }
+            }
+        }
+
+        while brick_cubes
+            .iter()
+            .all(|&[x, y, z]| z > 1 && !settled.contains_key(&[x, y, z - 1]))
+        {
+            for cube in brick_cubes.iter_mut() {
+                cube[2] -= 1;
+            }
+        }
+
+        for &cube in &brick_cubes {
+            settled.insert(cube, index);
+        }
+        cubes[index] = brick_cubes;
+    }
+
+    let mut expected = (0..bricks.len()).collect::<BTreeSet<_>>();
+    for (index, brick_cubes) in cubes.iter().enumerate() {
+        let supports = brick_cubes
+            .iter()
+            .filter_map(|&[x, y, z]| settled.get(&[x, y, z.checked_sub(1)?]))
+            .filter(|support| **support != index)
+            .collect::<HashSet<_>>();
+        if let &[only_support] = supports.into_iter().collect::<Vec<_>>().as_slice() {
+            expected.remove(only_support);
+        }
+    }
+
+    let mut witnessed = BTreeSet::new();
+    for line in safe {
+        if line == 0 || line > bricks.len() {
+            return Err(anyhow!("No brick on line {}", line));
+        }
+        if !witnessed.insert(line - 1) {
+            return Err(anyhow!("Brick on line {} is listed twice", line));
+        }
+    }
+    if let Some(index) = witnessed.difference(&expected).next() {
+        return Err(anyhow!("Brick on line {} is not safe", index + 1));
+    }
+    if let Some(index) = expected.difference(&witnessed).next() {
+        return Err(anyhow!(
+            "Brick on line {} is safe but not listed",
+            index + 1
+        ));
+    }
+
+    certificate.expect_answer(witnessed.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::trim_newlines;
+
+    use crate::certify::certify;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+";
+
+    #[test]
+    fn example() -> Result<()> {
+        let input = trim_newlines(EXAMPLE);
+        let certificate = certify(input, 1)?;
+
+        assert_eq!(certificate.answer, "5");
+        assert_eq!(certificate.witness::<Witness>()?.safe, [2, 3, 4, 5, 7]);
+        check(input, 1, &certificate)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_safe_bricks() -> Result<()> {
+        let input = trim_newlines(EXAMPLE);
+        let check_safe = |answer, safe: &[usize]| {
+            let witness = Witness {
+                safe: safe.to_vec(),
+            };
+
+            check(input, 1, &Certificate::new(answer, &witness)?)
+        };
+
+        check_safe(5, &[7, 5, 4, 3, 2])?;
+        assert!(check_safe(4, &[2, 3, 4, 5, 7]).is_err());
+        assert!(check_safe(5, &[1, 3, 4, 5, 7]).is_err());
+        assert!(check_safe(4, &[2, 3, 4, 5]).is_err());
+        assert!(check_safe(6, &[2, 3, 4, 5, 7, 7]).is_err());
+        assert!(check_safe(6, &[2, 3, 4, 5, 7, 8]).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind, NumberError, answer::Overflow};
+
+use crate::Coord;
+
+/// Why a day 22 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    #[error("Cannot split input into low and high: {0}")]
+    MissingHigh(String),
+
+    #[error("Cannot split input into x, y, and z: {0}")]
+    InvalidCoord(String),
+
+    #[error(transparent)]
+    Number(#[from] NumberError),
+
+    /// A brick must be a straight line from its low end to its high end.
+    #[error("Invalid delta: {low:?}, {high:?}")]
+    NotStraight { low: Coord, high: Coord },
+
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::MissingHigh(_) | Error::InvalidCoord(_) | Error::Number(_) => ErrorKind::Parse,
+            Error::NotStraight { .. } => ErrorKind::Validation,
+            Error::Overflow(_) => ErrorKind::Unsolvable,
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+
+use rand::{Rng, rngs::StdRng, seq::SliceRandom};
+
+use cli::Generated;
+
+/// The cubes of a brick, as (x, y, z).
+type Brick = Vec<(usize, usize, usize)>;
+
+/// Generates size bricks in a 5 x 5 column, each at its own height, so that none overlap before
+/// settling.
+///
+/// Both parts are answered by lowering bricks 1 cube at a time until none can move, first for the
+/// whole stack and then for the stack without each brick in turn.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let mut bricks = Vec::new();
+    let mut z = 1;
+    for _ in 0..size {
+        let len = rng.random_range(1..=4);
+        let axis = rng.random_range(0..3);
+        let (x, y) = match axis {
+            0 => (rng.random_range(0..=5 - len), rng.random_range(0..5)),
+            1 => (rng.random_range(0..5), rng.random_range(0..=5 - len)),
+            _ => (rng.random_range(0..5), rng.random_range(0..5)),
+        };
+
+        let brick = (0..len)
+            .map(|offset| match axis {
+                0 => (x + offset, y, z),
+                1 => (x, y + offset, z),
+                _ => (x, y, z + offset),
+            })
+            .collect::<Brick>();
+        z = brick.last().unwrap().2 + 1 + rng.random_range(0..=2);
+        bricks.push(brick);
+    }
+
+    let mut lines = bricks
+        .iter()
+        .map(|brick| {
+            let (low, high) = (brick[0], brick[brick.len() - 1]);
+            format!(
+                "{},{},{}~{},{},{}",
+                low.0, low.1, low.2, high.0, high.1, high.2
+            )
+        })
+        .collect::<Vec<_>>();
+    lines.shuffle(rng);
+
+    settle(&mut bricks);
+    let falls = (0..bricks.len())
+        .map(|index| {
+            let mut bricks = bricks.clone();
+            bricks.remove(index);
+
+            settle(&mut bricks)
+        })
+        .collect::<Vec<_>>();
+
+    Generated {
+        input: lines.join("\n"),
+        answers: [
+            Some(
+                falls
+                    .iter()
+                    .filter(|falls| **falls == 0)
+                    .count()
+                    .to_string(),
+            ),
+            Some(falls.iter().sum::<usize>().to_string()),
+        ],
+    }
+}
+
+/// Lowers bricks 1 cube at a time, until none can move.
+///
+/// Returns the num of bricks that moved.
+fn settle(bricks: &mut [Brick]) -> usize {
+    let mut cubes = bricks.iter().flatten().copied().collect::<HashSet<_>>();
+    let mut moved = vec![false; bricks.len()];
+    let mut any_moved = true;
+    while any_moved {
+        any_moved = false;
+        for (index, brick) in bricks.iter_mut().enumerate() {
+            let can_move = brick.iter().all(|&(x, y, z)| {
+                z > 1 && (brick.contains(&(x, y, z - 1)) || !cubes.contains(&(x, y, z - 1)))
+            });
+            if can_move {
+                for cube in brick.iter_mut() {
+                    cubes.remove(cube);
+                    cube.2 -= 1;
+                }
+                cubes.extend(brick.iter().copied());
+                moved[index] = true;
+                any_moved = true;
+            }
+        }
+    }
+
+    moved.into_iter().filter(|moved| *moved).count()
+}
@@ -0,0 +1,302 @@
+use std::{
+    cmp::{Ordering, max},
+    str::FromStr,
+};
+
+use serde::Serialize;
+
+use cli::{
+    Solver,
+    answer::{Answer, try_sum, widen, with_width},
+    certify::Certifier,
+    dump_lines, parallel, parse_number, progress,
+};
+
+pub use error::Error;
+
+pub mod animate;
+pub mod certify;
+pub mod check;
+mod error;
+pub mod generator;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 22,
+    version: env!("SOLVER_VERSION"),
+    // Part 1 counts bricks, which are all in memory.
+    part_1: |input, _| Ok(part_1(input)?.to_string()),
+    part_2: Some(|input, settings| {
+        with_width(settings, || part_2(input), || part_2_as::<u128>(input))
+    }),
+    generator: generator::generate,
+    render: None,
+    animate: Some(animate::animate),
+    dump: Some(|input| dump_lines(input, Brick::from_str)),
+    stream: None,
+    certify: Some(Certifier {
+        parts: &[1],
+        witness: certify::certify,
+        check: check::check,
+    }),
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<usize, Error> {
+    let mut bricks = input
+        .lines()
+        .map(Brick::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+    bricks.sort();
+    settle_bricks(&mut bricks);
+    bricks.sort();
+
+    let indices = (0..bricks.len()).collect::<Vec<_>>();
+
+    let progress = progress::start(Some(indices.len()));
+    Ok(parallel::map(&indices, |index| {
+        let mut snapshot = bricks.clone();
+        snapshot.remove(*index);
+        let fallen = settle_bricks(&mut snapshot);
+        progress.advance();
+
+        fallen == 0
+    })
+    .into_iter()
+    .filter(|is_stable| *is_stable)
+    .count())
+}
+
+pub fn part_2(input: &str) -> Result<usize, Error> {
+    part_2_as(input)
+}
+
+/// Sums the bricks that fall when each brick is disintegrated in A.
+pub fn part_2_as<A: Answer + TryFrom<usize>>(input: &str) -> Result<A, Error> {
+    let mut bricks = input
+        .lines()
+        .map(Brick::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+    bricks.sort();
+    settle_bricks(&mut bricks);
+    bricks.sort();
+
+    let indices = (0..bricks.len()).collect::<Vec<_>>();
+
+    let progress = progress::start(Some(indices.len()));
+    let fallen = parallel::map(&indices, |index| {
+        let mut snapshot = bricks.clone();
+        snapshot.remove(*index);
+        let fallen = settle_bricks(&mut snapshot);
+        progress.advance();
+
+        fallen
+    })
+    .into_iter()
+    .map(widen)
+    .collect::<Result<Vec<A>, _>>()?;
+
+    Ok(try_sum(fallen)?)
+}
+
+/// (x, y, z)
+pub type Coord = (usize, usize, usize);
+
+#[derive(Clone, Copy, Eq, PartialEq, Serialize)]
+pub struct Brick {
+    pub low: Coord,
+    pub high: Coord,
+    pub axis: usize,
+}
+
+impl FromStr for Brick {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let Some((low, high)) = s.split_once("~") else {
+            return Err(Error::MissingHigh(s.to_string()));
+        };
+
+        let &[x, y, z] = low.split_terminator(",").collect::<Vec<_>>().as_slice() else {
+            return Err(Error::InvalidCoord(low.to_string()));
+        };
+        let x = parse_number(x)?;
+        let y = parse_number(y)?;
+        let z = parse_number(z)?;
+        let low = (x, y, z);
+
+        let &[x, y, z] = high.split_terminator(",").collect::<Vec<_>>().as_slice() else {
+            return Err(Error::InvalidCoord(high.to_string()));
+        };
+        let x = parse_number(x)?;
+        let y = parse_number(y)?;
+        let z = parse_number(z)?;
+        let high = (x, y, z);
+
+        let axis = match (low, high) {
+            ((low_x, low_y, low_z), (high_x, high_y, high_z))
+                if low_x < high_x && low_y == high_y && low_z == high_z =>
+            {
+                0
+            }
+            ((low_x, low_y, low_z), (high_x, high_y, high_z))
+                if low_x == high_x && low_y < high_y && low_z == high_z =>
+            {
+                1
+            }
+            ((low_x, low_y, low_z), (high_x, high_y, high_z))
+                if low_x == high_x && low_y == high_y && low_z < high_z =>
+            {
+                2
+            }
+            ((low_x, low_y, low_z), (high_x, high_y, high_z))
+                if low_x == high_x && low_y == high_y && low_z == high_z =>
+            {
+                2
+            }
+            _ => return Err(Error::NotStraight { low, high }),
+        };
+
+        Ok(Self { low, high, axis })
+    }
+}
+
+impl Ord for Brick {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.low.2.cmp(&other.low.2) {
+            Ordering::Equal => (),
+            ord => return ord,
+        }
+
+        match self.low.cmp(&other.low) {
+            Ordering::Equal => (),
+            ord => return ord,
+        }
+
+        self.high.cmp(&other.high)
+    }
+}
+
+impl PartialOrd for Brick {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Brick {
+    /// Adjusts the z pos of the [Brick].
+    ///
+    /// Returns true if the z pos is updated, false if unchanged.
+    pub fn drop(&mut self, low_z: usize) -> bool {
+        if low_z == self.low.2 {
+            false
+        } else {
+            self.high.2 = low_z + (self.high.2 - self.low.2);
+            self.low.2 = low_z;
+
+            true
+        }
+    }
+}
+
+/// Lowers all [Brick]s as far as they can go.
+///
+/// Returns the num of [Brick]s that are lowered.
+///
+/// sorted_bricks must be sorted by increasing z pos.
+pub fn settle_bricks(sorted_bricks: &mut [Brick]) -> usize {
+    let mut bricks_dropped = vec![false; sorted_bricks.len()];
+
+    let (max_x, max_y) = sorted_bricks.iter().fold((0, 0), |acc, brick| {
+        (max(acc.0, brick.high.0), max(acc.1, brick.high.1))
+    });
+
+    loop {
+        // [y][x]
+        let mut depths = vec![vec![0; max_x + 1]; max_y + 1];
+        let mut drop_during_iteration = false;
+
+        for (index, brick) in sorted_bricks.iter_mut().enumerate() {
+            if brick.drop(max_depth(&depths, brick)) {
+                bricks_dropped[index] = true;
+                drop_during_iteration = true;
+            }
+
+            update_depths(&mut depths, brick);
+        }
+
+        if !drop_during_iteration {
+            break;
+        }
+    }
+
+    bricks_dropped.into_iter().filter(|brick| *brick).count()
+}
+
+/// Checks the lowest depth that the given [Brick] can be placed.
+pub(crate) fn max_depth(depths: &[Vec<usize>], brick: &Brick) -> usize {
+    match brick.axis {
+        0 => (brick.low.0..=brick.high.0).fold(0, |acc, x| max(acc, depths[brick.low.1][x] + 1)),
+        1 => (brick.low.1..=brick.high.1).fold(0, |acc, y| max(acc, depths[y][brick.low.0] + 1)),
+        _ => depths[brick.low.1][brick.low.0] + 1,
+    }
+}
+
+/// Updates depths with the given [Brick]'s z pos.
+pub(crate) fn update_depths(depths: &mut [Vec<usize>], brick: &Brick) {
+    match brick.axis {
+        0 => (brick.low.0..=brick.high.0).for_each(|x| depths[brick.low.1][x] = brick.low.2),
+        1 => (brick.low.1..=brick.high.1).for_each(|y| depths[y][brick.low.0] = brick.low.2),
+        _ => depths[brick.low.1][brick.low.0] = brick.high.2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::{progress::Progress, trim_newlines};
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+";
+
+    #[test]
+    fn example_1() -> Result<()> {
+        assert_eq!(part_1(trim_newlines(EXAMPLE))?, 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        assert_eq!(part_2(trim_newlines(EXAMPLE))?, 7);
+
+        Ok(())
+    }
+
+    #[test]
+    fn progress() -> Result<()> {
+        let (answer, reported) = progress::track(|| part_2(trim_newlines(EXAMPLE)));
+
+        assert_eq!(answer?, 7);
+        assert_eq!(
+            reported,
+            Some(Progress {
+                done: 7,
+                total: Some(7),
+                best: None,
+            })
+        );
+
+        Ok(())
+    }
+}
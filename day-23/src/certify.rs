@@ -0,0 +1,85 @@
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+
+use cli::certify::Certificate;
+use geometry::Point;
+
+use crate::{Error, Grid, Node, Nodes, check::Witness, longest_hike_downslope, trace_trail};
+
+/// Solves the given part along with the longest hike.
+pub fn certify(input: &str, part: u8) -> Result<Certificate> {
+    let grid = Grid::from_str(input)?;
+    let hike = match part {
+        1 => longest_hike_downslope(&grid),
+        2 => longest_hike(grid.erase_arrows())?,
+        _ => return Err(anyhow!("Invalid part number: {}", part)),
+    };
+    if hike.is_empty() {
+        return Err(Error::NoPath.into());
+    }
+
+    let hike = hike
+        .into_iter()
+        .map(|point| [point.row, point.col])
+        .collect::<Vec<_>>();
+
+    Certificate::new(hike.len(), &Witness { hike })
+}
+
+/// Finds the tiles of the longest hike, excluding the start, by tracing the trails between the
+/// junctions of the longest route.
+fn longest_hike(grid: Grid) -> Result<Vec<Point>> {
+    let start = Point::new(0, 1);
+    let goal = Point::new(grid.size - 1, grid.size - 2);
+    let nodes = grid.clone().into_nodes()?;
+    let Some((_, route)) = longest_route(start, goal, &mut vec![start], &nodes) else {
+        return Err(Error::NoPath.into());
+    };
+
+    let junctions = nodes.keys().copied().collect::<Vec<_>>();
+    let mut hike = Vec::new();
+    for leg in route.windows(2) {
+        let trail = leg[0]
+            .neighbours(grid.bounds())
+            .filter(|(_, exit)| grid.tile(*exit) != b'#')
+            .filter_map(|(_, exit)| trace_trail(leg[0], exit, &grid, &junctions).ok())
+            .filter(|trail| trail.last() == Some(&leg[1]))
+            .max_by_key(|trail| trail.len())
+            .ok_or(Error::NoPath)?;
+        hike.extend(trail);
+    }
+
+    Ok(hike)
+}
+
+/// Finds the len of the longest route from node to target, and the nodes along it.
+fn longest_route(
+    node: Node,
+    target: Node,
+    route: &mut Vec<Node>,
+    nodes: &Nodes,
+) -> Option<(usize, Vec<Node>)> {
+    if node == target {
+        return Some((0, route.clone()));
+    }
+
+    let mut longest: Option<(usize, Vec<Node>)> = None;
+    for &(connection, connection_len) in &nodes[&node] {
+        if route.contains(&connection) {
+            continue;
+        }
+
+        route.push(connection);
+        if let Some((len, found)) = longest_route(connection, target, route, nodes)
+            && longest
+                .as_ref()
+                .is_none_or(|(longest_len, _)| len + connection_len > *longest_len)
+        {
+            longest = Some((len + connection_len, found));
+        }
+        route.pop();
+    }
+
+    longest
+}
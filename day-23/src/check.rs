@@ -0,0 +1,169 @@
+use std::{collections::HashSet, str::FromStr};
+
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+use cli::certify::Certificate;
+
+use crate::Grid;
+
+/// A hike as long as the answer. It is not checked that there is no longer hike.
+#[derive(Deserialize, Serialize)]
+pub struct Witness {
+    /// [row, col] of every tile stepped on after the start, ending at the goal.
+    pub hike: Vec<[usize; 2]>,
+}
+
+/// Follows the hike a step at a time, checking that it stays on the paths, never steps on a tile
+/// twice and, in part 1, only goes down slopes.
+pub fn check(input: &str, part: u8, certificate: &Certificate) -> Result<()> {
+    if !(1..=2).contains(&part) {
+        return Err(anyhow!("Invalid part number: {}", part));
+    }
+
+    let grid = Grid::from_str(input)?;
+    let Witness { hike } = certificate.witness()?;
+
+    let mut prev = [0, 1];
+    let mut visited = HashSet::from([prev]);
+    for &[row, col] in &hike {
+        if row >= grid.size || col >= grid.size || grid.layout[row][col] == b'#' {
+            return Err(anyhow!("Leaves the paths at {:?}", [row, col]));
+        }
+        if !visited.insert([row, col]) {
+            return Err(anyhow!("Steps on {:?} twice", [row, col]));
+        }
+
+        let step = [
+            row as isize - prev[0] as isize,
+            col as isize - prev[1] as isize,
+        ];
+        if step[0].abs() + step[1].abs() != 1 {
+            return Err(anyhow!("Jumps from {:?} to {:?}", prev, [row, col]));
+        }
+        if part == 1 {
+            // Onto a slope and off it again, in the direction of the slope.
+            for [slope_row, slope_col] in [prev, [row, col]] {
+                let downhill = match grid.layout[slope_row][slope_col] {
+                    b'^' => [-1, 0],
+                    b'>' => [0, 1],
+                    b'v' => [1, 0],
+                    b'<' => [0, -1],
+                    _ => continue,
+                };
+                if step != downhill {
+                    return Err(anyhow!("Climbs the slope at {:?}", [slope_row, slope_col]));
+                }
+            }
+        }
+
+        prev = [row, col];
+    }
+
+    if prev != [grid.size - 1, grid.size - 2] {
+        return Err(anyhow!("Ends at {:?} rather than the goal", prev));
+    }
+
+    certificate.expect_answer(hike.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::trim_newlines;
+
+    use crate::certify::certify;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+";
+
+    #[test]
+    fn example() -> Result<()> {
+        let input = trim_newlines(EXAMPLE);
+        for (part, answer) in [(1, "94"), (2, "154")] {
+            let certificate = certify(input, part)?;
+
+            assert_eq!(certificate.answer, answer);
+            check(input, part, &certificate)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_hikes() -> Result<()> {
+        let input = "#.###\n#...#\n#.>.#\n#...#\n###.#";
+        let check_hike = |part, answer, hike: &[[usize; 2]]| {
+            let witness = Witness {
+                hike: hike.to_vec(),
+            };
+
+            check(input, part, &Certificate::new(answer, &witness)?)
+        };
+
+        let down = [[1, 1], [2, 1], [3, 1], [3, 2], [3, 3], [4, 3]];
+        let slope = [[1, 1], [2, 1], [2, 2], [2, 3], [3, 3], [4, 3]];
+        let climb = [
+            [1, 1],
+            [1, 2],
+            [1, 3],
+            [2, 3],
+            [2, 2],
+            [2, 1],
+            [3, 1],
+            [3, 2],
+            [3, 3],
+            [4, 3],
+        ];
+        check_hike(1, 6, &down)?;
+        check_hike(1, 6, &slope)?;
+        check_hike(2, 10, &climb)?;
+        assert!(check_hike(1, 10, &climb).is_err());
+        assert!(check_hike(1, 7, &down).is_err());
+        assert!(check_hike(1, 5, &down[..5]).is_err());
+        assert!(
+            check_hike(
+                2,
+                8,
+                &[
+                    [1, 1],
+                    [2, 1],
+                    [1, 1],
+                    [2, 1],
+                    [3, 1],
+                    [3, 2],
+                    [3, 3],
+                    [4, 3]
+                ]
+            )
+            .is_err()
+        );
+        assert!(check_hike(2, 3, &[[1, 1], [1, 2], [0, 2]]).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind};
+use geometry::Point;
+
+/// Why a day 23 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    /// The map must be as tall as it is wide.
+    #[error("Map is not square: {0}")]
+    NotSquare(String),
+
+    /// The start and the goal are in the second and the second-last columns.
+    #[error("Map is too small: {0}")]
+    TooSmall(usize),
+
+    #[error("Invalid node and exit: {node:?}, {exit:?}")]
+    InvalidExit { node: Point, exit: Point },
+
+    /// A trail between junctions that dead-ends, forks or goes round in circles.
+    #[error("Invalid path: {0:?}")]
+    InvalidPath(Point),
+
+    #[error("Cannot find longest path")]
+    NoPath,
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::NotSquare(_) => ErrorKind::Parse,
+            Error::TooSmall(_) | Error::InvalidExit { .. } | Error::InvalidPath(_) => {
+                ErrorKind::Validation
+            }
+            Error::NoPath => ErrorKind::Unsolvable,
+        }
+    }
+}
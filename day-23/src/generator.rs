@@ -0,0 +1,172 @@
+use std::collections::HashSet;
+
+use rand::{Rng, rngs::StdRng, seq::SliceRandom};
+
+use cli::Generated;
+
+/// (row, col) of a junction on the lattice.
+type Junction = (usize, usize);
+
+/// Generates a map of trails joining a lattice of size.clamp(2, 5) x size.clamp(2, 5) junctions, with
+/// some trails removed. Slopes around each junction point right or down, as in the puzzle inputs.
+///
+/// Both parts are answered by trying every hike a tile at a time, so the lattice is kept small.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let junctions = size.clamp(2, 5);
+    let row_gaps = (1..junctions)
+        .map(|_| rng.random_range(2..=8))
+        .collect::<Vec<_>>();
+    let mut col_gaps = row_gaps.clone();
+    col_gaps.shuffle(rng);
+    let offsets = |gaps: &[usize]| {
+        gaps.iter().fold(vec![1], |mut offsets, gap| {
+            offsets.push(offsets[offsets.len() - 1] + gap);
+            offsets
+        })
+    };
+    let (rows, cols) = (offsets(&row_gaps), offsets(&col_gaps));
+    let size = rows[junctions - 1] + 2;
+
+    // Each trail joins a junction to the junction right of it or below it.
+    let mut trails = HashSet::new();
+    for row in 0..junctions {
+        for col in 0..junctions {
+            if col + 1 < junctions {
+                trails.insert(((row, col), (row, col + 1)));
+            }
+            if row + 1 < junctions {
+                trails.insert(((row, col), (row + 1, col)));
+            }
+        }
+    }
+    let mut candidates = trails.iter().copied().collect::<Vec<_>>();
+    candidates.sort();
+    candidates.shuffle(rng);
+    for trail in candidates {
+        if !rng.random_bool(0.3) {
+            continue;
+        }
+
+        // Keep every junction on at least 2 trails, so there are no dead ends.
+        trails.remove(&trail);
+        if degree(trail.0, &trails, junctions) < 2
+            || degree(trail.1, &trails, junctions) < 2
+            || !is_connected(&trails, junctions)
+        {
+            trails.insert(trail);
+        }
+    }
+
+    let mut layout = vec![vec![b'#'; size]; size];
+    layout[0][1] = b'.';
+    layout[size - 1][size - 2] = b'.';
+    for &((from_row, from_col), (to_row, to_col)) in &trails {
+        for tiles in &mut layout[rows[from_row]..=rows[to_row]] {
+            tiles[cols[from_col]..=cols[to_col]].fill(b'.');
+        }
+    }
+    for (junction_row, &row) in rows.iter().enumerate() {
+        for (junction_col, &col) in cols.iter().enumerate() {
+            if degree((junction_row, junction_col), &trails, junctions) < 3 {
+                continue;
+            }
+
+            for (r, c, slope) in [
+                (row - 1, col, b'v'),
+                (row + 1, col, b'v'),
+                (row, col - 1, b'>'),
+                (row, col + 1, b'>'),
+            ] {
+                if layout[r][c] != b'#' && r != 0 && r != size - 1 {
+                    layout[r][c] = slope;
+                }
+            }
+        }
+    }
+
+    let mut seen = vec![vec![false; size]; size];
+    seen[0][1] = true;
+    let longest_1 = longest_hike(&layout, (0, 1), &mut seen, true);
+    let longest_2 = longest_hike(&layout, (0, 1), &mut seen, false);
+
+    Generated {
+        input: layout
+            .iter()
+            .map(|row| String::from_utf8_lossy(row))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        answers: [
+            longest_1.map(|len| len.to_string()),
+            longest_2.map(|len| len.to_string()),
+        ],
+    }
+}
+
+/// Counts the trails at a junction, including the trails from the start and to the end.
+fn degree(junction: Junction, trails: &HashSet<(Junction, Junction)>, junctions: usize) -> usize {
+    let ends = [(0, 0), (junctions - 1, junctions - 1)];
+
+    trails
+        .iter()
+        .filter(|(from, to)| *from == junction || *to == junction)
+        .count()
+        + ends.iter().filter(|end| **end == junction).count()
+}
+
+fn is_connected(trails: &HashSet<(Junction, Junction)>, junctions: usize) -> bool {
+    let mut seen = HashSet::from([(0, 0)]);
+    let mut stack = vec![(0, 0)];
+    while let Some(junction) = stack.pop() {
+        for &(from, to) in trails {
+            for (a, b) in [(from, to), (to, from)] {
+                if a == junction && seen.insert(b) {
+                    stack.push(b);
+                }
+            }
+        }
+    }
+
+    seen.len() == junctions * junctions
+}
+
+/// Tries every hike from tile, returning the num of steps of the longest hike to the end.
+fn longest_hike(
+    layout: &[Vec<u8>],
+    tile: (usize, usize),
+    seen: &mut [Vec<bool>],
+    slippery: bool,
+) -> Option<usize> {
+    let size = layout.len();
+    if tile == (size - 1, size - 2) {
+        return Some(0);
+    }
+
+    let (row, col) = tile;
+    let mut longest = None;
+    for (next, slope) in [
+        ((row.wrapping_sub(1), col), b'^'),
+        ((row, col + 1), b'>'),
+        ((row + 1, col), b'v'),
+        ((row, col.wrapping_sub(1)), b'<'),
+    ] {
+        let (r, c) = next;
+        if r >= size || c >= size || layout[r][c] == b'#' || seen[r][c] {
+            continue;
+        }
+        if slippery {
+            let on_slope = layout[row][col] != b'.';
+            let onto_slope = layout[r][c] != b'.';
+            if (on_slope && layout[row][col] != slope) || (onto_slope && layout[r][c] != slope) {
+                continue;
+            }
+        }
+
+        seen[r][c] = true;
+        if let Some(len) = longest_hike(layout, next, seen, slippery) {
+            longest = longest.max(Some(len + 1));
+        }
+        seen[r][c] = false;
+    }
+
+    longest
+}
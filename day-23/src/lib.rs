@@ -0,0 +1,441 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use serde::Serialize;
+
+use cli::{
+    Solver,
+    certify::Certifier,
+    parallel,
+    progress::{self, Goal, Reporter},
+    to_json,
+};
+use geometry::{Direction, Point, Size};
+
+pub use error::Error;
+
+pub mod certify;
+pub mod check;
+mod error;
+pub mod generator;
+pub mod render;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 23,
+    version: env!("SOLVER_VERSION"),
+    // A hike never steps on the same tile twice, so it is shorter than the grid, held in memory.
+    part_1: |input, _| Ok(part_1(input)?.to_string()),
+    part_2: Some(|input, _| Ok(part_2(input)?.to_string())),
+    generator: generator::generate,
+    render: Some(render::render),
+    animate: None,
+    dump: Some(|input| to_json(&Grid::from_str(input)?)),
+    stream: None,
+    certify: Some(Certifier {
+        parts: &[1, 2],
+        witness: certify::certify,
+        check: check::check,
+    }),
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<usize, Error> {
+    let grid = Grid::from_str(input)?;
+
+    Ok(longest_path_downslope(&grid))
+}
+
+pub fn part_2(input: &str) -> Result<usize, Error> {
+    let grid = Grid::from_str(input)?;
+    let grid = grid.erase_arrows();
+
+    let size = grid.size;
+    let nodes = grid.into_nodes()?;
+
+    // The num of paths is not known until they are all found.
+    let progress = progress::start(None);
+    longest_path(
+        Point::new(0, 1),
+        Point::new(size - 1, size - 2),
+        0,
+        HashSet::new(),
+        &nodes,
+        &progress,
+    )
+    .ok_or(Error::NoPath)
+}
+
+#[derive(Clone, Serialize)]
+pub struct Grid {
+    #[serde(serialize_with = "cli::serialize_rows")]
+    pub layout: Vec<Vec<u8>>,
+    pub size: usize,
+}
+
+impl FromStr for Grid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let lines = s.lines().collect::<Vec<_>>();
+
+        let size = lines.len();
+        if let Some(line) = lines.iter().find(|line| line.len() != size) {
+            return Err(Error::NotSquare(line.to_string()));
+        }
+        if size < 3 {
+            return Err(Error::TooSmall(size));
+        }
+
+        let layout = lines
+            .into_iter()
+            .map(|line| line.bytes().collect())
+            .collect();
+
+        Ok(Self { layout, size })
+    }
+}
+
+impl Grid {
+    pub fn erase_arrows(self) -> Self {
+        let layout = self
+            .layout
+            .into_iter()
+            .map(|bytes| {
+                bytes
+                    .into_iter()
+                    .map(|byte| {
+                        if matches!(byte, b'^' | b'>' | b'v' | b'<') {
+                            b'.'
+                        } else {
+                            byte
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self { layout, ..self }
+    }
+
+    pub fn into_nodes(self) -> Result<Nodes, Error> {
+        Ok(cache_to_nodes(grid_to_cache(&self)?))
+    }
+
+    pub fn bounds(&self) -> Size {
+        Size::square(self.size)
+    }
+
+    pub fn tile(&self, point: Point) -> u8 {
+        self.layout[point.row][point.col]
+    }
+}
+
+pub fn longest_path_downslope(grid: &Grid) -> usize {
+    longest_hike_downslope(grid).len()
+}
+
+/// Finds the tiles of the longest hike that never climbs a slope, excluding the start.
+pub fn longest_hike_downslope(grid: &Grid) -> Vec<Point> {
+    find_longest_path_downslope(Point::new(0, 1), Vec::new(), grid)
+}
+
+fn find_longest_path_downslope(point: Point, path: Vec<Point>, grid: &Grid) -> Vec<Point> {
+    if point == Point::new(grid.size - 1, grid.size - 2) {
+        return path;
+    }
+
+    let mut paths = Vec::new();
+    for (direction, neighbour) in point.neighbours(grid.bounds()) {
+        if path.contains(&neighbour) {
+            continue;
+        }
+
+        match grid.tile(neighbour) {
+            b'.' => {
+                let mut path = path.clone();
+                path.push(neighbour);
+                paths.push(path);
+            }
+            slope if Direction::try_from(slope).is_ok_and(|slope| slope == direction) => {
+                let Some(landing) = neighbour.step(direction, grid.bounds()) else {
+                    continue;
+                };
+
+                let mut path = path.clone();
+                path.push(neighbour);
+                path.push(landing);
+                paths.push(path);
+            }
+            _ => (),
+        }
+    }
+
+    paths
+        .into_iter()
+        .map(|path| find_longest_path_downslope(path[path.len() - 1], path, grid))
+        .max_by_key(|path| path.len())
+        .unwrap_or_default()
+}
+
+/// A junction, or the Start, or the Goal.
+pub type Node = Point;
+
+/// (node, path_len)
+pub type Connection = (Node, usize);
+
+/// node => [connection]
+pub type Nodes = HashMap<Node, Vec<Connection>>;
+
+/// node => exit => connection
+pub type Cache = HashMap<Point, HashMap<Point, Option<Connection>>>;
+
+fn grid_to_cache(grid: &Grid) -> Result<Cache, Error> {
+    // Create an empty cache to fill up.
+    let mut cache: Cache = HashMap::new();
+    cache
+        .entry(Point::new(0, 1))
+        .or_default()
+        .entry(Point::new(1, 1))
+        .or_default();
+    cache
+        .entry(Point::new(grid.size - 1, grid.size - 2))
+        .or_default()
+        .entry(Point::new(grid.size - 2, grid.size - 2))
+        .or_default();
+
+    // A junction is any path tile with at least 3 exits.
+    for point in grid.bounds().points() {
+        if grid.tile(point) == b'#' {
+            continue;
+        }
+
+        let exits = point
+            .neighbours(grid.bounds())
+            .filter(|(_, neighbour)| grid.tile(*neighbour) == b'.')
+            .map(|(_, neighbour)| neighbour)
+            .collect::<Vec<_>>();
+        if exits.len() >= 3 {
+            let entry = cache.entry(point).or_default();
+            for exit in exits {
+                entry.entry(exit).or_default();
+            }
+        }
+    }
+
+    fill_cache(&mut cache, grid)?;
+
+    Ok(cache)
+}
+
+/// Fills up the given [Cache] with [Connection] entries.
+fn fill_cache(cache: &mut Cache, grid: &Grid) -> Result<(), Error> {
+    let snapshot = cache.clone();
+    let nodes = snapshot.keys().copied().collect::<Vec<_>>();
+    for (node, connections) in snapshot {
+        for (exit, _) in connections {
+            if cache[&node][&exit].is_none() {
+                let (other_exit, connection) = find_connection(node, exit, grid, &nodes)?;
+                let (other_node, path_len) = connection;
+                cache
+                    .entry(node)
+                    .or_default()
+                    .entry(exit)
+                    .and_modify(|value| *value = Some(connection));
+                cache
+                    .entry(other_node)
+                    .or_default()
+                    .entry(other_exit)
+                    .and_modify(|value| *value = Some((node, path_len)));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Traces a path from the current [Node] exit to the next [Node].
+///
+/// Returns the [Connection] and the entrance into that [Connection].
+fn find_connection(
+    node: Point,
+    exit: Point,
+    grid: &Grid,
+    nodes: &[Point],
+) -> Result<(Point, Connection), Error> {
+    let trail = trace_trail(node, exit, grid, nodes)?;
+    let point = trail[trail.len() - 1];
+    let prev_point = if trail.len() >= 2 {
+        trail[trail.len() - 2]
+    } else {
+        node
+    };
+
+    Ok((prev_point, (point, trail.len())))
+}
+
+/// Traces the tiles from the current [Node] exit to the next [Node], inclusive of both.
+pub fn trace_trail(
+    node: Point,
+    exit: Point,
+    grid: &Grid,
+    nodes: &[Point],
+) -> Result<Vec<Point>, Error> {
+    let Some(mut direction) = Direction::ALL
+        .into_iter()
+        .find(|direction| node.step(*direction, grid.bounds()) == Some(exit))
+    else {
+        return Err(Error::InvalidExit { node, exit });
+    };
+
+    let mut trail = vec![exit];
+    let mut point = exit;
+    loop {
+        if nodes.contains(&point) {
+            return Ok(trail);
+        }
+
+        // Exactly 1 of left, ahead and right must continue the path.
+        let exits = [direction.turn_left(), direction, direction.turn_right()]
+            .into_iter()
+            .filter_map(|direction| Some((direction, point.step(direction, grid.bounds())?)))
+            .filter(|(_, next)| grid.tile(*next) != b'#')
+            .collect::<Vec<_>>();
+        let &[(next_direction, next)] = exits.as_slice() else {
+            return Err(Error::InvalidPath(point));
+        };
+        if trail.len() > grid.size * grid.size {
+            return Err(Error::InvalidPath(point));
+        }
+
+        direction = next_direction;
+        point = next;
+        trail.push(point);
+    }
+}
+
+/// Flattens the given [Cache] to [Nodes].
+fn cache_to_nodes(cache: Cache) -> Nodes {
+    cache
+        .into_iter()
+        .map(|(node, connections)| (node, connections.into_values().flatten().collect()))
+        .collect::<HashMap<_, _>>()
+}
+
+/// Finds the len of the longest path from node to target, reporting every path found to progress.
+pub fn longest_path(
+    node: Node,
+    target: Node,
+    path_len: usize,
+    visited_nodes: HashSet<Node>,
+    nodes: &Nodes,
+    progress: &Reporter,
+) -> Option<usize> {
+    if node == target {
+        progress.advance();
+        progress.best(path_len, Goal::Max);
+
+        return Some(path_len);
+    }
+
+    let explore = |(connection, connection_len): &Connection| {
+        if visited_nodes.contains(connection) {
+            None
+        } else {
+            let mut visited_nodes = visited_nodes.clone();
+            visited_nodes.insert(node);
+            longest_path(
+                *connection,
+                target,
+                path_len + connection_len,
+                visited_nodes,
+                nodes,
+                progress,
+            )
+        }
+    };
+
+    // Only the branches near the start are spread over the threads, as deeper down each branch is
+    // too little work to be worth it.
+    if visited_nodes.len() < PARALLEL_DEPTH {
+        parallel::map(&nodes[&node], explore)
+            .into_iter()
+            .flatten()
+            .max()
+    } else {
+        nodes[&node].iter().flat_map(explore).max()
+    }
+}
+
+/// How many nodes deep [longest_path] explores the branches in parallel.
+const PARALLEL_DEPTH: usize = 6;
+
+#[cfg(test)]
+mod tests {
+    use anyhow::{Result, anyhow};
+    use cli::trim_newlines;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+";
+
+    #[test]
+    fn example_1() -> Result<()> {
+        assert_eq!(part_1(trim_newlines(EXAMPLE))?, 94);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        assert_eq!(part_2(trim_newlines(EXAMPLE))?, 154);
+
+        Ok(())
+    }
+
+    #[test]
+    fn progress() -> Result<()> {
+        let (answer, reported) = progress::track(|| part_2(trim_newlines(EXAMPLE)));
+
+        assert_eq!(answer?, 154);
+        let reported = reported.ok_or_else(|| anyhow!("No progress reported"))?;
+        assert!(reported.done > 0);
+        assert_eq!(reported.total, None);
+        assert_eq!(reported.best, Some(154));
+
+        Ok(())
+    }
+
+    #[test]
+    fn too_small() {
+        assert_eq!(part_1("#."), Err(Error::NotSquare(String::from("#."))));
+        assert_eq!(part_2("#.\n.#"), Err(Error::TooSmall(2)));
+    }
+}
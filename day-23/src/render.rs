@@ -0,0 +1,132 @@
+use std::{collections::HashSet, str::FromStr};
+
+use anyhow::{Result, anyhow};
+
+use cli::render::{HIGHLIGHT, Image};
+use geometry::Point;
+
+use crate::{Connection, Error, Grid, Node, Nodes, longest_hike_downslope, trace_trail};
+
+/// Draws the longest hike over the trails. Part 1 never climbs a slope, while part 2 treats slopes
+/// as ordinary trail.
+pub fn render(input: &str, part: u8) -> Result<Image> {
+    let grid = Grid::from_str(input)?;
+
+    let mut hike = vec![Point::new(0, 1)];
+    match part {
+        1 => hike.extend(longest_hike_downslope(&grid)),
+        2 => hike.extend(longest_hike(&grid.clone().erase_arrows())?),
+        _ => return Err(anyhow!("Invalid part number: {}", part)),
+    }
+
+    let mut image = Image::from_layout(&grid.layout);
+    for point in hike {
+        image.set(point.row, point.col, HIGHLIGHT);
+    }
+
+    Ok(image)
+}
+
+/// Finds the tiles of the longest hike between junctions, excluding the start.
+fn longest_hike(grid: &Grid) -> Result<Vec<Point>> {
+    let start = Point::new(0, 1);
+    let goal = Point::new(grid.size - 1, grid.size - 2);
+    let nodes = grid.clone().into_nodes()?;
+    let route = longest_route(start, goal, &mut HashSet::new(), &nodes)
+        .ok_or(Error::NoPath)?
+        .1;
+
+    // Retrace the trail between each pair of nodes on the route, choosing the trail of the right
+    // len where two nodes are joined by more than one trail.
+    let node_points = nodes.keys().copied().collect::<Vec<_>>();
+    let mut hike = Vec::new();
+    let mut node = start;
+    for (next, len) in route {
+        let trail = node
+            .neighbours(grid.bounds())
+            .filter(|(_, exit)| grid.tile(*exit) != b'#')
+            .filter_map(|(_, exit)| trace_trail(node, exit, grid, &node_points).ok())
+            .find(|trail| trail.len() == len && trail.last() == Some(&next))
+            .ok_or(anyhow!("Cannot retrace trail: {:?}, {:?}", node, next))?;
+        hike.extend(trail);
+        node = next;
+    }
+
+    Ok(hike)
+}
+
+/// Finds the len of the longest path from node to target, and each [Connection] along it.
+fn longest_route(
+    node: Node,
+    target: Node,
+    visited_nodes: &mut HashSet<Node>,
+    nodes: &Nodes,
+) -> Option<(usize, Vec<Connection>)> {
+    if node == target {
+        return Some((0, Vec::new()));
+    }
+
+    visited_nodes.insert(node);
+    let mut longest: Option<(usize, Vec<Connection>)> = None;
+    for &(connection, connection_len) in &nodes[&node] {
+        if visited_nodes.contains(&connection) {
+            continue;
+        }
+
+        let Some((len, route)) = longest_route(connection, target, visited_nodes, nodes) else {
+            continue;
+        };
+        if longest
+            .as_ref()
+            .is_none_or(|(longest_len, _)| len + connection_len > *longest_len)
+        {
+            let mut longest_route = vec![(connection, connection_len)];
+            longest_route.extend(route);
+            longest = Some((len + connection_len, longest_route));
+        }
+    }
+    visited_nodes.remove(&node);
+
+    longest
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::trim_newlines;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+";
+
+    #[test]
+    fn longest_hikes() -> Result<()> {
+        assert_eq!(render(trim_newlines(EXAMPLE), 1)?.count(HIGHLIGHT), 94 + 1);
+        assert_eq!(render(trim_newlines(EXAMPLE), 2)?.count(HIGHLIGHT), 154 + 1);
+
+        Ok(())
+    }
+}
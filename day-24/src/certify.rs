@@ -0,0 +1,46 @@
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+
+use cli::{
+    answer::{Answer, Overflow, try_sum},
+    certify::Certificate,
+};
+
+use crate::{HailstoneI128, check::Witness, throw_rock};
+
+/// Solves part 2 along with the rock and the time it hits each hailstone.
+pub fn certify(input: &str, part: u8) -> Result<Certificate> {
+    if part != 2 {
+        return Err(anyhow!("Cannot certify part {}", part));
+    }
+
+    let (position, velocity) = throw_rock(input)?;
+    let times = input
+        .lines()
+        .map(HailstoneI128::from_str)
+        .enumerate()
+        .map(|(index, hailstone)| {
+            let hailstone = hailstone?;
+
+            // The rock closes in on the hailstone along any axis where their velocities differ.
+            let axis = (0..3)
+                .find(|axis| hailstone.velocity[*axis] != velocity[*axis])
+                .ok_or_else(|| anyhow!("Cannot time the hit on line {}", index + 1))?;
+
+            Ok(position[axis]
+                .try_sub(hailstone.pos[axis])?
+                .checked_div(hailstone.velocity[axis].try_sub(velocity[axis])?)
+                .ok_or(Overflow("i128"))?)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Certificate::new(
+        try_sum([position.x, position.y, position.z])?,
+        &Witness {
+            position: position.into(),
+            velocity: velocity.into(),
+            times,
+        },
+    )
+}
@@ -0,0 +1,116 @@
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+use cli::{answer::try_sum, certify::Certificate};
+
+use crate::HailstoneI128;
+
+/// The throw of the rock, and when it hits each hailstone.
+#[derive(Deserialize, Serialize)]
+pub struct Witness {
+    /// [x, y, z]
+    pub position: [i128; 3],
+
+    /// [x, y, z]
+    pub velocity: [i128; 3],
+
+    /// The time of the hit on each hailstone, in the order of the input.
+    pub times: Vec<i128>,
+}
+
+/// Checks that the rock and each hailstone are in the same place at the time of the hit.
+pub fn check(input: &str, part: u8, certificate: &Certificate) -> Result<()> {
+    if part != 2 {
+        return Err(anyhow!("Cannot check part {}", part));
+    }
+
+    let hailstones = input
+        .lines()
+        .map(HailstoneI128::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+    let Witness {
+        position,
+        velocity,
+        times,
+    } = certificate.witness()?;
+    if times.len() != hailstones.len() {
+        return Err(anyhow!(
+            "{} times for {} hailstones",
+            times.len(),
+            hailstones.len()
+        ));
+    }
+
+    for (index, (hailstone, time)) in hailstones.iter().zip(times).enumerate() {
+        if time < 0 {
+            return Err(anyhow!("Hits line {} in the past", index + 1));
+        }
+
+        for axis in 0..3 {
+            let rock = time
+                .checked_mul(velocity[axis])
+                .and_then(|distance| distance.checked_add(position[axis]));
+            let hail = time
+                .checked_mul(hailstone.velocity[axis])
+                .and_then(|distance| distance.checked_add(hailstone.pos[axis]));
+            if rock.is_none() || rock != hail {
+                return Err(anyhow!("Misses line {} at time {}", index + 1, time));
+            }
+        }
+    }
+
+    certificate.expect_answer(try_sum(position)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::trim_newlines;
+
+    use crate::certify::certify;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3
+";
+
+    #[test]
+    fn example() -> Result<()> {
+        let input = trim_newlines(EXAMPLE);
+        let certificate = certify(input, 2)?;
+
+        assert_eq!(certificate.answer, "47");
+        assert_eq!(certificate.witness::<Witness>()?.times, [5, 3, 4, 6, 1]);
+        check(input, 2, &certificate)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_throws() -> Result<()> {
+        let input = trim_newlines(EXAMPLE);
+        let check_throw = |answer, velocity, times: &[i128]| {
+            let witness = Witness {
+                position: [24, 13, 10],
+                velocity,
+                times: times.to_vec(),
+            };
+
+            check(input, 2, &Certificate::new(answer, &witness)?)
+        };
+
+        check_throw(47, [-3, 1, 2], &[5, 3, 4, 6, 1])?;
+        assert!(check_throw(48, [-3, 1, 2], &[5, 3, 4, 6, 1]).is_err());
+        assert!(check_throw(47, [-3, 1, 2], &[5, 3, 4, 6, 2]).is_err());
+        assert!(check_throw(47, [-3, 1, 2], &[5, 3, 4, 6]).is_err());
+        assert!(check_throw(47, [-3, 1, 1], &[5, 3, 4, 6, 1]).is_err());
+
+        Ok(())
+    }
+}
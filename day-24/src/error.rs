@@ -0,0 +1,40 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind, NumberError, answer::Overflow};
+
+/// Why a day 24 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    #[error("Cannot split input into pos and velocity: {0}")]
+    MissingVelocity(String),
+
+    /// A pos or velocity that is not x, y and z.
+    #[error("Expected 3 components: {0}")]
+    NotThreeDimensional(String),
+
+    #[error(transparent)]
+    Number(#[from] NumberError),
+
+    /// Part 2 solves for the rock from the first 3 hailstones.
+    #[error("Cannot find rock from {0} hailstones")]
+    TooFewHailstones(usize),
+
+    /// The first 3 hailstones do not pin down a single throw, e.g. as they are parallel.
+    #[error("Cannot find rock from degenerate hailstones")]
+    Degenerate,
+
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::MissingVelocity(_) | Error::NotThreeDimensional(_) | Error::Number(_) => {
+                ErrorKind::Parse
+            }
+            Error::TooFewHailstones(_) => ErrorKind::Validation,
+            Error::Degenerate | Error::Overflow(_) => ErrorKind::Unsolvable,
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+
+use rand::{Rng, rngs::StdRng};
+
+use cli::Generated;
+
+const TEST_AREA: (i128, i128) = (200000000000000, 400000000000000);
+
+/// (pos, velocity)
+type Hailstone = ([i128; 3], [i128; 3]);
+
+/// Generates size hailstones, at least 3, that a rock thrown from a random pos in the test area will
+/// hit, each at its own time.
+///
+/// Each hailstone is placed where it will meet the rock at its time, so part 2 is known by
+/// construction. Part 1 is answered by checking each pair of paths in exact integer arithmetic.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let rock_pos: [i128; 3] =
+        std::array::from_fn(|_| rng.random_range(250000000000000..350000000000000));
+    let rock_velocity: [i128; 3] = std::array::from_fn(|_| rng.random_range(-300..=300));
+
+    let mut times = HashSet::new();
+    let mut hailstones = Vec::new();
+    while hailstones.len() < size.max(3) {
+        let time = rng.random_range(1..100000000000i128);
+        let velocity: [i128; 3] = std::array::from_fn(|_| rng.random_range(-300..=300));
+        if velocity == rock_velocity || !times.insert(time) {
+            continue;
+        }
+
+        let pos = std::array::from_fn(|axis| {
+            rock_pos[axis] + (rock_velocity[axis] - velocity[axis]) * time
+        });
+        hailstones.push((pos, velocity));
+    }
+
+    let mut crossings = 0;
+    for (index, hailstone) in hailstones.iter().enumerate() {
+        for other in &hailstones[index + 1..] {
+            if crosses_in_test_area(hailstone, other) {
+                crossings += 1;
+            }
+        }
+    }
+
+    let format = |values: &[i128; 3]| {
+        values
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    Generated {
+        input: hailstones
+            .iter()
+            .map(|(pos, velocity)| format!("{} @ {}", format(pos), format(velocity)))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        answers: [
+            Some(crossings.to_string()),
+            Some(rock_pos.iter().sum::<i128>().to_string()),
+        ],
+    }
+}
+
+/// Checks if the paths of the hailstones cross in the future, within the test area, ignoring z.
+///
+/// The times of crossing are kept as fractions over the same denominator, to stay exact.
+fn crosses_in_test_area(hailstone: &Hailstone, other: &Hailstone) -> bool {
+    let ([x, y, _], [vx, vy, _]) = *hailstone;
+    let ([other_x, other_y, _], [other_vx, other_vy, _]) = *other;
+
+    let denominator = other_vx * vy - vx * other_vy;
+    if denominator == 0 {
+        return false;
+    }
+    let (dx, dy) = (other_x - x, other_y - y);
+    let time = other_vx * dy - dx * other_vy;
+    let other_time = vx * dy - vy * dx;
+
+    // Flip the signs so the denominator is positive, and comparisons keep their direction.
+    let sign = denominator.signum();
+    let (denominator, time, other_time) = (denominator * sign, time * sign, other_time * sign);
+
+    let (min, max) = TEST_AREA;
+    let crossing_x = x * denominator + time * vx;
+    let crossing_y = y * denominator + time * vy;
+
+    time >= 0
+        && other_time >= 0
+        && (min * denominator..=max * denominator).contains(&crossing_x)
+        && (min * denominator..=max * denominator).contains(&crossing_y)
+}
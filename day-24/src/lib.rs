@@ -0,0 +1,303 @@
+use std::{fmt::Display, ops::RangeInclusive, str::FromStr};
+
+use itertools::Itertools;
+use nalgebra::{Scalar, Vector2, Vector3, matrix, vector};
+use serde::Serialize;
+
+use cli::{
+    Solver,
+    answer::{Answer, Overflow, try_sum},
+    certify::Certifier,
+    dump_lines, parse_number,
+};
+
+pub use error::Error;
+
+pub mod certify;
+pub mod check;
+mod error;
+pub mod generator;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 24,
+    version: env!("SOLVER_VERSION"),
+    part_1: |input, settings| {
+        let [min, max] = settings.get("test_area")?.unwrap_or(TEST_AREA);
+
+        Ok(test_collisions_2d(input, min..=max)?.to_string())
+    },
+    // Part 2 already solves in i128, the widest type there is, checking every step.
+    part_2: Some(|input, _| Ok(part_2(input)?.to_string())),
+    generator: generator::generate,
+    render: None,
+    animate: None,
+    dump: Some(|input| dump_lines(input, Hailstone::from_str)),
+    stream: None,
+    certify: Some(Certifier {
+        parts: &[2],
+        witness: certify::certify,
+        check: check::check,
+    }),
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+/// [min, max] of x and y in the test area, which can be overridden by test_area in aoc.toml.
+pub const TEST_AREA: [f64; 2] = [200000000000000.0, 400000000000000.0];
+
+pub fn part_1(input: &str) -> Result<usize, Error> {
+    let [min, max] = TEST_AREA;
+
+    test_collisions_2d(input, min..=max)
+}
+
+pub fn part_2(input: &str) -> Result<i128, Error> {
+    let (p, _) = throw_rock(input)?;
+
+    Ok(try_sum([p.x, p.y, p.z])?)
+}
+
+/// Finds the position and the velocity of the rock that hits every hailstone.
+pub fn throw_rock(input: &str) -> Result<(Vector3<i128>, Vector3<i128>), Error> {
+    // Can't solve this. See
+    // https://www.reddit.com/r/adventofcode/comments/18pnycy/2023_day_24_solutions/.
+    //
+    // Have to use i128 matrices to overcome floating point errors.
+
+    let hailstones = input
+        .lines()
+        .map(HailstoneI128::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+    if hailstones.len() < 3 {
+        return Err(Error::TooFewHailstones(hailstones.len()));
+    }
+
+    // p1 = position_1 - position_0
+    // v1 = velocity_1 - velocity_0
+    // p2 = position_2 - position_0
+    // v2 = velocity_2 - velocity_0
+
+    let [h0, h1, h2] = [&hailstones[0], &hailstones[1], &hailstones[2]];
+    let p1 = try_zip(&h1.pos, &h0.pos, i128::try_sub)?;
+    let v1 = try_zip(&h1.velocity, &h0.velocity, i128::try_sub)?;
+    let p2 = try_zip(&h2.pos, &h0.pos, i128::try_sub)?;
+    let v2 = try_zip(&h2.velocity, &h0.velocity, i128::try_sub)?;
+    let p1_x_p2 = try_cross(&p1, &p2)?;
+
+    // t1 = -((p1 x p2) * v2) / ((v1 x p2) * v2)
+
+    let t1 = 0i128
+        .try_sub(try_dot(&p1_x_p2, &v2)?)?
+        .checked_div(try_dot(&try_cross(&v1, &p2)?, &v2)?)
+        .ok_or(Error::Degenerate)?;
+
+    // t2 = -((p1 x p2) * v1) / ((p1 x v2) * v1)
+
+    let t2 = 0i128
+        .try_sub(try_dot(&p1_x_p2, &v1)?)?
+        .checked_div(try_dot(&try_cross(&p1, &v2)?, &v1)?)
+        .ok_or(Error::Degenerate)?;
+    if t1 == t2 {
+        return Err(Error::Degenerate);
+    }
+
+    // c1 = position_1 + t1 * velocity_1
+    // c2 = position_2 + t2 * velocity_2
+
+    let c1 = try_zip(&h1.pos, &h1.velocity, |pos, velocity| {
+        pos.try_add(t1.try_mul(velocity)?)
+    })?;
+    let c2 = try_zip(&h2.pos, &h2.velocity, |pos, velocity| {
+        pos.try_add(t2.try_mul(velocity)?)
+    })?;
+
+    // v = (c2 - c1) / (t2 - t1)
+    // p = c1 - t1 * v
+
+    let dt = t2.try_sub(t1)?;
+    let v = try_zip(&c2, &c1, |c2, c1| {
+        c2.try_sub(c1)?.checked_div(dt).ok_or(Overflow("i128"))
+    })?;
+    let p = try_zip(&c1, &v, |c1, v| c1.try_sub(t1.try_mul(v)?))?;
+
+    Ok((p, v))
+}
+
+/// Combines a and b component by component, failing on [Overflow]. Matrix arithmetic on i128 is
+/// unchecked, so the rock is thrown with these instead.
+fn try_zip(
+    a: &Vector3<i128>,
+    b: &Vector3<i128>,
+    op: impl Fn(i128, i128) -> Result<i128, Overflow>,
+) -> Result<Vector3<i128>, Overflow> {
+    Ok(Vector3::new(op(a.x, b.x)?, op(a.y, b.y)?, op(a.z, b.z)?))
+}
+
+/// a x b, failing on [Overflow].
+fn try_cross(a: &Vector3<i128>, b: &Vector3<i128>) -> Result<Vector3<i128>, Overflow> {
+    Ok(Vector3::new(
+        a.y.try_mul(b.z)?.try_sub(a.z.try_mul(b.y)?)?,
+        a.z.try_mul(b.x)?.try_sub(a.x.try_mul(b.z)?)?,
+        a.x.try_mul(b.y)?.try_sub(a.y.try_mul(b.x)?)?,
+    ))
+}
+
+/// a . b, failing on [Overflow].
+fn try_dot(a: &Vector3<i128>, b: &Vector3<i128>) -> Result<i128, Overflow> {
+    try_sum(
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| a.try_mul(*b))
+            .collect::<Result<Vec<_>, _>>()?,
+    )
+}
+
+#[derive(Clone, Copy, Serialize)]
+pub struct Hailstone {
+    pub pos: Vector3<f64>,
+    pub velocity: Vector3<f64>,
+}
+
+impl FromStr for Hailstone {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let Some((pos, velocity)) = s.split_once(" @ ") else {
+            return Err(Error::MissingVelocity(s.to_string()));
+        };
+
+        let pos = parse_vector(pos)?;
+        let velocity = parse_vector(velocity)?;
+
+        Ok(Self { pos, velocity })
+    }
+}
+
+pub struct HailstoneI128 {
+    pub pos: Vector3<i128>,
+    pub velocity: Vector3<i128>,
+}
+
+impl FromStr for HailstoneI128 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let Some((pos, velocity)) = s.split_once(" @ ") else {
+            return Err(Error::MissingVelocity(s.to_string()));
+        };
+
+        let pos = parse_vector(pos)?;
+        let velocity = parse_vector(velocity)?;
+
+        Ok(Self { pos, velocity })
+    }
+}
+
+/// Parses comma-separated x, y and z.
+fn parse_vector<T>(s: &str) -> Result<Vector3<T>, Error>
+where
+    T: FromStr + Scalar,
+    T::Err: Display,
+{
+    let components = s
+        .split_terminator(",")
+        .map(str::trim)
+        .map(parse_number::<T>)
+        .collect::<Result<Vec<_>, _>>()?;
+    if components.len() != 3 {
+        return Err(Error::NotThreeDimensional(s.to_string()));
+    }
+
+    Ok(Vector3::from_vec(components))
+}
+
+pub fn test_collisions_2d(input: &str, range: RangeInclusive<f64>) -> Result<usize, Error> {
+    Ok(input
+        .lines()
+        .map(Hailstone::from_str)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .tuple_combinations()
+        .filter(|(hailstone, other)| {
+            if let Some(solution) = solve_2d(hailstone, other) {
+                let x = hailstone.pos.x + solution[0] * hailstone.velocity.x;
+                let y = hailstone.pos.y + solution[0] * hailstone.velocity.y;
+
+                solution[0] >= 0.0 && solution[1] >= 0.0 && range.contains(&x) && range.contains(&y)
+            } else {
+                false
+            }
+        })
+        .count())
+}
+
+pub fn solve_2d(hailstone: &Hailstone, other: &Hailstone) -> Option<Vector2<f64>> {
+    //    - p_x_hail + t_hail * v_x_hail = p_x_other + t_other * v_x_other
+    //    - p_y_hail + t_hail * v_y_hail = p_y_other + t_other * v_y_other
+    // => - v_x_hail * t_hail - v_x_other * t_other = p_x_other - p_x_hail
+    //    - v_y_hail * t_hail - v_y_other * t_other = p_y_other - p_y_hail
+    // Solve for t_hail and t_other.
+
+    let coefficients = matrix![
+        hailstone.velocity.x, -other.velocity.x;
+        hailstone.velocity.y, -other.velocity.y;
+    ];
+    let constants = vector![other.pos.x - hailstone.pos.x, other.pos.y - hailstone.pos.y];
+
+    coefficients.lu().solve(&constants)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::trim_newlines;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3
+";
+
+    #[test]
+    fn example_1() -> Result<()> {
+        assert_eq!(test_collisions_2d(trim_newlines(EXAMPLE), 7.0..=27.0)?, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_area_setting() -> Result<()> {
+        let settings = "test_area = [7, 27]".parse()?;
+
+        assert_eq!(
+            SOLVER.solve_with(1, trim_newlines(EXAMPLE), &settings)?,
+            "2"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        assert_eq!(part_2(trim_newlines(EXAMPLE))?, 47);
+
+        Ok(())
+    }
+
+    #[test]
+    fn too_few_hailstones() {
+        assert_eq!(
+            part_2("19, 13, 30 @ -2,  1, -2"),
+            Err(Error::TooFewHailstones(1))
+        );
+        assert_eq!(
+            part_2("19, 13 @ -2,  1, -2"),
+            Err(Error::NotThreeDimensional(String::from("19, 13")))
+        );
+    }
+}
@@ -0,0 +1,33 @@
+use anyhow::{Result, anyhow};
+
+use cli::certify::Certificate;
+
+use crate::{bisect, check::Witness, parse_input_into_edges};
+
+/// Solves part 1 along with the 3 wires to cut.
+pub fn certify(input: &str, part: u8) -> Result<Certificate> {
+    if part != 1 {
+        return Err(anyhow!("Cannot certify part {}", part));
+    }
+
+    let edges = parse_input_into_edges(input)?;
+    let (left_partition, right_partition) = bisect(&edges)?;
+    let mut cut = left_partition
+        .iter()
+        .flat_map(|vertex| {
+            edges[vertex]
+                .iter()
+                .filter(|connected| right_partition.contains(connected))
+                .map(|connected| [vertex.to_string(), connected.to_string()])
+        })
+        .collect::<Vec<_>>();
+    cut.sort();
+    if cut.len() != 3 {
+        return Err(anyhow!("Cuts {} wires rather than 3", cut.len()));
+    }
+
+    Certificate::new(
+        left_partition.len() * right_partition.len(),
+        &Witness { cut },
+    )
+}
@@ -0,0 +1,118 @@
+use std::collections::{BTreeSet, HashSet};
+
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+use cli::certify::Certificate;
+
+use crate::parse_input_into_edges;
+
+/// The wires that split the components into 2 groups once cut.
+#[derive(Deserialize, Serialize)]
+pub struct Witness {
+    pub cut: Vec<[String; 2]>,
+}
+
+/// Cuts the 3 wires, and checks that the components that are still connected form 2 groups.
+pub fn check(input: &str, part: u8, certificate: &Certificate) -> Result<()> {
+    if part != 1 {
+        return Err(anyhow!("Cannot check part {}", part));
+    }
+
+    let mut edges = parse_input_into_edges(input)?;
+    let Witness { cut } = certificate.witness()?;
+    if cut.len() != 3 {
+        return Err(anyhow!("Cuts {} wires rather than 3", cut.len()));
+    }
+    for [vertex, connected] in &cut {
+        let is_wire = edges
+            .get_mut(vertex.as_str())
+            .is_some_and(|vertices| vertices.remove(connected.as_str()));
+        if !is_wire {
+            return Err(anyhow!("No wire between {} and {}", vertex, connected));
+        }
+        if let Some(vertices) = edges.get_mut(connected.as_str()) {
+            vertices.remove(vertex.as_str());
+        }
+    }
+
+    let mut unvisited = edges.keys().copied().collect::<BTreeSet<_>>();
+    let mut group_sizes = Vec::new();
+    while let Some(first) = unvisited.pop_first() {
+        let mut group = HashSet::from([first]);
+        let mut queue = vec![first];
+        while let Some(vertex) = queue.pop() {
+            for connected in &edges[vertex] {
+                if group.insert(*connected) {
+                    unvisited.remove(connected);
+                    queue.push(connected);
+                }
+            }
+        }
+        group_sizes.push(group.len());
+    }
+    let &[left, right] = group_sizes.as_slice() else {
+        return Err(anyhow!("Leaves {} groups rather than 2", group_sizes.len()));
+    };
+
+    certificate.expect_answer(left * right)
+}
+
+#[cfg(test)]
+mod tests {
+    use cli::trim_newlines;
+
+    use crate::certify::certify;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr
+";
+
+    #[test]
+    fn example() -> Result<()> {
+        let input = trim_newlines(EXAMPLE);
+        let certificate = certify(input, 1)?;
+
+        assert_eq!(certificate.answer, "54");
+        check(input, 1, &certificate)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_cuts() -> Result<()> {
+        let input = trim_newlines(EXAMPLE);
+        let check_cut = |answer, cut: &[[&str; 2]]| {
+            let witness = Witness {
+                cut: cut
+                    .iter()
+                    .map(|wire| wire.map(|vertex| vertex.to_string()))
+                    .collect(),
+            };
+
+            check(input, 1, &Certificate::new(answer, &witness)?)
+        };
+
+        check_cut(54, &[["hfx", "pzl"], ["bvb", "cmg"], ["nvd", "jqt"]])?;
+        assert!(check_cut(53, &[["hfx", "pzl"], ["bvb", "cmg"], ["nvd", "jqt"]]).is_err());
+        assert!(check_cut(54, &[["hfx", "pzl"], ["bvb", "cmg"], ["nvd", "lhk"]]).is_err());
+        assert!(check_cut(54, &[["hfx", "pzl"], ["bvb", "cmg"], ["nvd", "rzs"]]).is_err());
+        assert!(check_cut(54, &[["hfx", "pzl"], ["bvb", "cmg"]]).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind, answer::Overflow};
+
+/// Why a day 25 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    #[error("Cannot split input into vertex and connected vertices: {0}")]
+    InvalidLine(String),
+
+    /// There must be components on both sides of the cut.
+    #[error("Cannot split {0} components into 2 groups")]
+    TooFewComponents(usize),
+
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::InvalidLine(_) => ErrorKind::Parse,
+            Error::TooFewComponents(_) | Error::Overflow(_) => ErrorKind::Unsolvable,
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use std::collections::{BTreeMap, HashSet};
+
+use rand::{
+    Rng,
+    rngs::StdRng,
+    seq::{IndexedRandom, SliceRandom},
+};
+
+use cli::Generated;
+
+/// Generates 2 groups of about size components each, at least 5, joined by exactly 3 wires.
+///
+/// Each group links every component to the next 2 around a ring, then adds random wires, so it
+/// cannot be split by cutting fewer than 4 wires. The 3 wires between the groups are then the only
+/// cut of 3 wires, and part 1 is the product of the group sizes by construction.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let sizes = [
+        rng.random_range(size.max(5)..=2 * size.max(5)),
+        rng.random_range(size.max(5)..=2 * size.max(5)),
+    ];
+
+    let mut ids = ids(sizes[0] + sizes[1]);
+    ids.shuffle(rng);
+
+    let mut groups = Vec::new();
+    let mut wires = Vec::new();
+    for size in sizes {
+        let components = ids.split_off(ids.len() - size);
+
+        let mut group_wires = HashSet::new();
+        for index in 0..size {
+            for step in 1..=2 {
+                let (a, b) = (index, (index + step) % size);
+                group_wires.insert((a.min(b), a.max(b)));
+            }
+        }
+        for _ in 0..size {
+            let (a, b) = (rng.random_range(0..size), rng.random_range(0..size));
+            if a != b {
+                group_wires.insert((a.min(b), a.max(b)));
+            }
+        }
+        wires.extend(
+            group_wires
+                .into_iter()
+                .map(|(a, b)| (components[a].clone(), components[b].clone())),
+        );
+        groups.push(components);
+    }
+
+    let ends = [
+        groups[0]
+            .choose_multiple(rng, 3)
+            .cloned()
+            .collect::<Vec<_>>(),
+        groups[1]
+            .choose_multiple(rng, 3)
+            .cloned()
+            .collect::<Vec<_>>(),
+    ];
+    wires.extend(ends[0].iter().cloned().zip(ends[1].iter().cloned()));
+
+    // List each wire under either of its components.
+    let mut lines: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    wires.sort();
+    for (a, b) in wires {
+        let (from, to) = if rng.random_bool(0.5) { (a, b) } else { (b, a) };
+        lines.entry(from).or_default().push(to);
+    }
+    let mut lines = lines
+        .into_iter()
+        .map(|(from, to)| format!("{}: {}", from, to.join(" ")))
+        .collect::<Vec<_>>();
+    lines.shuffle(rng);
+
+    Generated {
+        input: lines.join("\n"),
+        answers: [Some((sizes[0] * sizes[1]).to_string()), None],
+    }
+}
+
+/// Names count components by counting in base 26 with the letters a to z, in as many letters as
+/// it takes, but at least 3 as in the puzzle.
+fn ids(count: usize) -> Vec<String> {
+    let mut len = 3;
+    while 26usize.checked_pow(len).is_some_and(|ids| ids < count) {
+        len += 1;
+    }
+
+    (0..count)
+        .map(|mut number| {
+            let mut id = vec![b'a'; len as usize];
+            for letter in id.iter_mut().rev() {
+                *letter += (number % 26) as u8;
+                number /= 26;
+            }
+
+            String::from_utf8_lossy(&id).into_owned()
+        })
+        .collect()
+}
@@ -0,0 +1,167 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use nalgebra::{DMatrix, DVector};
+
+use cli::{
+    Solver,
+    answer::{Answer, widen, with_width},
+    certify::Certifier,
+    to_json,
+};
+
+pub use error::Error;
+
+pub mod certify;
+pub mod check;
+mod error;
+pub mod generator;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 25,
+    version: env!("SOLVER_VERSION"),
+    part_1: |input, settings| with_width(settings, || part_1(input), || part_1_as::<u128>(input)),
+    part_2: None,
+    generator: generator::generate,
+    render: None,
+    animate: None,
+    dump: Some(|input| {
+        let edges = parse_input_into_edges(input)?
+            .into_iter()
+            .map(|(vertex, connected)| (vertex, connected.into_iter().collect::<BTreeSet<_>>()))
+            .collect::<HashMap<_, _>>();
+
+        to_json(&edges)
+    }),
+    stream: None,
+    certify: Some(Certifier {
+        parts: &[1],
+        witness: certify::certify,
+        check: check::check,
+    }),
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<usize, Error> {
+    part_1_as(input)
+}
+
+/// Multiplies the sizes of the 2 groups in A.
+pub fn part_1_as<A: Answer + TryFrom<usize>>(input: &str) -> Result<A, Error> {
+    let edges = parse_input_into_edges(input)?;
+    let (left_partition, right_partition) = bisect(&edges)?;
+
+    Ok(widen::<A, _>(left_partition.len())?.try_mul(widen(right_partition.len())?)?)
+}
+
+/// Splits the vertices into 2 groups with as few wires between them as possible.
+pub fn bisect<'a>(
+    edges: &HashMap<&'a str, HashSet<&'a str>>,
+) -> Result<(Vec<&'a str>, Vec<&'a str>), Error> {
+    // See
+    // https://en.wikipedia.org/wiki/Graph_partition#Spectral_partitioning_and_spectral_bisection.
+    //
+    // We are going to assume the graph will be bisected by cutting some 3 wires, as we only care
+    // about the count on either side of the partition.
+
+    let vertices = edges.keys().copied().collect::<Vec<_>>();
+    if vertices.len() < 2 {
+        return Err(Error::TooFewComponents(vertices.len()));
+    }
+
+    let degree_matrix = DMatrix::from_diagonal(&DVector::from_iterator(
+        vertices.len(),
+        vertices.iter().map(|vertex| edges[vertex].len() as f64),
+    ));
+    let adjacency_matrix = DMatrix::from_fn(vertices.len(), vertices.len(), |row, col| {
+        if row == col {
+            return 0.0f64;
+        }
+
+        if edges[vertices[row]].contains(vertices[col]) {
+            1.0
+        } else {
+            0.0
+        }
+    });
+    let laplacian_matrix = degree_matrix - adjacency_matrix;
+
+    let symmetric_eigen = laplacian_matrix.symmetric_eigen();
+    let (_, (pivot, _)) = symmetric_eigen.eigenvalues.iter().enumerate().fold(
+        ((0, f64::MAX), (0, f64::MAX)),
+        |acc, (index, eigenvalue)| {
+            if *eigenvalue < acc.0.1 {
+                ((index, *eigenvalue), acc.0)
+            } else if *eigenvalue < acc.1.1 {
+                (acc.0, (index, *eigenvalue))
+            } else {
+                acc
+            }
+        },
+    );
+    let eigenvector = symmetric_eigen.eigenvectors.column(pivot);
+    let (left_partition, right_partition) = vertices
+        .into_iter()
+        .zip(eigenvector.iter())
+        .partition::<Vec<_>, _>(|(_, component)| **component > 0.0);
+
+    Ok((
+        left_partition
+            .into_iter()
+            .map(|(vertex, _)| vertex)
+            .collect(),
+        right_partition
+            .into_iter()
+            .map(|(vertex, _)| vertex)
+            .collect(),
+    ))
+}
+
+pub fn parse_input_into_edges(input: &str) -> Result<HashMap<&str, HashSet<&str>>, Error> {
+    let mut edges: HashMap<&str, HashSet<&str>> = HashMap::new();
+
+    for line in input.lines() {
+        let Some((vertex, connected_vertices)) = line.split_once(": ") else {
+            return Err(Error::InvalidLine(line.to_string()));
+        };
+
+        for connected_vertex in connected_vertices.split_whitespace() {
+            edges.entry(vertex).or_default().insert(connected_vertex);
+            edges.entry(connected_vertex).or_default().insert(vertex);
+        }
+    }
+
+    Ok(edges)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::trim_newlines;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr
+";
+
+    #[test]
+    fn example_1() -> Result<()> {
+        assert_eq!(part_1(trim_newlines(EXAMPLE))?, 54);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind, answer::Overflow};
+
+/// Why a day 3 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    /// The schematic must be as tall as it is wide.
+    #[error("Schematic is not square: {0}")]
+    NotSquare(String),
+
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::NotSquare(_) => ErrorKind::Parse,
+            Error::Overflow(_) => ErrorKind::Unsolvable,
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+
+use rand::{Rng, rngs::StdRng, seq::IndexedRandom};
+
+use cli::Generated;
+
+const SYMBOLS: &[u8] = b"*#+$/@=%&";
+
+/// Generates a size x size schematic of numbers and symbols.
+///
+/// Every number is distinct, since the solver tells numbers apart by value.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let mut layout = vec![vec![b'.'; size]; size];
+    let mut used = HashSet::new();
+    for row in layout.iter_mut() {
+        let mut col = rng.random_range(0..3);
+        while col < size {
+            let len = rng.random_range(1..=3).min(size - col);
+            let min = 10u32.pow(len as u32 - 1);
+            let number = rng.random_range(min..min * 10);
+            if used.insert(number) {
+                for (offset, digit) in number.to_string().bytes().enumerate() {
+                    row[col + offset] = digit;
+                }
+            }
+
+            // Leave at least one gap, so numbers do not merge.
+            col += len + rng.random_range(1..=4);
+        }
+    }
+    for row in layout.iter_mut() {
+        for tile in row.iter_mut() {
+            if *tile == b'.' && rng.random_bool(0.1) {
+                *tile = *SYMBOLS.choose(rng).unwrap();
+            }
+        }
+    }
+
+    // (row, left col, right col, value)
+    let mut numbers = Vec::new();
+    for (row, tiles) in layout.iter().enumerate() {
+        let mut col = 0;
+        while col < size {
+            if tiles[col].is_ascii_digit() {
+                let left = col;
+                let mut value = 0;
+                while col < size && tiles[col].is_ascii_digit() {
+                    value = value * 10 + (tiles[col] - b'0') as u32;
+                    col += 1;
+                }
+                numbers.push((row, left, col - 1, value));
+            } else {
+                col += 1;
+            }
+        }
+    }
+
+    let is_adjacent = |number: &(usize, usize, usize, u32), row: usize, col: usize| {
+        number.0.abs_diff(row) <= 1 && number.1 <= col + 1 && col <= number.2 + 1
+    };
+
+    let mut sum_parts = 0;
+    let mut sum_ratios = 0;
+    for (row, tiles) in layout.iter().enumerate() {
+        for (col, &tile) in tiles.iter().enumerate() {
+            if tile == b'.' || tile.is_ascii_digit() {
+                continue;
+            }
+
+            let adjacent = numbers
+                .iter()
+                .filter(|number| is_adjacent(number, row, col))
+                .collect::<Vec<_>>();
+            if tile == b'*' && adjacent.len() == 2 {
+                sum_ratios += adjacent[0].3 * adjacent[1].3;
+            }
+        }
+    }
+    for number in &numbers {
+        let has_symbol = (number.0.saturating_sub(1)..=(number.0 + 1).min(size - 1)).any(|row| {
+            (number.1.saturating_sub(1)..=(number.2 + 1).min(size - 1)).any(|col| {
+                let tile = layout[row][col];
+                tile != b'.' && !tile.is_ascii_digit()
+            })
+        });
+        if has_symbol {
+            sum_parts += number.3;
+        }
+    }
+
+    let input = layout
+        .into_iter()
+        .map(|row| String::from_utf8(row).unwrap())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Generated {
+        input,
+        answers: [Some(sum_parts.to_string()), Some(sum_ratios.to_string())],
+    }
+}
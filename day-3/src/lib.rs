@@ -0,0 +1,247 @@
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use cli::{
+    Solver,
+    answer::{Answer, Overflow, try_product, try_sum, with_width},
+    to_json,
+};
+use geometry::{Point, Size, Vector};
+
+pub use error::Error;
+
+mod error;
+pub mod generator;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 3,
+    version: env!("SOLVER_VERSION"),
+    part_1: |input, settings| with_width(settings, || part_1(input), || part_1_as::<u128>(input)),
+    part_2: Some(|input, settings| {
+        with_width(settings, || part_2(input), || part_2_as::<u128>(input))
+    }),
+    generator: generator::generate,
+    render: None,
+    animate: None,
+    dump: Some(|input| to_json(&Grid::from_str(input)?)),
+    stream: None,
+    certify: None,
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<u32, Error> {
+    part_1_as(input)
+}
+
+pub fn part_2(input: &str) -> Result<u32, Error> {
+    part_2_as(input)
+}
+
+/// Sums the part numbers in A.
+pub fn part_1_as<A: Answer>(input: &str) -> Result<A, Error> {
+    let grid = Grid::from_str(input)?;
+
+    let mut part_numbers = grid
+        .bounds()
+        .points()
+        .filter_map(|point| get_part_number(point, &grid))
+        .collect::<Vec<_>>();
+    part_numbers.dedup();
+
+    Ok(try_sum(
+        part_numbers.into_iter().collect::<Result<Vec<A>, _>>()?,
+    )?)
+}
+
+/// Sums the gear ratios in A.
+pub fn part_2_as<A: Answer>(input: &str) -> Result<A, Error> {
+    let grid = Grid::from_str(input)?;
+
+    let gear_ratios = grid
+        .bounds()
+        .points()
+        .filter_map(|point| get_gear_ratio(point, &grid))
+        .collect::<Result<Vec<A>, _>>()?;
+
+    Ok(try_sum(gear_ratios)?)
+}
+
+#[derive(Serialize)]
+pub struct Grid {
+    #[serde(serialize_with = "cli::serialize_rows")]
+    pub layout: Vec<Vec<u8>>,
+    pub size: usize,
+}
+
+impl FromStr for Grid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let lines = s.lines().collect::<Vec<_>>();
+
+        let size = lines.first().map_or(0, |line| line.len());
+        if let Some(line) = lines.iter().find(|line| line.len() != size) {
+            return Err(Error::NotSquare(line.to_string()));
+        }
+        if lines.len() != size {
+            return Err(Error::NotSquare(format!(
+                "{} rows of {}",
+                lines.len(),
+                size
+            )));
+        }
+
+        let layout = lines
+            .into_iter()
+            .map(|line| line.bytes().collect())
+            .collect();
+
+        Ok(Self { layout, size })
+    }
+}
+
+impl Grid {
+    pub fn bounds(&self) -> Size {
+        Size::square(self.size)
+    }
+}
+
+/// The [Vector]s to every neighbour, diagonals included, starting from NW, clockwise.
+const NEIGHBOURS: [Vector; 8] = [
+    Vector::new(-1, -1),
+    Vector::new(-1, 0),
+    Vector::new(-1, 1),
+    Vector::new(0, 1),
+    Vector::new(1, 1),
+    Vector::new(1, 0),
+    Vector::new(1, -1),
+    Vector::new(0, -1),
+];
+
+/// Gets all neighbouring [Point]s within the grid, starting from NW, clockwise.
+fn neighbours(point: Point, grid: &Grid) -> impl Iterator<Item = Point> {
+    let bounds = grid.bounds();
+
+    NEIGHBOURS
+        .into_iter()
+        .filter_map(move |vector| point.offset(vector, bounds))
+}
+
+/// Converts the digits between left and right [Point]s (inclusive) into a number.
+///
+/// left and right must be on the same row, and left col <= right col.
+fn as_number<A: Answer>(left: Point, right: Point, grid: &Grid) -> Result<A, Overflow> {
+    let mut part_number = A::default();
+    for col in left.col..=right.col {
+        part_number = part_number
+            .try_mul(A::from(10))?
+            .try_add(A::from(grid.layout[left.row][col] - b'0'))?;
+    }
+
+    Ok(part_number)
+}
+
+pub fn get_part_number<A: Answer>(point: Point, grid: &Grid) -> Option<Result<A, Overflow>> {
+    let Point { row, col } = point;
+
+    if !grid.layout[row][col].is_ascii_digit() {
+        return None;
+    }
+
+    if !neighbours(point, grid).any(|neighbour| {
+        let byte = grid.layout[neighbour.row][neighbour.col];
+
+        byte != b'.' && byte.is_ascii_punctuation()
+    }) {
+        return None;
+    }
+
+    let mut left_col = col;
+    while left_col > 0 && grid.layout[row][left_col - 1].is_ascii_digit() {
+        left_col -= 1;
+    }
+    let mut right_col = col;
+    while right_col < grid.size - 1 && grid.layout[row][right_col + 1].is_ascii_digit() {
+        right_col += 1;
+    }
+
+    Some(as_number(
+        Point::new(row, left_col),
+        Point::new(row, right_col),
+        grid,
+    ))
+}
+
+pub fn get_gear_ratio<A: Answer>(point: Point, grid: &Grid) -> Option<Result<A, Overflow>> {
+    if grid.layout[point.row][point.col] != b'*' {
+        return None;
+    }
+
+    let mut part_numbers = neighbours(point, grid)
+        .filter_map(|neighbour| get_part_number(neighbour, grid))
+        .collect::<Vec<_>>();
+    part_numbers.dedup();
+
+    if part_numbers.len() == 2 {
+        Some(
+            part_numbers
+                .into_iter()
+                .collect::<Result<Vec<A>, _>>()
+                .and_then(try_product),
+        )
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::trim_newlines;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+    #[test]
+    fn example_1() -> Result<()> {
+        assert_eq!(part_1(trim_newlines(EXAMPLE))?, 4361);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        assert_eq!(part_2(trim_newlines(EXAMPLE))?, 467835);
+
+        Ok(())
+    }
+
+    #[test]
+    fn overflow() -> Result<()> {
+        let input = [String::from("99999999999*")]
+            .into_iter()
+            .chain((0..11).map(|_| ".".repeat(12)))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert_eq!(part_1(&input), Err(Error::Overflow(Overflow("u32"))));
+        assert_eq!(part_1_as::<u128>(&input)?, 99999999999);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind, NumberError, answer::Overflow};
+
+/// Why a day 4 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    #[error("Cannot split input to get ID: {0}")]
+    MissingId(String),
+
+    #[error("Cannot split input into winning numbers and numbers: {0}")]
+    MissingSeparator(String),
+
+    #[error(transparent)]
+    Number(#[from] NumberError),
+
+    /// Cards must be numbered from 1 in order, as winning copies the cards that follow.
+    #[error("Card {id} is out of order, expected card {expected}")]
+    OutOfOrder { id: usize, expected: usize },
+
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::MissingId(_) | Error::MissingSeparator(_) | Error::Number(_) => ErrorKind::Parse,
+            Error::OutOfOrder { .. } => ErrorKind::Validation,
+            Error::Overflow(_) => ErrorKind::Unsolvable,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use rand::{Rng, rngs::StdRng, seq::SliceRandom};
+
+use cli::Generated;
+
+const NUM_WINNING_NUMBERS: usize = 10;
+const NUM_NUMBERS: usize = 25;
+
+/// The most copies any one card may win, keeping the total num of cards small.
+const MAX_COPIES: u32 = 100000;
+
+/// Generates size cards of 10 winning numbers and 25 numbers.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    // Choose the overlaps from the last card back, so that the num of cards won by each card is known
+    // before choosing the overlap of any card before it.
+    let mut overlaps = vec![0; size];
+    let mut cards_won = vec![1u32; size];
+    for id in (0..size).rev() {
+        let max_overlap = NUM_WINNING_NUMBERS.min(size - 1 - id);
+        let overlap = if rng.random_bool(0.5) {
+            0
+        } else {
+            rng.random_range(0..=max_overlap)
+        };
+
+        let mut total = 1;
+        for (index, won) in cards_won.iter().enumerate().skip(id + 1).take(overlap) {
+            if total + won > MAX_COPIES {
+                break;
+            }
+            total += won;
+            overlaps[id] = index - id;
+        }
+        cards_won[id] = total;
+    }
+
+    let mut lines = Vec::new();
+    let mut points = 0;
+    for (id, overlap) in overlaps.into_iter().enumerate() {
+        let mut pool = (1..100).collect::<Vec<u8>>();
+        pool.shuffle(rng);
+        let winning_numbers = &pool[..NUM_WINNING_NUMBERS];
+        let mut numbers = winning_numbers[..overlap].to_vec();
+        numbers.extend(&pool[NUM_WINNING_NUMBERS..NUM_WINNING_NUMBERS + NUM_NUMBERS - overlap]);
+        numbers.shuffle(rng);
+
+        if overlap > 0 {
+            points += 1 << (overlap - 1);
+        }
+
+        let format = |numbers: &[u8]| {
+            numbers
+                .iter()
+                .map(|number| format!("{number:>2}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        lines.push(format!(
+            "Card {:>3}: {} | {}",
+            id + 1,
+            format(winning_numbers),
+            format(&numbers)
+        ));
+    }
+
+    Generated {
+        input: lines.join("\n"),
+        answers: [
+            Some(points.to_string()),
+            Some(cards_won.iter().sum::<u32>().to_string()),
+        ],
+    }
+}
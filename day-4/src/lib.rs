@@ -0,0 +1,232 @@
+use std::{collections::HashSet, str::FromStr};
+
+use serde::Serialize;
+
+use cli::{
+    Solver,
+    answer::{Answer, Overflow, try_sum, with_width},
+    dump_lines, parse_number,
+};
+
+pub use error::Error;
+
+mod error;
+pub mod generator;
+pub mod stream;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 4,
+    version: env!("SOLVER_VERSION"),
+    part_1: |input, settings| with_width(settings, || part_1(input), || part_1_as::<u128>(input)),
+    part_2: Some(|input, settings| {
+        with_width(settings, || part_2(input), || part_2_as::<u128>(input))
+    }),
+    generator: generator::generate,
+    render: None,
+    animate: None,
+    dump: Some(|input| dump_lines(input, Card::from_str)),
+    stream: Some(stream::stream),
+    certify: None,
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<u32, Error> {
+    part_1_as(input)
+}
+
+pub fn part_2(input: &str) -> Result<u32, Error> {
+    part_2_as(input)
+}
+
+/// Sums the points of the cards in A.
+pub fn part_1_as<A: Answer>(input: &str) -> Result<A, Error> {
+    let points = input
+        .lines()
+        .map(|line| Ok(Card::from_str(line)?.points()?))
+        .collect::<Result<Vec<A>, Error>>()?;
+
+    Ok(try_sum(points)?)
+}
+
+/// Counts the cards won in A.
+pub fn part_2_as<A: Answer>(input: &str) -> Result<A, Error> {
+    let cards = input
+        .lines()
+        .map(Card::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+    for (index, card) in cards.iter().enumerate() {
+        if card.id != index + 1 {
+            return Err(Error::OutOfOrder {
+                id: card.id,
+                expected: index + 1,
+            });
+        }
+    }
+
+    let mut card_count = vec![A::from(1); cards.len()];
+    for card in cards {
+        let copies = card_count[card.id - 1]; // id starts from 1
+
+        for count in card_count.iter_mut().skip(card.id).take(card.overlap()) {
+            *count = count.try_add(copies)?;
+        }
+    }
+
+    Ok(try_sum(card_count)?)
+}
+
+#[derive(Serialize)]
+pub struct Card {
+    pub id: usize,
+
+    #[serde(serialize_with = "cli::serialize_sorted")]
+    pub winning_numbers: HashSet<u8>,
+
+    #[serde(serialize_with = "cli::serialize_sorted")]
+    pub numbers: HashSet<u8>,
+}
+
+impl FromStr for Card {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let Some((id, rhs)) = s.strip_prefix("Card ").and_then(|s| s.split_once(": ")) else {
+            return Err(Error::MissingId(s.to_string()));
+        };
+        let id = parse_number(id.trim())?;
+
+        let Some((winning_numbers, numbers)) = rhs.split_once(" | ") else {
+            return Err(Error::MissingSeparator(rhs.to_string()));
+        };
+        let winning_numbers = winning_numbers
+            .split_whitespace()
+            .map(parse_number::<u8>)
+            .collect::<Result<HashSet<_>, _>>()?;
+        let numbers = numbers
+            .split_whitespace()
+            .map(parse_number::<u8>)
+            .collect::<Result<HashSet<_>, _>>()?;
+
+        Ok(Self {
+            id,
+            winning_numbers,
+            numbers,
+        })
+    }
+}
+
+impl Card {
+    pub fn overlap(&self) -> usize {
+        self.winning_numbers.intersection(&self.numbers).count()
+    }
+
+    pub fn points<A: Answer>(&self) -> Result<A, Overflow> {
+        match self.overlap() {
+            0 => Ok(A::default()),
+            overlap => A::from(2).try_pow(overlap as u32 - 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::{ErrorKind, trim_newlines};
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    #[test]
+    fn example_1() -> Result<()> {
+        assert_eq!(part_1(trim_newlines(EXAMPLE))?, 13);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        assert_eq!(part_2(trim_newlines(EXAMPLE))?, 30);
+
+        Ok(())
+    }
+
+    #[test]
+    fn overflow() -> Result<()> {
+        let wide = "wide = true".parse()?;
+        let numbers = (1..=40)
+            .map(|number| number.to_string())
+            .collect::<Vec<_>>();
+        let card = format!("Card 1: {} | {}", numbers.join(" "), numbers.join(" "));
+
+        assert_eq!(part_1(&card), Err(Error::Overflow(Overflow("u32"))));
+        assert_eq!(part_1_as::<u128>(&card)?, 1 << 39);
+        assert_eq!(
+            stream::stream(&mut card.as_bytes(), 1, &wide)?,
+            part_1_as::<u128>(&card)?.to_string()
+        );
+
+        let cards = (1..=40)
+            .map(|id| format!("Card {id}: {} | {}", numbers.join(" "), numbers.join(" ")))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert_eq!(part_2(&cards), Err(Error::Overflow(Overflow("u32"))));
+        assert_eq!(part_2_as::<u128>(&cards)?, (1 << 40) - 1);
+        assert_eq!(
+            stream::stream(&mut cards.as_bytes(), 2, &wide)?,
+            part_2_as::<u128>(&cards)?.to_string()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn error_kinds() {
+        let kind = |part, input| {
+            let error = SOLVER.solve(part, input).err()?;
+
+            (SOLVER.classify)(&error)
+        };
+
+        assert_eq!(kind(1, "Card 1 1 | 2"), Some(ErrorKind::Parse));
+        assert_eq!(kind(1, "Card one: 1 | 2"), Some(ErrorKind::Parse));
+        assert_eq!(kind(2, "Card 2: 1 | 2"), Some(ErrorKind::Validation));
+        assert_eq!(kind(3, "Card 1: 1 | 2"), None);
+    }
+
+    #[test]
+    fn dump() -> Result<()> {
+        let expected = r#"
+[
+  {
+    "id": 1,
+    "numbers": [
+      41,
+      83
+    ],
+    "winning_numbers": [
+      41,
+      48
+    ]
+  }
+]
+"#;
+
+        assert_eq!(
+            SOLVER.dump("Card 1: 48 41 | 83 41")?,
+            trim_newlines(expected)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use std::{collections::VecDeque, io::BufRead, str::FromStr};
+
+use anyhow::{Result, anyhow};
+
+use cli::{
+    answer::{Answer, is_wide},
+    config::Settings,
+    input::for_each_line,
+};
+
+use crate::{Card, Error};
+
+/// Sums the points of the cards, or counts the cards won, a card at a time.
+///
+/// For part 2, only the copies won of the cards yet to be read are kept, which are no more than the
+/// numbers on a card. The answer is in 128 bits if [is_wide].
+pub fn stream(reader: &mut dyn BufRead, part: u8, settings: &Settings) -> Result<String> {
+    if is_wide(settings)? {
+        Ok(stream_as::<u128>(reader, part)?.to_string())
+    } else {
+        Ok(stream_as::<u32>(reader, part)?.to_string())
+    }
+}
+
+/// Sums the points or counts the cards in A, a card at a time.
+fn stream_as<A: Answer>(reader: &mut dyn BufRead, part: u8) -> Result<A> {
+    if !(1..=2).contains(&part) {
+        return Err(anyhow!("Invalid part number: {}", part));
+    }
+
+    let mut sum = A::default();
+    let mut expected = 1;
+    let mut won_copies = VecDeque::<A>::new();
+    for_each_line(reader, |line| {
+        let card = Card::from_str(line)?;
+        if part == 1 {
+            sum = sum.try_add(card.points()?)?;
+            return Ok(());
+        }
+
+        if card.id != expected {
+            return Err(Error::OutOfOrder {
+                id: card.id,
+                expected,
+            }
+            .into());
+        }
+        expected += 1;
+
+        let copies = won_copies
+            .pop_front()
+            .unwrap_or_default()
+            .try_add(A::from(1))?;
+        let overlap = card.overlap();
+        if won_copies.len() < overlap {
+            won_copies.resize(overlap, A::default());
+        }
+        for count in won_copies.iter_mut().take(overlap) {
+            *count = count.try_add(copies)?;
+        }
+        sum = sum.try_add(copies)?;
+
+        Ok(())
+    })?;
+
+    Ok(sum)
+}
@@ -0,0 +1,51 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind, NumberError, answer::Overflow};
+
+/// Why a day 5 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    /// The almanac must have the seeds followed by 7 maps.
+    #[error("Expected 8 sections, found {0}")]
+    WrongSectionCount(usize),
+
+    #[error("Cannot split input into seeds: {0}")]
+    MissingSeeds(String),
+
+    #[error("Invalid map header: {0}")]
+    InvalidMapper(String),
+
+    /// A map line that is not a destination, a source and a range length.
+    #[error("Invalid map: {0}")]
+    InvalidMap(String),
+
+    #[error(transparent)]
+    Number(#[from] NumberError),
+
+    /// Part 2 reads the seeds as pairs of start and length.
+    #[error("Cannot pair up {0} seeds into ranges")]
+    OddSeeds(usize),
+
+    #[error("Cannot find min location without seeds")]
+    NoSeeds,
+
+    #[error("Cannot find min location for range: {start}..{end}")]
+    NoLocation { start: u64, end: u64 },
+
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::WrongSectionCount(_)
+            | Error::MissingSeeds(_)
+            | Error::InvalidMapper(_)
+            | Error::InvalidMap(_)
+            | Error::Number(_) => ErrorKind::Parse,
+            Error::OddSeeds(_) | Error::NoSeeds => ErrorKind::Validation,
+            Error::NoLocation { .. } | Error::Overflow(_) => ErrorKind::Unsolvable,
+        }
+    }
+}
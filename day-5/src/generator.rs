@@ -0,0 +1,90 @@
+use rand::{Rng, rngs::StdRng};
+
+use cli::Generated;
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// Generates 10 seed ranges and 7 maps over values below 100 * size.
+///
+/// Part 2 is answered by mapping every seed, so size should stay small.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let limit = 100 * size.max(1) as u64;
+
+    let seeds = (0..10)
+        .map(|_| {
+            let start = rng.random_range(0..limit);
+            (start, rng.random_range(1..=limit / 10 + 1))
+        })
+        .collect::<Vec<_>>();
+
+    // (dst, src, len)
+    let mut mappers = Vec::new();
+    for _ in 1..CATEGORIES.len() {
+        let mut maps = Vec::new();
+        let mut src = rng.random_range(0..limit / 10 + 1);
+        while src < limit {
+            let len = rng.random_range(1..=limit / 5 + 1);
+            if rng.random_bool(0.8) {
+                maps.push((rng.random_range(0..limit), src, len));
+            }
+            src += len + rng.random_range(0..=limit / 20);
+        }
+        mappers.push(maps);
+    }
+
+    let location = |seed: u64| {
+        mappers.iter().fold(seed, |value, maps| {
+            maps.iter()
+                .find(|(_, src, len)| (*src..src + len).contains(&value))
+                .map_or(value, |(dst, src, _)| dst + value - src)
+        })
+    };
+    let min_location_1 = seeds
+        .iter()
+        .flat_map(|&(start, len)| [start, len])
+        .map(location)
+        .min();
+    let min_location_2 = seeds
+        .iter()
+        .flat_map(|&(start, len)| start..start + len)
+        .map(location)
+        .min();
+
+    let mut blocks = vec![format!(
+        "seeds: {}",
+        seeds
+            .iter()
+            .map(|(start, len)| format!("{start} {len}"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    )];
+    for (index, maps) in mappers.iter().enumerate() {
+        let mut lines = vec![format!(
+            "{}-to-{} map:",
+            CATEGORIES[index],
+            CATEGORIES[index + 1]
+        )];
+        lines.extend(
+            maps.iter()
+                .map(|(dst, src, len)| format!("{dst} {src} {len}")),
+        );
+        blocks.push(lines.join("\n"));
+    }
+
+    Generated {
+        input: blocks.join("\n\n"),
+        answers: [
+            min_location_1.map(|location| location.to_string()),
+            min_location_2.map(|location| location.to_string()),
+        ],
+    }
+}
@@ -0,0 +1,308 @@
+use std::{ops::Range, str::FromStr};
+
+use serde::Serialize;
+
+use cli::{Solver, answer::Answer, parse_number, to_json};
+
+pub use error::Error;
+
+mod error;
+pub mod generator;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 5,
+    version: env!("SOLVER_VERSION"),
+    // Locations are values of the maps, which are checked to fit in u64 as they are parsed, so
+    // there is nothing to widen.
+    part_1: |input, _| Ok(part_1(input)?.to_string()),
+    part_2: Some(|input, _| Ok(part_2(input)?.to_string())),
+    generator: generator::generate,
+    render: None,
+    animate: None,
+    dump: Some(|input| to_json(&parse_input_into_seeds_and_mappers(input)?)),
+    stream: None,
+    certify: None,
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<u64, Error> {
+    let (seeds, mappers) = parse_input_into_seeds_and_mappers(input)?;
+
+    seeds
+        .into_iter()
+        .map(|seed| mappers.iter().fold(seed, |acc, mapper| mapper.map(acc)))
+        .min()
+        .ok_or(Error::NoSeeds)
+}
+
+pub fn part_2(input: &str) -> Result<u64, Error> {
+    let (seeds, mappers) = parse_input_into_seeds_and_mappers(input)?;
+    if seeds.len() % 2 != 0 {
+        return Err(Error::OddSeeds(seeds.len()));
+    }
+
+    seeds
+        .chunks(2)
+        .map(|chunk| {
+            let end = chunk[0].try_add(chunk[1])?;
+            #[allow(clippy::single_range_in_vec_init)]
+            let mut ranges = vec![chunk[0]..end];
+
+            for mapper in &mappers {
+                ranges = mapper.map_ranges(ranges);
+            }
+
+            ranges
+                .into_iter()
+                .map(|range| range.start)
+                .min()
+                .ok_or(Error::NoLocation {
+                    start: chunk[0],
+                    end,
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .min()
+        .ok_or(Error::NoSeeds)
+}
+
+#[derive(Serialize)]
+pub struct Map {
+    pub src: Range<u64>,
+    pub dst: u64,
+}
+
+impl FromStr for Map {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let tokens = s.split_whitespace().collect::<Vec<_>>();
+        if tokens.len() != 3 {
+            return Err(Error::InvalidMap(s.to_string()));
+        }
+
+        let dst = parse_number::<u64>(tokens[0])?;
+        let src = parse_number::<u64>(tokens[1])?;
+        let range = parse_number::<u64>(tokens[2])?;
+        // Every value mapped is then within u64 too.
+        dst.try_add(range)?;
+
+        Ok(Self {
+            src: src..src.try_add(range)?,
+            dst,
+        })
+    }
+}
+
+impl Map {
+    pub fn map(&self, value: u64) -> Option<u64> {
+        if self.src.contains(&value) {
+            Some(self.dst + (value - self.src.start))
+        } else {
+            None
+        }
+    }
+
+    /// Maps the given ranges into the ranges of mapped values, and the ranges of unmapped values.
+    pub fn map_ranges(&self, ranges: Vec<Range<u64>>) -> (Vec<Range<u64>>, Vec<Range<u64>>) {
+        let mut values_that_map = Vec::new();
+        let mut values_that_don_t_map = Vec::new();
+
+        for range in ranges {
+            match range {
+                range if self.src.contains(&range.start) && !self.src.contains(&range.end) => {
+                    // <--- src --->
+                    //       <-- range -->
+
+                    values_that_map.push(range.start..self.src.end);
+                    values_that_don_t_map.push(self.src.end..range.end);
+                }
+                range if !self.src.contains(&range.start) && self.src.contains(&range.end) => {
+                    //       <--- src --->
+                    // <-- range -->
+
+                    values_that_map.push(self.src.start..range.end);
+                    values_that_don_t_map.push(range.start..self.src.start);
+                }
+                range if self.src.contains(&range.start) && self.src.contains(&range.end) => {
+                    // <------ src ------>
+                    //    <-- range -->
+
+                    values_that_map.push(range);
+                }
+                range if range.contains(&self.src.start) && range.contains(&self.src.end) => {
+                    //    <--- src --->
+                    // <----- range ----->
+
+                    values_that_map.push(self.src.clone());
+                    values_that_don_t_map.push(range.start..self.src.start);
+                    values_that_don_t_map.push(self.src.end..range.end);
+                }
+                _ => {
+                    // <-- range --> <--- src --->  or  <--- src ---> <-- range -->
+                    values_that_don_t_map.push(range);
+                }
+            }
+        }
+
+        // Do the actual translation.
+        let values_mapped = values_that_map
+            .into_iter()
+            .map(|range| {
+                let start = self.dst + (range.start - self.src.start);
+                let end = start + (range.end - range.start);
+
+                start..end
+            })
+            .collect::<Vec<_>>();
+
+        (values_mapped, values_that_don_t_map)
+    }
+}
+
+#[derive(Serialize)]
+pub struct Mapper {
+    pub maps: Vec<Map>,
+}
+
+impl FromStr for Mapper {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let lines = s.lines().collect::<Vec<_>>();
+        if lines.is_empty() || !lines[0].ends_with("map:") {
+            return Err(Error::InvalidMapper(s.to_string()));
+        }
+
+        let maps = lines
+            .into_iter()
+            .skip(1)
+            .map(Map::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { maps })
+    }
+}
+
+impl Mapper {
+    pub fn map(&self, value: u64) -> u64 {
+        if let Some(mapped_value) = self
+            .maps
+            .iter()
+            .fold(None, |acc, map| acc.or(map.map(value)))
+        {
+            mapped_value
+        } else {
+            value
+        }
+    }
+
+    pub fn map_ranges(&self, ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+        let mut ranges_mapped = Vec::new();
+        let mut ranges_left_to_map = ranges;
+        for map in &self.maps {
+            let (values_mapped, values_unmapped) = map.map_ranges(ranges_left_to_map);
+
+            ranges_mapped.extend(values_mapped);
+            ranges_left_to_map = values_unmapped;
+        }
+        ranges_mapped.extend(ranges_left_to_map); // unmapped values stay the same
+
+        ranges_mapped
+    }
+}
+
+pub fn parse_input_into_seeds_and_mappers(input: &str) -> Result<(Vec<u64>, Vec<Mapper>), Error> {
+    let tokens = input.split_terminator("\n\n").collect::<Vec<_>>();
+    if tokens.len() != 8 {
+        return Err(Error::WrongSectionCount(tokens.len()));
+    }
+
+    let seeds = tokens[0]
+        .strip_prefix("seeds: ")
+        .ok_or_else(|| Error::MissingSeeds(tokens[0].to_string()))?
+        .split_whitespace()
+        .map(parse_number::<u64>)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mappers = tokens
+        .into_iter()
+        .skip(1)
+        .map(Mapper::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((seeds, mappers))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::trim_newlines;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn example_1() -> Result<()> {
+        assert_eq!(part_1(trim_newlines(EXAMPLE))?, 35);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        assert_eq!(part_2(trim_newlines(EXAMPLE))?, 46);
+
+        Ok(())
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(
+            Map::from_str("18446744073709551615 0 2").err(),
+            Some(Error::Overflow(cli::answer::Overflow("u64")))
+        );
+        assert_eq!(
+            Map::from_str("0 18446744073709551615 2").err(),
+            Some(Error::Overflow(cli::answer::Overflow("u64")))
+        );
+    }
+}
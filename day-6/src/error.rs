@@ -0,0 +1,42 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind, NumberError, answer::Overflow};
+
+/// Why a day 6 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    /// The input must be a line of times and a line of distances.
+    #[error("Expected 2 lines, found {0}")]
+    WrongLineCount(usize),
+
+    #[error("Invalid time: {0}")]
+    InvalidTime(String),
+
+    #[error("Invalid distance: {0}")]
+    InvalidDistance(String),
+
+    #[error(transparent)]
+    Number(#[from] NumberError),
+
+    #[error("times and distances don't pair up: {times:?}, {distances:?}")]
+    Unpaired {
+        times: Vec<u64>,
+        distances: Vec<u64>,
+    },
+
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::WrongLineCount(_)
+            | Error::InvalidTime(_)
+            | Error::InvalidDistance(_)
+            | Error::Number(_) => ErrorKind::Parse,
+            Error::Unpaired { .. } => ErrorKind::Validation,
+            Error::Overflow(_) => ErrorKind::Unsolvable,
+        }
+    }
+}
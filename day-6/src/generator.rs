@@ -0,0 +1,74 @@
+use rand::{Rng, rngs::StdRng};
+
+use cli::Generated;
+
+/// Generates size races, but at most 4, so that the time and the distance of the single race in
+/// part 2, the concatenations of those of every race, fit in u64.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let records = (0..size.clamp(1, 4))
+        .map(|_| {
+            let time = rng.random_range(7..100u64);
+            let best_distance = (time / 2) * (time - time / 2);
+
+            (time, rng.random_range(0..best_distance))
+        })
+        .collect::<Vec<_>>();
+
+    // Saturates rather than overflows, though 4 races never get near u64::MAX.
+    let concat = |values: &mut dyn Iterator<Item = u64>| {
+        values.fold(0u64, |concat, value| {
+            let digits = value.checked_ilog10().unwrap_or(0) + 1;
+
+            concat
+                .saturating_mul(10u64.pow(digits))
+                .saturating_add(value)
+        })
+    };
+    let time = concat(&mut records.iter().map(|record| record.0));
+    let distance = concat(&mut records.iter().map(|record| record.1));
+
+    let product = records
+        .iter()
+        .map(|&(time, distance)| count_wins(time, distance))
+        .product::<u64>();
+
+    let format = |values: &mut dyn Iterator<Item = u64>| {
+        values
+            .map(|value| format!("{value:>5}"))
+            .collect::<String>()
+    };
+    let input = format!(
+        "Time:    {}\nDistance:{}",
+        format(&mut records.iter().map(|record| record.0)),
+        format(&mut records.iter().map(|record| record.1))
+    );
+
+    Generated {
+        input,
+        answers: [
+            Some(product.to_string()),
+            Some(count_wins(time, distance).to_string()),
+        ],
+    }
+}
+
+/// Counts the wins by binary searching for the shortest winning hold, since distance travelled is
+/// symmetric about holding for half the time.
+fn count_wins(time: u64, distance: u64) -> u64 {
+    let travelled = |hold: u64| hold as u128 * (time - hold) as u128;
+    if travelled(time / 2) <= distance as u128 {
+        return 0;
+    }
+
+    let (mut lo, mut hi) = (0, time / 2);
+    while hi - lo > 1 {
+        let mid = (lo + hi) / 2;
+        if travelled(mid) > distance as u128 {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+
+    time - 2 * hi + 1
+}
@@ -0,0 +1,199 @@
+use cli::{
+    Solver,
+    answer::{Answer, try_product, widen, with_width},
+    parse_number, to_json,
+};
+
+pub use error::Error;
+
+mod error;
+pub mod generator;
+pub mod stream;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 6,
+    version: env!("SOLVER_VERSION"),
+    part_1: |input, settings| with_width(settings, || part_1(input), || part_1_as::<u128>(input)),
+    // There are fewer winning speeds than ms in the race, whose time is a u64.
+    part_2: Some(|input, _| Ok(part_2(input)?.to_string())),
+    generator: generator::generate,
+    render: None,
+    animate: None,
+    dump: Some(|input| to_json(&parse_input_into_records(input)?)),
+    stream: Some(stream::stream),
+    certify: None,
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<u64, Error> {
+    part_1_as(input)
+}
+
+/// Multiplies the counts of winning strategies in A.
+pub fn part_1_as<A: Answer + TryFrom<u64>>(input: &str) -> Result<A, Error> {
+    let counts = parse_input_into_records(input)?
+        .iter()
+        .map(|record| widen(count_winning_strategies(record)))
+        .collect::<Result<Vec<A>, _>>()?;
+
+    Ok(try_product(counts)?)
+}
+
+pub fn part_2(input: &str) -> Result<u64, Error> {
+    let record = parse_input_into_single_record(input)?;
+
+    Ok(count_winning_strategies(&record))
+}
+
+/// (time, distance)
+pub type Record = (u64, u64);
+
+pub fn parse_input_into_records(input: &str) -> Result<Vec<Record>, Error> {
+    let lines = input.lines().collect::<Vec<_>>();
+    if lines.len() != 2 {
+        return Err(Error::WrongLineCount(lines.len()));
+    }
+
+    let times = lines[0]
+        .strip_prefix("Time:")
+        .ok_or_else(|| Error::InvalidTime(lines[0].to_string()))?
+        .split_whitespace()
+        .map(parse_number::<u64>)
+        .collect::<Result<Vec<_>, _>>()?;
+    let distances = lines[1]
+        .strip_prefix("Distance:")
+        .ok_or_else(|| Error::InvalidDistance(lines[1].to_string()))?
+        .split_whitespace()
+        .map(parse_number::<u64>)
+        .collect::<Result<Vec<_>, _>>()?;
+    if times.len() != distances.len() {
+        return Err(Error::Unpaired { times, distances });
+    }
+
+    Ok(times.into_iter().zip(distances).collect())
+}
+
+/// Counts the winning speeds by binary searching for the slowest, since the distance travelled
+/// grows up to half the time and is symmetric about it.
+pub fn count_winning_strategies(record: &Record) -> u64 {
+    let &(time, distance) = record;
+    let wins = |speed: u64| u128::from(speed) * u128::from(time - speed) > u128::from(distance);
+    if !wins(time / 2) {
+        return 0;
+    }
+
+    // wins(0) never holds, as 0 is not more than any distance.
+    let (mut lo, mut hi) = (0, time / 2);
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if wins(mid) {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+
+    // hi is at least 1, so this is at most time - 1.
+    time - 2 * hi + 1
+}
+
+pub fn parse_input_into_single_record(input: &str) -> Result<Record, Error> {
+    let lines = input.lines().collect::<Vec<_>>();
+    if lines.len() != 2 {
+        return Err(Error::WrongLineCount(lines.len()));
+    }
+
+    let time = lines[0]
+        .strip_prefix("Time:")
+        .ok_or_else(|| Error::InvalidTime(lines[0].to_string()))?
+        .replace(" ", "");
+    let time = parse_number(&time)?;
+    let distance = lines[1]
+        .strip_prefix("Distance:")
+        .ok_or_else(|| Error::InvalidDistance(lines[1].to_string()))?
+        .replace(" ", "");
+    let distance = parse_number(&distance)?;
+
+    Ok((time, distance))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::trim_newlines;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+Time:      7  15   30
+Distance:  9  40  200
+";
+
+    #[test]
+    fn example_1() -> Result<()> {
+        assert_eq!(part_1(trim_newlines(EXAMPLE))?, 288);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        assert_eq!(part_2(trim_newlines(EXAMPLE))?, 71503);
+
+        Ok(())
+    }
+
+    #[test]
+    fn overflow() -> Result<()> {
+        let wide = "wide = true".parse()?;
+        let input = "Time: 10000 10000 10000 10000 10000\nDistance: 0 0 0 0 0";
+
+        assert_eq!(
+            part_1(input),
+            Err(Error::Overflow(cli::answer::Overflow("u64")))
+        );
+        assert_eq!(part_1_as::<u128>(input)?, 9999u128.pow(5));
+        assert_eq!(
+            stream::stream(&mut input.as_bytes(), 1, &wide)?,
+            part_1_as::<u128>(input)?.to_string()
+        );
+
+        Ok(())
+    }
+
+    /// Races each strategy, 1 ms at a time.
+    fn count_winning_strategies_naive(record: &Record) -> u64 {
+        let &(time, distance) = record;
+
+        (0..=time)
+            .filter(|speed| (*speed..time).map(|_| speed).sum::<u64>() > distance)
+            .count() as u64
+    }
+
+    #[test]
+    fn longest_race() -> Result<()> {
+        assert_eq!(
+            part_1("Time: 18446744073709551615\nDistance: 0")?,
+            u64::MAX - 1
+        );
+        assert_eq!(
+            part_2("Time: 18446744073709551615\nDistance: 18446744073709551615")?,
+            u64::MAX - 3
+        );
+
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn count_winning_strategies_matches_naive(time in 0..200u64, distance in 0..10000u64) {
+            prop_assert_eq!(
+                count_winning_strategies(&(time, distance)),
+                count_winning_strategies_naive(&(time, distance))
+            );
+        }
+    }
+}
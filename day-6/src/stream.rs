@@ -0,0 +1,33 @@
+use std::io::BufRead;
+
+use anyhow::{Result, anyhow};
+
+use cli::{answer::is_wide, config::Settings, input::for_each_line};
+
+use crate::Error;
+
+/// Solves from the line of times and the line of distances, part 1 in 128 bits if [is_wide]. Any
+/// further lines are only counted.
+pub fn stream(reader: &mut dyn BufRead, part: u8, settings: &Settings) -> Result<String> {
+    let mut lines = Vec::with_capacity(2);
+    let mut line_count = 0;
+    for_each_line(reader, |line| {
+        if line_count < 2 {
+            lines.push(line.to_string());
+        }
+        line_count += 1;
+
+        Ok(())
+    })?;
+    if line_count != 2 {
+        return Err(Error::WrongLineCount(line_count).into());
+    }
+    let input = lines.join("\n");
+
+    match part {
+        1 if is_wide(settings)? => Ok(crate::part_1_as::<u128>(&input)?.to_string()),
+        1 => Ok(crate::part_1(&input)?.to_string()),
+        2 => Ok(crate::part_2(&input)?.to_string()),
+        _ => Err(anyhow!("Invalid part number: {}", part)),
+    }
+}
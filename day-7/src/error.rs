@@ -0,0 +1,34 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind, NumberError, answer::Overflow};
+
+/// Why a day 7 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    #[error("Cannot split input into cards and bid: {0}")]
+    MissingBid(String),
+
+    #[error("Hand does not have 5 cards: {0}")]
+    WrongHandSize(String),
+
+    #[error("Invalid card: {0}")]
+    InvalidCard(char),
+
+    #[error(transparent)]
+    Number(#[from] NumberError),
+
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::MissingBid(_)
+            | Error::WrongHandSize(_)
+            | Error::InvalidCard(_)
+            | Error::Number(_) => ErrorKind::Parse,
+            Error::Overflow(_) => ErrorKind::Unsolvable,
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use std::collections::HashSet;
+
+use rand::{Rng, rngs::StdRng, seq::IndexedRandom};
+
+use cli::Generated;
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+/// Generates size distinct hands with bids.
+///
+/// Cards are drawn from a few labels per hand, so that pairs and jokers come up often.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let mut seen = HashSet::new();
+    let mut hands = Vec::new();
+    while hands.len() < size.min(1000) {
+        let num_labels = rng.random_range(1..=5);
+        let labels = CARDS
+            .choose_multiple(rng, num_labels)
+            .copied()
+            .collect::<Vec<_>>();
+        let cards = (0..5)
+            .map(|_| *labels.choose(rng).unwrap())
+            .collect::<Vec<_>>();
+        if seen.insert(cards.clone()) {
+            hands.push((cards, rng.random_range(1..1000u64)));
+        }
+    }
+
+    let input = hands
+        .iter()
+        .map(|(cards, bid)| format!("{} {}", String::from_utf8_lossy(cards), bid))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Generated {
+        input,
+        answers: [
+            Some(total_winnings(&hands, false).to_string()),
+            Some(total_winnings(&hands, true).to_string()),
+        ],
+    }
+}
+
+/// Ranks hands by their sorted counts of each label, trying every label for the jokers.
+fn total_winnings(hands: &[(Vec<u8>, u64)], jokers: bool) -> u64 {
+    let strength = |card: u8| {
+        if jokers && card == b'J' {
+            0
+        } else {
+            CARDS.iter().position(|&label| label == card).unwrap() + 1
+        }
+    };
+    let counts = |cards: &[u8]| {
+        let mut counts = CARDS
+            .iter()
+            .map(|label| cards.iter().filter(|card| *card == label).count())
+            .collect::<Vec<_>>();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+
+        counts
+    };
+
+    let mut ranked = hands
+        .iter()
+        .map(|(cards, bid)| {
+            let best_counts = if jokers {
+                CARDS
+                    .iter()
+                    .map(|&label| {
+                        let replaced = cards
+                            .iter()
+                            .map(|&card| if card == b'J' { label } else { card })
+                            .collect::<Vec<_>>();
+                        counts(&replaced)
+                    })
+                    .max()
+                    .unwrap()
+            } else {
+                counts(cards)
+            };
+            let strengths = cards.iter().map(|&card| strength(card)).collect::<Vec<_>>();
+
+            ((best_counts, strengths), *bid)
+        })
+        .collect::<Vec<_>>();
+    ranked.sort();
+
+    ranked
+        .into_iter()
+        .enumerate()
+        .map(|(index, (_, bid))| (index + 1) as u64 * bid)
+        .sum()
+}
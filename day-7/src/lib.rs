@@ -0,0 +1,316 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use serde::Serialize;
+
+use cli::{
+    Solver,
+    answer::{Answer, Overflow, try_sum, widen, with_width},
+    dump_lines, parse_number,
+};
+
+pub use error::Error;
+
+mod error;
+pub mod generator;
+pub mod stream;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 7,
+    version: env!("SOLVER_VERSION"),
+    part_1: |input, settings| with_width(settings, || part_1(input), || part_1_as::<u128>(input)),
+    part_2: Some(|input, settings| {
+        with_width(settings, || part_2(input), || part_2_as::<u128>(input))
+    }),
+    generator: generator::generate,
+    render: None,
+    animate: None,
+    dump: Some(|input| dump_lines(input, Hand::from_str)),
+    stream: Some(stream::stream),
+    certify: None,
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<u64, Error> {
+    part_1_as(input)
+}
+
+pub fn part_2(input: &str) -> Result<u64, Error> {
+    part_2_as(input)
+}
+
+/// Sums the winnings in A.
+pub fn part_1_as<A>(input: &str) -> Result<A, Error>
+where
+    A: Answer + From<u64> + TryFrom<usize>,
+{
+    let hands = input
+        .lines()
+        .map(Hand::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(total_winnings(hands)?)
+}
+
+/// Sums the winnings in A, with J as a joker.
+pub fn part_2_as<A>(input: &str) -> Result<A, Error>
+where
+    A: Answer + From<u64> + TryFrom<usize>,
+{
+    let hands = input
+        .lines()
+        .map(Hand::from_str)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .map(|hand| hand.into_joker())
+        .collect();
+
+    Ok(total_winnings(hands)?)
+}
+
+/// Ranks the hands, and sums the bid of each multiplied by its rank.
+pub fn total_winnings<A>(mut hands: Vec<Hand>) -> Result<A, Overflow>
+where
+    A: Answer + From<u64> + TryFrom<usize>,
+{
+    hands.sort();
+
+    let winnings = hands
+        .into_iter()
+        .enumerate()
+        .map(|(index, hand)| widen::<A, _>(index + 1)?.try_mul(A::from(hand.bid)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    try_sum(winnings)
+}
+
+#[derive(Clone, Copy, Default, Eq, PartialEq, Serialize)]
+pub struct Card(pub u8);
+
+impl TryFrom<char> for Card {
+    type Error = Error;
+
+    fn try_from(value: char) -> std::result::Result<Self, Self::Error> {
+        match value {
+            '2' => Ok(Card(2)),
+            '3' => Ok(Card(3)),
+            '4' => Ok(Card(4)),
+            '5' => Ok(Card(5)),
+            '6' => Ok(Card(6)),
+            '7' => Ok(Card(7)),
+            '8' => Ok(Card(8)),
+            '9' => Ok(Card(9)),
+            'T' => Ok(Card(10)),
+            'J' => Ok(Card(11)),
+            'Q' => Ok(Card(12)),
+            'K' => Ok(Card(13)),
+            'A' => Ok(Card(14)),
+            _ => Err(Error::InvalidCard(value)),
+        }
+    }
+}
+
+impl Ord for Card {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl PartialOrd for Card {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Card {
+    pub fn into_joker(self) -> Self {
+        if self.0 == 11 { Self(1) } else { self }
+    }
+
+    pub fn is_joker(&self) -> bool {
+        self.0 == 1
+    }
+}
+
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+#[derive(Eq, PartialEq, Serialize)]
+pub struct Hand {
+    pub cards: [Card; 5],
+    pub bid: u64,
+    pub hand_type: HandType,
+}
+
+impl FromStr for Hand {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let Some((cards, bid)) = s.split_once(" ") else {
+            return Err(Error::MissingBid(s.to_string()));
+        };
+
+        let cards: [Card; 5] = cards
+            .chars()
+            .map(Card::try_from)
+            .collect::<Result<Vec<_>, _>>()?
+            .try_into()
+            .map_err(|_| Error::WrongHandSize(cards.to_string()))?;
+
+        let bid = parse_number(bid)?;
+
+        let mut sorted_cards = cards;
+        sorted_cards.sort();
+        let hand_type = match sorted_cards.as_slice() {
+            [a, b, c, d, e] if a == b && a == c && a == d && a == e => HandType::FiveOfAKind,
+            [a, b, c, d, _] | [_, a, b, c, d] if a == b && a == c && a == d => {
+                HandType::FourOfAKind
+            }
+            [a, b, c, d, e] if (a == b && a == c && d == e) || (a == b && c == d && c == e) => {
+                HandType::FullHouse
+            }
+            [a, b, c, _, _] | [_, a, b, c, _] | [_, _, a, b, c] if a == b && a == c => {
+                HandType::ThreeOfAKind
+            }
+            [a, b, c, d, _] | [a, b, _, c, d] | [_, a, b, c, d] if a == b && c == d => {
+                HandType::TwoPair
+            }
+            [a, b, _, _, _] | [_, a, b, _, _] | [_, _, a, b, _] | [_, _, _, a, b] if a == b => {
+                HandType::OnePair
+            }
+            _ => HandType::HighCard,
+        };
+
+        Ok(Self {
+            cards,
+            bid,
+            hand_type,
+        })
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.hand_type.cmp(&other.hand_type) {
+            Ordering::Equal => (),
+            ord => return ord,
+        }
+
+        match self.cards[0].cmp(&other.cards[0]) {
+            Ordering::Equal => (),
+            ord => return ord,
+        }
+
+        match self.cards[1].cmp(&other.cards[1]) {
+            Ordering::Equal => (),
+            ord => return ord,
+        }
+
+        match self.cards[2].cmp(&other.cards[2]) {
+            Ordering::Equal => (),
+            ord => return ord,
+        }
+
+        match self.cards[3].cmp(&other.cards[3]) {
+            Ordering::Equal => (),
+            ord => return ord,
+        }
+
+        self.cards[4].cmp(&other.cards[4])
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hand {
+    pub fn into_joker(mut self) -> Self {
+        self.cards
+            .iter_mut()
+            .for_each(|card| *card = card.into_joker());
+
+        // Upgrade hand type.
+        let jokers = self.cards.iter().filter(|card| card.is_joker()).count();
+        self.hand_type = match (self.hand_type, jokers) {
+            (HandType::FourOfAKind, 1 | 4) => HandType::FiveOfAKind,
+            (HandType::FullHouse, 2 | 3) => HandType::FiveOfAKind,
+            (HandType::ThreeOfAKind, 1 | 3) => HandType::FourOfAKind,
+            (HandType::TwoPair, 1) => HandType::FullHouse,
+            (HandType::TwoPair, 2) => HandType::FourOfAKind,
+            (HandType::OnePair, 1 | 2) => HandType::ThreeOfAKind,
+            (HandType::HighCard, 1) => HandType::OnePair,
+            _ => self.hand_type,
+        };
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::trim_newlines;
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+    #[test]
+    fn example_1() -> Result<()> {
+        assert_eq!(part_1(trim_newlines(EXAMPLE))?, 6440);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        assert_eq!(part_2(trim_newlines(EXAMPLE))?, 5905);
+
+        Ok(())
+    }
+
+    #[test]
+    fn wrong_hand_size() {
+        assert_eq!(
+            part_1("32T3 765"),
+            Err(Error::WrongHandSize(String::from("32T3")))
+        );
+        assert_eq!(
+            part_2("32T3KK 765"),
+            Err(Error::WrongHandSize(String::from("32T3KK")))
+        );
+    }
+
+    #[test]
+    fn overflow() -> Result<()> {
+        let wide = "wide = true".parse()?;
+        let input = "32T3K 1\nT55J5 18446744073709551615";
+
+        assert_eq!(part_1(input), Err(Error::Overflow(Overflow("u64"))));
+        assert_eq!(part_1_as::<u128>(input)?, 1 + 2 * u128::from(u64::MAX));
+        assert_eq!(
+            stream::stream(&mut input.as_bytes(), 1, &wide)?,
+            part_1_as::<u128>(input)?.to_string()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use std::{io::BufRead, str::FromStr};
+
+use anyhow::{Result, anyhow};
+
+use cli::{answer::is_wide, config::Settings, input::for_each_line};
+
+use crate::{Hand, total_winnings};
+
+/// Ranks the hands read a line at a time. Ranking needs every hand, so the parsed hands are kept,
+/// though not the lines they were read from. The winnings are in 128 bits if [is_wide].
+pub fn stream(reader: &mut dyn BufRead, part: u8, settings: &Settings) -> Result<String> {
+    if !(1..=2).contains(&part) {
+        return Err(anyhow!("Invalid part number: {}", part));
+    }
+
+    let mut hands = Vec::new();
+    for_each_line(reader, |line| {
+        let hand = Hand::from_str(line)?;
+        hands.push(if part == 2 { hand.into_joker() } else { hand });
+
+        Ok(())
+    })?;
+
+    if is_wide(settings)? {
+        Ok(total_winnings::<u128>(hands)?.to_string())
+    } else {
+        Ok(total_winnings::<u64>(hands)?.to_string())
+    }
+}
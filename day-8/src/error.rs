@@ -0,0 +1,64 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind, answer::Overflow};
+
+/// Why a day 8 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    #[error("Cannot split input into directions and elements: {0}")]
+    MissingElements(String),
+
+    #[error("Cannot split input into from and to: {0}")]
+    InvalidNode(String),
+
+    #[error("Cannot split input into left and right: {0}")]
+    InvalidPair(String),
+
+    #[error("Invalid direction: {0}")]
+    InvalidDirection(char),
+
+    #[error("Cannot follow an empty list of directions")]
+    NoDirections,
+
+    /// An element that is reached but never defined.
+    #[error("Cannot get {direction} element: {element}")]
+    UnknownElement { element: String, direction: char },
+
+    /// The ghosts do not loop in a way that makes the lcm of their steps the answer to part 2.
+    #[error("Cycle len {cycle_len} from {start} is not a multiple of {steps}")]
+    CycleMismatch {
+        start: String,
+        cycle_len: usize,
+        steps: u64,
+    },
+
+    #[error("{start} does not reach ??Z every {steps} steps: {reached:?} at step {step}")]
+    NotPeriodic {
+        start: String,
+        steps: u64,
+        reached: Option<String>,
+        step: usize,
+    },
+
+    #[error("{0} never reaches ??Z")]
+    NoEnd(String),
+
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::MissingElements(_)
+            | Error::InvalidNode(_)
+            | Error::InvalidPair(_)
+            | Error::InvalidDirection(_) => ErrorKind::Parse,
+            Error::NoDirections | Error::UnknownElement { .. } => ErrorKind::Validation,
+            Error::CycleMismatch { .. }
+            | Error::NotPeriodic { .. }
+            | Error::NoEnd(_)
+            | Error::Overflow(_) => ErrorKind::Unsolvable,
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+
+use rand::{Rng, rngs::StdRng, seq::SliceRandom};
+
+use cli::Generated;
+
+/// Generates directions and a network of size.clamp(1, 6) ghost loops, starting from AAA and then
+/// ??A, each through layers of twin elements to its ??Z and back to the start of the loop.
+///
+/// Twin elements share the same next elements, so each loop takes the same num of steps whatever the
+/// directions, as in the puzzle inputs.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let directions = (0..rng.random_range(2..=20))
+        .map(|_| if rng.random_bool(0.5) { 'L' } else { 'R' })
+        .collect::<String>();
+
+    let mut used = HashSet::from([String::from("AAA"), String::from("ZZZ")]);
+    let mut name = |rng: &mut StdRng, last: Option<char>| loop {
+        let mut name = (0..3)
+            .map(|_| rng.random_range(b'B'..b'Z') as char)
+            .collect::<String>();
+        if let Some(last) = last {
+            name.pop();
+            name.push(last);
+        }
+        if used.insert(name.clone()) {
+            return name;
+        }
+    };
+
+    let mut lines = Vec::new();
+    let mut push_line = |rng: &mut StdRng, from: &str, left: &str, right: &str| {
+        if rng.random_bool(0.5) {
+            lines.push(format!("{from} = ({left}, {right})"));
+        } else {
+            lines.push(format!("{from} = ({right}, {left})"));
+        }
+    };
+
+    let mut periods = Vec::new();
+    for ghost in 0..size.clamp(1, 6) {
+        let (start, end) = if ghost == 0 {
+            (String::from("AAA"), String::from("ZZZ"))
+        } else {
+            (name(rng, Some('A')), name(rng, Some('Z')))
+        };
+
+        let period = rng.random_range(2..=60u64);
+        let layers = (1..period)
+            .map(|_| (name(rng, None), name(rng, None)))
+            .collect::<Vec<_>>();
+        push_line(rng, &start, &layers[0].0, &layers[0].1);
+        push_line(rng, &end, &layers[0].0, &layers[0].1);
+        for (index, (a, b)) in layers.iter().enumerate() {
+            let (next_a, next_b) = layers
+                .get(index + 1)
+                .unwrap_or(&(end.clone(), end.clone()))
+                .clone();
+            push_line(rng, a, &next_a, &next_b);
+            push_line(rng, b, &next_a, &next_b);
+        }
+
+        periods.push(period);
+    }
+    lines.shuffle(rng);
+
+    let lcm = periods
+        .iter()
+        .fold(1, |acc, &period| acc / gcd(acc, period) * period);
+
+    Generated {
+        input: format!("{}\n\n{}", directions, lines.join("\n")),
+        answers: [Some(periods[0].to_string()), Some(lcm.to_string())],
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
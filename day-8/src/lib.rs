@@ -0,0 +1,294 @@
+use std::collections::HashMap;
+
+use cli::{
+    Solver,
+    answer::{Answer, try_lcm, with_width},
+    to_json,
+};
+use cycle::find_cycle_brent;
+
+pub use error::Error;
+
+mod error;
+pub mod generator;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 8,
+    version: env!("SOLVER_VERSION"),
+    // Part 1 ends within one step of each (element, direction) state, which are all in memory.
+    part_1: |input, _| Ok(part_1(input)?.to_string()),
+    part_2: Some(|input, settings| {
+        with_width(settings, || part_2(input), || part_2_as::<u128>(input))
+    }),
+    generator: generator::generate,
+    render: None,
+    animate: None,
+    dump: Some(|input| {
+        let (directions, left, right) = parse_input_into_directions_and_elements(input)?;
+
+        to_json(&(String::from_utf8_lossy(&directions), left, right))
+    }),
+    stream: None,
+    certify: None,
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<u64, Error> {
+    let (directions, left, right) = parse_input_into_directions_and_elements(input)?;
+
+    // AAA always maps to ZZZ and no other ??Z.
+    steps("AAA", &directions, &left, &right)
+}
+
+pub fn part_2(input: &str) -> Result<u64, Error> {
+    part_2_as(input)
+}
+
+/// Finds the lcm of the steps of every ghost in A.
+pub fn part_2_as<A: Answer + From<u64>>(input: &str) -> Result<A, Error> {
+    // Brute-force won't work. We need to determine the cycle len of each ??A element and find the
+    // lcm of all the lens.
+    //
+    // For the input, the ??A element and corresponding ??Z element both map to the same pair of
+    // next elements (even though the left and right sides may be swapped) and the cycle restarts
+    // from ??Z. check_periodic() verifies this instead of trusting it.
+
+    let (directions, left, right) = parse_input_into_directions_and_elements(input)?;
+
+    Ok(left
+        .keys()
+        .filter(|element| element.ends_with("A"))
+        .map(|element| {
+            let steps = steps(element, &directions, &left, &right)?;
+            check_periodic(element, steps, &directions, &left, &right)?;
+
+            Ok(steps)
+        })
+        .collect::<Result<Vec<_>, Error>>()?
+        .into_iter()
+        .try_fold(A::from(1u64), |lcm, steps| try_lcm(lcm, A::from(steps)))?)
+}
+
+pub type ElementMap<'a> = HashMap<&'a str, &'a str>;
+
+pub fn parse_input_into_directions_and_elements(
+    input: &str,
+) -> Result<(Vec<u8>, ElementMap<'_>, ElementMap<'_>), Error> {
+    let Some((directions, elements)) = input.split_once("\n\n") else {
+        return Err(Error::MissingElements(input.to_string()));
+    };
+
+    let directions = directions.bytes().collect::<Vec<_>>();
+
+    let (left, right) = elements
+        .lines()
+        .map(|line| {
+            let Some((from, to)) = line.strip_suffix(")").and_then(|s| s.split_once(" = (")) else {
+                return Err(Error::InvalidNode(line.to_string()));
+            };
+
+            let Some((left, right)) = to.split_once(", ") else {
+                return Err(Error::InvalidPair(to.to_string()));
+            };
+
+            Ok(((from, left), (from, right)))
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .unzip::<_, _, HashMap<_, _>, HashMap<_, _>>();
+
+    Ok((directions, left, right))
+}
+
+/// (element, index of next direction)
+pub type State<'a> = (&'a str, usize);
+
+pub fn next_state<'a>(
+    state: State<'a>,
+    directions: &[u8],
+    left: &ElementMap<'a>,
+    right: &ElementMap<'a>,
+) -> Result<State<'a>, Error> {
+    let (element, index) = state;
+
+    let element = match directions.get(index) {
+        Some(&direction @ (b'L' | b'R')) => {
+            let elements = if direction == b'L' { left } else { right };
+            elements.get(element).ok_or(Error::UnknownElement {
+                element: element.to_string(),
+                direction: direction as char,
+            })?
+        }
+        Some(&direction) => return Err(Error::InvalidDirection(direction as char)),
+        None => return Err(Error::NoDirections),
+    };
+
+    Ok((element, (index + 1) % directions.len()))
+}
+
+/// Counts the steps from start_element to the first ??Z, which must be reached before any state is
+/// repeated, or it never is.
+pub fn steps<'a>(
+    start_element: &'a str,
+    directions: &[u8],
+    left: &ElementMap<'a>,
+    right: &ElementMap<'a>,
+) -> Result<u64, Error> {
+    let states = directions.len().saturating_mul(left.len().max(1));
+    let mut state = (start_element, 0);
+    for steps in 1..=states {
+        state = next_state(state, directions, left, right)?;
+
+        if state.0.ends_with("Z") {
+            return Ok(steps as u64);
+        }
+    }
+
+    if directions.is_empty() {
+        return Err(Error::NoDirections);
+    }
+
+    Err(Error::NoEnd(start_element.to_string()))
+}
+
+/// Checks that, starting from start_element, a ??Z element is reached at every multiple of steps,
+/// and at no other time. This is what makes the lcm of the steps the answer for part 2.
+fn check_periodic<'a>(
+    start_element: &'a str,
+    steps: u64,
+    directions: &[u8],
+    left: &ElementMap<'a>,
+    right: &ElementMap<'a>,
+) -> Result<(), Error> {
+    // A missing element becomes None, which can never reach ??Z again.
+    let step = |state: &Option<State<'a>>| {
+        state.and_then(|state| next_state(state, directions, left, right).ok())
+    };
+
+    let cycle = find_cycle_brent(Some((start_element, 0)), step);
+
+    // Once the prefix and 1 full cycle have been checked, the pattern repeats forever.
+    let steps = steps as usize;
+    if cycle.cycle_len % steps != 0 {
+        return Err(Error::CycleMismatch {
+            start: start_element.to_string(),
+            cycle_len: cycle.cycle_len,
+            steps: steps as u64,
+        });
+    }
+
+    let mut state = Some((start_element, 0));
+    for step_count in 1..=cycle.prefix_len + cycle.cycle_len {
+        state = step(&state);
+
+        let is_end = state.is_some_and(|(element, _)| element.ends_with("Z"));
+        if is_end != (step_count % steps == 0) {
+            return Err(Error::NotPeriodic {
+                start: start_element.to_string(),
+                steps: steps as u64,
+                reached: state.map(|(element, _)| element.to_string()),
+                step: step_count,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::trim_newlines;
+
+    use super::*;
+
+    #[test]
+    fn example_1a() -> Result<()> {
+        let example = r"
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+
+        assert_eq!(part_1(trim_newlines(example))?, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_1b() -> Result<()> {
+        let example = r"
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+
+        assert_eq!(part_1(trim_newlines(example))?, 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        let example = r"
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+
+        assert_eq!(part_2(trim_newlines(example))?, 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_end() {
+        assert_eq!(
+            part_1("LR\n\nAAA = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)"),
+            Err(Error::NoEnd(String::from("AAA")))
+        );
+        assert_eq!(
+            part_2("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)"),
+            Err(Error::NoEnd(String::from("AAA")))
+        );
+    }
+
+    #[test]
+    fn example_2_not_periodic() {
+        let example = r"
+L
+
+11A = (11Z, XXX)
+11Z = (11B, XXX)
+11B = (11Z, XXX)
+XXX = (XXX, XXX)
+";
+
+        assert_eq!(
+            part_2(trim_newlines(example)),
+            Err(Error::NotPeriodic {
+                start: String::from("11A"),
+                steps: 1,
+                reached: Some(String::from("11B")),
+                step: 2,
+            })
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use thiserror::Error;
+
+use cli::{ErrorKind, Kind, NumberError, answer::Overflow};
+
+/// Why a day 9 puzzle input cannot be solved.
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    #[error(transparent)]
+    Number(#[from] NumberError),
+
+    #[error("Cannot extrapolate an empty sequence")]
+    EmptySequence,
+
+    /// The differences of the sequence run out before they are all zero.
+    #[error("Cannot extrapolate next number of {0:?}")]
+    NoPattern(Vec<i64>),
+
+    #[error(transparent)]
+    Overflow(#[from] Overflow),
+}
+
+impl Kind for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::Number(_) => ErrorKind::Parse,
+            Error::EmptySequence => ErrorKind::Validation,
+            Error::NoPattern(_) | Error::Overflow(_) => ErrorKind::Unsolvable,
+        }
+    }
+}
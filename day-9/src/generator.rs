@@ -0,0 +1,45 @@
+use rand::{Rng, rngs::StdRng};
+
+use cli::Generated;
+
+const LEN: i64 = 21;
+
+/// Generates size histories of 21 values, each from a random polynomial of degree at most 6.
+///
+/// The polynomials are written in the binomial basis C(x, k), so they give integers at every
+/// integer x, and the answers are the polynomials evaluated at x = 21 and x = -1.
+pub fn generate(rng: &mut StdRng, size: usize) -> Generated {
+    let mut lines = Vec::new();
+    let (mut sum_next, mut sum_previous) = (0, 0);
+    for _ in 0..size {
+        let coefficients = (0..rng.random_range(1..=7))
+            .map(|_| rng.random_range(-20..=20))
+            .collect::<Vec<i64>>();
+        let value = |x: i64| {
+            coefficients
+                .iter()
+                .enumerate()
+                .map(|(k, coefficient)| coefficient * binomial(x, k as i64))
+                .sum::<i64>()
+        };
+
+        lines.push(
+            (0..LEN)
+                .map(|x| value(x).to_string())
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+        sum_next += value(LEN);
+        sum_previous += value(-1);
+    }
+
+    Generated {
+        input: lines.join("\n"),
+        answers: [Some(sum_next.to_string()), Some(sum_previous.to_string())],
+    }
+}
+
+/// Computes C(x, k) = x (x - 1) ... (x - k + 1) / k!, which is also defined for negative x.
+fn binomial(x: i64, k: i64) -> i64 {
+    (0..k).fold(1, |acc, i| acc * (x - i) / (i + 1))
+}
@@ -0,0 +1,171 @@
+use cli::{
+    Solver,
+    answer::{Answer, try_sum, with_width},
+    dump_lines, parse_number,
+};
+
+pub use error::Error;
+
+mod error;
+pub mod generator;
+pub mod stream;
+
+pub const SOLVER: Solver = Solver {
+    year: 2023,
+    day: 9,
+    version: env!("SOLVER_VERSION"),
+    part_1: |input, settings| with_width(settings, || part_1(input), || part_1_as::<i128>(input)),
+    part_2: Some(|input, settings| {
+        with_width(settings, || part_2(input), || part_2_as::<i128>(input))
+    }),
+    generator: generator::generate,
+    render: None,
+    animate: None,
+    dump: Some(|input| {
+        dump_lines(input, |line| {
+            line.split_whitespace()
+                .map(parse_number::<i64>)
+                .collect::<Result<Vec<_>, _>>()
+        })
+    }),
+    stream: Some(stream::stream),
+    certify: None,
+    report: None,
+    classify: cli::classify::<Error>,
+};
+
+pub fn part_1(input: &str) -> Result<i64, Error> {
+    part_1_as(input)
+}
+
+pub fn part_2(input: &str) -> Result<i64, Error> {
+    part_2_as(input)
+}
+
+/// Sums the extrapolated next numbers in A.
+pub fn part_1_as<A: Answer + From<i64>>(input: &str) -> Result<A, Error> {
+    let values = parse(input)?
+        .into_iter()
+        .map(extrapolate)
+        .collect::<Result<Vec<A>, _>>()?;
+
+    Ok(try_sum(values)?)
+}
+
+/// Sums the extrapolated previous numbers in A.
+pub fn part_2_as<A: Answer + From<i64>>(input: &str) -> Result<A, Error> {
+    let values = parse(input)?
+        .into_iter()
+        .map(extrapolate_backwards)
+        .collect::<Result<Vec<A>, _>>()?;
+
+    Ok(try_sum(values)?)
+}
+
+fn parse(input: &str) -> Result<Vec<Vec<i64>>, Error> {
+    Ok(input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(parse_number::<i64>)
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?)
+}
+
+/// Finds the differences between consecutive numbers, failing on [cli::answer::Overflow].
+fn differences<A: Answer>(numbers: &[A]) -> Result<Vec<A>, Error> {
+    Ok(numbers
+        .windows(2)
+        .map(|window| window[1].try_sub(window[0]))
+        .collect::<Result<_, _>>()?)
+}
+
+pub fn extrapolate<A: Answer + From<i64>>(sequence: Vec<i64>) -> Result<A, Error> {
+    let mut numbers = sequence.iter().copied().map(A::from).collect::<Vec<_>>();
+    let mut last_numbers = vec![*numbers.last().ok_or(Error::EmptySequence)?];
+    while !numbers.iter().all(|number| *number == A::default()) {
+        numbers = differences(&numbers)?;
+
+        let Some(number) = numbers.last() else {
+            return Err(Error::NoPattern(sequence));
+        };
+        last_numbers.push(*number);
+    }
+
+    Ok(last_numbers
+        .into_iter()
+        .rev()
+        .try_fold(A::default(), |acc, number| acc.try_add(number))?)
+}
+
+pub fn extrapolate_backwards<A: Answer + From<i64>>(sequence: Vec<i64>) -> Result<A, Error> {
+    let mut numbers = sequence.iter().copied().map(A::from).collect::<Vec<_>>();
+    let mut first_numbers = vec![*numbers.first().ok_or(Error::EmptySequence)?];
+    while !numbers.iter().all(|number| *number == A::default()) {
+        numbers = differences(&numbers)?;
+
+        let Some(number) = numbers.first() else {
+            return Err(Error::NoPattern(sequence));
+        };
+        first_numbers.push(*number);
+    }
+
+    Ok(first_numbers
+        .into_iter()
+        .rev()
+        .try_fold(A::default(), |acc, number| number.try_sub(acc))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use cli::{answer::Overflow, trim_newlines};
+
+    use super::*;
+
+    const EXAMPLE: &str = r"
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+    #[test]
+    fn example_1() -> Result<()> {
+        assert_eq!(part_1(trim_newlines(EXAMPLE))?, 114);
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_2() -> Result<()> {
+        assert_eq!(part_2(trim_newlines(EXAMPLE))?, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_pattern() {
+        assert_eq!(part_1("1 2 4"), Err(Error::NoPattern(vec![1, 2, 4])));
+        assert_eq!(part_2("0 3 6\n"), Ok(-3));
+        assert_eq!(part_2("7"), Err(Error::NoPattern(vec![7])));
+    }
+
+    #[test]
+    fn overflow() -> Result<()> {
+        let wide = "wide = true".parse()?;
+        let input = format!("0 {} {}", i64::MAX / 2, i64::MAX - 1);
+
+        assert_eq!(part_1(&input), Err(Error::Overflow(Overflow("i64"))));
+        assert_eq!(
+            part_1_as::<i128>(&input)?,
+            (i128::from(i64::MAX) - 1) * 3 / 2
+        );
+        assert_eq!(
+            stream::stream(&mut input.as_bytes(), 1, &wide)?,
+            part_1_as::<i128>(&input)?.to_string()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use std::io::BufRead;
+
+use anyhow::{Result, anyhow};
+
+use cli::{
+    answer::{Answer, is_wide},
+    config::Settings,
+    input::for_each_line,
+    parse_number,
+};
+
+use crate::{extrapolate, extrapolate_backwards};
+
+/// Sums the extrapolated values a sequence at a time, in 128 bits if [is_wide].
+pub fn stream(reader: &mut dyn BufRead, part: u8, settings: &Settings) -> Result<String> {
+    if is_wide(settings)? {
+        Ok(stream_as::<i128>(reader, part)?.to_string())
+    } else {
+        Ok(stream_as::<i64>(reader, part)?.to_string())
+    }
+}
+
+/// Sums the extrapolated values in A a sequence at a time.
+fn stream_as<A: Answer + From<i64>>(reader: &mut dyn BufRead, part: u8) -> Result<A> {
+    let extrapolate = match part {
+        1 => extrapolate::<A>,
+        2 => extrapolate_backwards::<A>,
+        _ => return Err(anyhow!("Invalid part number: {}", part)),
+    };
+
+    let mut sum = A::default();
+    for_each_line(reader, |line| {
+        let numbers = line
+            .split_whitespace()
+            .map(parse_number::<i64>)
+            .collect::<Result<Vec<_>, _>>()?;
+        sum = sum.try_add(extrapolate(numbers)?)?;
+
+        Ok(())
+    })?;
+
+    Ok(sum)
+}
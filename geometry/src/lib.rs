@@ -0,0 +1,300 @@
+use std::{
+    ops::{Add, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+use anyhow::anyhow;
+use serde::Serialize;
+
+/// A cardinal direction on a grid, where [Direction::Up] points towards row 0.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All [Direction]s, starting from [Direction::Up], clockwise.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Turns 90 degrees anti-clockwise.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    /// Turns 90 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    /// Turns 180 degrees.
+    pub fn reverse(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    /// Gets the unit [Vector] that moves one step in this [Direction].
+    pub fn as_vector(self) -> Vector {
+        match self {
+            Direction::Up => Vector::new(-1, 0),
+            Direction::Right => Vector::new(0, 1),
+            Direction::Down => Vector::new(1, 0),
+            Direction::Left => Vector::new(0, -1),
+        }
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = anyhow::Error;
+
+    /// Accepts U/R/D/L, ^/>/v/< and N/E/S/W.
+    fn try_from(value: char) -> std::result::Result<Self, Self::Error> {
+        match value {
+            'U' | '^' | 'N' => Ok(Direction::Up),
+            'R' | '>' | 'E' => Ok(Direction::Right),
+            'D' | 'v' | 'S' => Ok(Direction::Down),
+            'L' | '<' | 'W' => Ok(Direction::Left),
+            _ => Err(anyhow!("Invalid direction: {}", value)),
+        }
+    }
+}
+
+impl TryFrom<u8> for Direction {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
+        Direction::try_from(value as char)
+    }
+}
+
+impl FromStr for Direction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(anyhow!("Invalid direction: {}", s)),
+        }
+    }
+}
+
+/// A displacement on a grid. Unlike [Point], the components may be negative.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Vector {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Vector {
+    pub const fn new(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+
+    /// Gets the Manhattan length of this [Vector].
+    pub fn manhattan_len(self) -> usize {
+        self.row.unsigned_abs() + self.col.unsigned_abs()
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Vector::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Vector::new(self.row * rhs, self.col * rhs)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Self::Output {
+        Vector::new(-self.row, -self.col)
+    }
+}
+
+/// The extent of a grid, in (rows, cols).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize)]
+pub struct Size {
+    pub rows: usize,
+    pub cols: usize,
+}
+
+impl Size {
+    pub const fn new(rows: usize, cols: usize) -> Self {
+        Self { rows, cols }
+    }
+
+    pub const fn square(size: usize) -> Self {
+        Self::new(size, size)
+    }
+
+    /// Checks if the given [Point] lies within this [Size].
+    pub fn contains(&self, point: Point) -> bool {
+        point.row < self.rows && point.col < self.cols
+    }
+
+    /// Iterates through all [Point]s within this [Size], row by row.
+    pub fn points(self) -> impl Iterator<Item = Point> {
+        (0..self.rows).flat_map(move |row| (0..self.cols).map(move |col| Point::new(row, col)))
+    }
+}
+
+/// A position on a grid, in (row, col).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Point {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl From<(usize, usize)> for Point {
+    fn from(value: (usize, usize)) -> Self {
+        Point::new(value.0, value.1)
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector::new(
+            self.row as isize - rhs.row as isize,
+            self.col as isize - rhs.col as isize,
+        )
+    }
+}
+
+impl Point {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// Moves by the given [Vector].
+    ///
+    /// Returns None if the new [Point] lies outside bounds.
+    pub fn offset(self, vector: Vector, bounds: Size) -> Option<Point> {
+        let row = self.row.checked_add_signed(vector.row)?;
+        let col = self.col.checked_add_signed(vector.col)?;
+
+        Some(Point::new(row, col)).filter(|point| bounds.contains(*point))
+    }
+
+    /// Moves one step in the given [Direction].
+    ///
+    /// Returns None if the new [Point] lies outside bounds.
+    pub fn step(self, direction: Direction, bounds: Size) -> Option<Point> {
+        self.offset(direction.as_vector(), bounds)
+    }
+
+    /// Moves distance steps in the given [Direction].
+    ///
+    /// Returns None if the new [Point] lies outside bounds.
+    pub fn step_by(self, direction: Direction, distance: usize, bounds: Size) -> Option<Point> {
+        self.offset(
+            direction.as_vector() * isize::try_from(distance).ok()?,
+            bounds,
+        )
+    }
+
+    /// Gets all orthogonal neighbours within bounds, starting from [Direction::Up], clockwise.
+    pub fn neighbours(self, bounds: Size) -> impl Iterator<Item = (Direction, Point)> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| Some((direction, self.step(direction, bounds)?)))
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::*;
+
+    #[test]
+    fn turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(direction.reverse().reverse(), direction);
+            assert_eq!(
+                direction.as_vector() + direction.reverse().as_vector(),
+                Vector::default()
+            );
+        }
+    }
+
+    #[test]
+    fn parse() -> Result<()> {
+        assert_eq!("U".parse::<Direction>()?, Direction::Up);
+        assert_eq!(Direction::try_from('>')?, Direction::Right);
+        assert_eq!(Direction::try_from(b'v')?, Direction::Down);
+        assert_eq!(Direction::try_from('W')?, Direction::Left);
+        assert!("UR".parse::<Direction>().is_err());
+        assert!(Direction::try_from('x').is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn step_within_bounds() {
+        let bounds = Size::new(2, 3);
+
+        assert_eq!(Point::new(0, 0).step(Direction::Up, bounds), None);
+        assert_eq!(Point::new(0, 0).step(Direction::Left, bounds), None);
+        assert_eq!(Point::new(1, 2).step(Direction::Down, bounds), None);
+        assert_eq!(Point::new(1, 2).step(Direction::Right, bounds), None);
+        assert_eq!(
+            Point::new(0, 0).step_by(Direction::Right, 2, bounds),
+            Some(Point::new(0, 2))
+        );
+        assert_eq!(Point::new(0, 0).neighbours(bounds).count(), 2);
+        assert_eq!(Point::new(1, 1).neighbours(bounds).count(), 3);
+    }
+
+    #[test]
+    fn distances() {
+        assert_eq!(Point::new(6, 1).manhattan_distance(Point::new(11, 5)), 9);
+        assert_eq!((Point::new(0, 4) - Point::new(10, 9)).manhattan_len(), 15);
+    }
+}